@@ -8,12 +8,50 @@ use std::path::Path;
 
 use winit::{event::*, keyboard::*};
 
-use crate::data_types::{EditorError, Result};
+use crate::data_types::{EditorError, MAX_CHANGES, Result};
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct ConfigData {
     pub hide_fps: bool,
     pub zoom: f32,
+    // Layer settings are indexed by map layer, not by the layer button order
+    #[serde(default = "default_layer_visible")]
+    pub layer_visible: [bool; 9],
+    #[serde(default)]
+    pub layer_locked: [bool; 9],
+    #[serde(default)]
+    pub dim_layers: bool,
+    #[serde(default = "default_dim_opacity")]
+    pub dim_opacity: u8,
+    // Keeps the undo history next to the temp map file so it can be recovered
+    #[serde(default = "default_save_undo_history")]
+    pub save_undo_history: bool,
+    // Amount of changes kept in the undo history of each map
+    #[serde(default = "default_history_depth")]
+    pub history_depth: usize,
+    #[serde(default)]
+    pub show_grid: bool,
+    #[serde(default)]
+    pub show_ruler: bool,
+    // Painted tiles set the collision written in their tile sheet metadata
+    #[serde(default)]
+    pub auto_collision: bool,
+}
+
+fn default_layer_visible() -> [bool; 9] {
+    [true; 9]
+}
+
+fn default_dim_opacity() -> u8 {
+    100
+}
+
+fn default_save_undo_history() -> bool {
+    true
+}
+
+fn default_history_depth() -> usize {
+    MAX_CHANGES
 }
 
 impl ConfigData {
@@ -21,6 +59,15 @@ impl ConfigData {
         Self {
             hide_fps: false,
             zoom: 1.0,
+            layer_visible: default_layer_visible(),
+            layer_locked: [false; 9],
+            dim_layers: false,
+            dim_opacity: default_dim_opacity(),
+            save_undo_history: default_save_undo_history(),
+            history_depth: default_history_depth(),
+            show_grid: false,
+            show_ruler: false,
+            auto_collision: false,
         }
     }
 
@@ -2,7 +2,7 @@ use crate::{
     audio::AudioCollection,
     content::widget::{Alert, AlertBuilder, AlertIndex},
     data_types::Result,
-    database::{MapPosition, Presets, save_and_clear_map, save_temp_file},
+    database::{MapPosition, Presets, Terrains, save_and_clear_map, save_temp_file},
     renderer::SystemHolder,
 };
 use ascending_graphics::MapRenderer;
@@ -31,6 +31,7 @@ pub struct Content {
     pub audio_collection: AudioCollection,
     pub data: EditorData,
     pub preset: Presets,
+    pub terrain: Terrains,
     pub input: ContentInput,
 }
 
@@ -42,8 +43,9 @@ impl Content {
             interface: Interface::new(&audio_collection, systems),
             map_view: MapView::new(systems, map_renderer)?,
             audio_collection,
-            data: EditorData::new(),
+            data: EditorData::new(systems.config.history_depth),
             preset: Presets::load_data()?,
+            terrain: Terrains::load_data()?,
             input: ContentInput {
                 ctrl_down: false,
                 shift_down: false,
@@ -72,15 +74,22 @@ pub fn exit_editor(
                 &content.data.mapdata,
                 true,
             )?;
+            content
+                .data
+                .save_history(systems.config.save_undo_history)?;
 
             let _ = content.data.unsaved_map.insert(mappos);
         } else {
             save_temp_file(0, 0, 0, &content.data.mapdata, false)?;
+            content
+                .data
+                .save_history(systems.config.save_undo_history)?;
         }
 
         content.data.temp_saved = true;
     }
 
+    save_link_map_temp(content)?;
     content.data.exiting_save = true;
 
     if !content.data.unsaved_map.is_empty() {
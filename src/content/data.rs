@@ -1,32 +1,44 @@
-use std::collections::VecDeque;
+use std::collections::{HashMap, VecDeque};
 
 use ascending_graphics::*;
 use indexmap::IndexSet;
+use speedy::{Readable, Writable};
 
 use crate::{
-    content::{Content, get_attribute_visual, get_tile_pos},
+    content::{
+        Content, MapTransform, apply_map_data, get_attribute_visual, get_dirblock_uv,
+        get_layer_color, get_link_offset, get_tile_pos, set_link_tile, set_tile_anim_time,
+        set_tile_collision, update_zone_list, update_zone_textbox, update_zone_visible,
+    },
     data_types::*,
-    database::{MapAttribute, MapData, MapPosition},
+    database::{
+        MapAttribute, MapData, MapPosition, Weather, ZoneData, load_history_file, save_history_file,
+    },
     renderer::SystemHolder,
 };
 
+#[derive(PartialEq, Eq, Clone, Copy)]
 pub enum ChangePlaceHolder {
     None,
     Tiles,
     Attributes,
+    DirBlocks,
+    ZoneSpawns,
 }
 
-#[derive(Clone)]
+#[derive(Clone, Readable, Writable)]
 pub struct TileChangeData {
     pub x: u16,
     pub y: u16,
     pub layer: usize,
+    // Index of the linked map the tile belongs to, None for the current map
+    pub link: Option<usize>,
 
     pub from: usize,
     pub to: usize,
 }
 
-#[derive(Clone)]
+#[derive(Clone, Readable, Writable)]
 pub struct AttrChangeData {
     pub x: u16,
     pub y: u16,
@@ -35,218 +47,826 @@ pub struct AttrChangeData {
     pub to: MapAttribute,
 }
 
-#[derive(Default, Clone)]
+#[derive(Clone, Readable, Writable)]
+pub struct DirBlockChangeData {
+    pub x: u16,
+    pub y: u16,
+
+    pub from: u8,
+    pub to: u8,
+}
+
+#[derive(Clone, Readable, Writable)]
+pub struct CollisionChangeData {
+    pub x: u16,
+    pub y: u16,
+
+    pub from_attr: MapAttribute,
+    pub to_attr: MapAttribute,
+    pub from_dirblock: u8,
+    pub to_dirblock: u8,
+}
+
+#[derive(Clone, Readable, Writable)]
+pub struct AnimTimeChangeData {
+    pub x: u16,
+    pub y: u16,
+
+    pub from: u32,
+    pub to: u32,
+}
+
+#[derive(Clone, Readable, Writable)]
+pub struct ZoneSpawnChangeData {
+    pub x: u16,
+    pub y: u16,
+    pub zone: usize,
+
+    // true if the spawn was added, false if it was removed
+    pub set: bool,
+}
+
+#[derive(Clone, Readable, Writable)]
+pub struct ZoneSettingChange {
+    pub zone: usize,
+    // Textbox and npc entry that was typed into, None for the npc buttons
+    pub textbox: Option<(usize, Option<usize>)>,
+
+    pub from: ZoneData,
+    pub to: ZoneData,
+}
+
+#[derive(Clone, Readable, Writable)]
+pub struct ZoneListChange {
+    pub zone: usize,
+    pub data: ZoneData,
+    pub spawns: Vec<(u16, u16)>,
+
+    // true if the zone was added, false if it was removed
+    pub add: bool,
+}
+
+#[derive(Default, Clone, Readable, Writable)]
 pub struct TileChanges {
     pub change: Vec<TileChangeData>,
+    pub fill: bool,
+    // Collision the painted tiles brought along from their tile sheet metadata
+    pub collision: Vec<CollisionChangeData>,
+    // Frame duration the painted animated presets set on the map tiles
+    pub anim_time: Vec<AnimTimeChangeData>,
 }
 
-#[derive(Default, Clone)]
+#[derive(Default, Clone, Readable, Writable)]
 pub struct AttrChanges {
     pub change: Vec<AttrChangeData>,
+    pub fill: bool,
+}
+
+#[derive(Default, Clone, Readable, Writable)]
+pub struct DirBlockChanges {
+    pub change: Vec<DirBlockChangeData>,
+}
+
+#[derive(Default, Clone, Readable, Writable)]
+pub struct CollisionChanges {
+    pub change: Vec<CollisionChangeData>,
 }
 
-#[derive(Clone)]
+#[derive(Default, Clone, Readable, Writable)]
+pub struct ZoneSpawnChanges {
+    pub change: Vec<ZoneSpawnChangeData>,
+}
+
+#[derive(Clone, Readable, Writable)]
 pub enum EditorChange {
     Tile(TileChanges),
     Attr(AttrChanges),
+    DirBlock(DirBlockChanges),
+    ZoneSpawn(ZoneSpawnChanges),
+    Collision(CollisionChanges),
+    ZoneSetting(ZoneSettingChange),
+    ZoneList(ZoneListChange),
+    Weather(Weather, Weather),
+    Music(Option<String>, Option<String>),
+    // Holds the whole map before and after the transform, stored as map file bytes
+    Transform(MapTransform, Vec<u8>, Vec<u8>),
+}
+
+impl EditorChange {
+    pub fn get_label(&self) -> String {
+        match self {
+            EditorChange::Tile(tile) => {
+                let Some(first) = tile.change.first() else {
+                    return "Tile Change".to_string();
+                };
+                let action = if tile.fill {
+                    "Fill"
+                } else if tile.change.iter().all(|data| data.to == 0) {
+                    "Erase"
+                } else {
+                    "Paint"
+                };
+                let layer = if tile.change.iter().all(|data| data.layer == first.layer) {
+                    map_layer_name(first.layer)
+                } else {
+                    "Layers"
+                };
+
+                get_change_label(
+                    &format!("{action} {layer}"),
+                    tile.change.len(),
+                    (first.x, first.y),
+                )
+            }
+            EditorChange::Attr(attr) => {
+                let Some(first) = attr.change.first() else {
+                    return "Attribute Change".to_string();
+                };
+                let clear = attr
+                    .change
+                    .iter()
+                    .all(|data| data.to == MapAttribute::Walkable);
+                let action = if attr.fill {
+                    "Fill"
+                } else if clear {
+                    "Clear"
+                } else {
+                    "Set"
+                };
+                let name = MapAttribute::as_str(MapAttribute::convert_to_num(if clear {
+                    &first.from
+                } else {
+                    &first.to
+                }));
+
+                get_change_label(
+                    &format!("{action} {name}"),
+                    attr.change.len(),
+                    (first.x, first.y),
+                )
+            }
+            EditorChange::DirBlock(dirblock) => {
+                let Some(first) = dirblock.change.first() else {
+                    return "Dir Block Change".to_string();
+                };
+                let action = if dirblock.change.iter().all(|data| data.to == 0) {
+                    "Clear"
+                } else {
+                    "Set"
+                };
+
+                get_change_label(
+                    &format!("{action} Dir Block"),
+                    dirblock.change.len(),
+                    (first.x, first.y),
+                )
+            }
+            EditorChange::ZoneSpawn(zonespawn) => {
+                let Some(first) = zonespawn.change.first() else {
+                    return "Zone Spawn Change".to_string();
+                };
+                let action = if first.set { "Add" } else { "Remove" };
+
+                get_change_label(
+                    &format!("{action} Zone {} Spawn", first.zone + 1),
+                    zonespawn.change.len(),
+                    (first.x, first.y),
+                )
+            }
+            EditorChange::Collision(collision) => {
+                format!("Recompute Collisions on {} tiles", collision.change.len())
+            }
+            EditorChange::ZoneSetting(setting) => {
+                format!("Edit {} Settings", setting.to.name)
+            }
+            EditorChange::ZoneList(zone) => {
+                let action = if zone.add { "Add" } else { "Remove" };
+                format!("{action} {}", zone.data.name)
+            }
+            EditorChange::Weather(_, to) => {
+                format!("Set Weather {}", to.convert_to_string())
+            }
+            EditorChange::Music(_, to) => {
+                format!("Set Music {}", to.as_deref().unwrap_or("None"))
+            }
+            EditorChange::Transform(transform, _, _) => transform.get_label(),
+        }
+    }
+}
+
+fn get_change_label(action: &str, count: usize, pos: (u16, u16)) -> String {
+    if count == 1 {
+        format!("{action} at {},{}", pos.0, pos.1)
+    } else {
+        format!("{action} {count} tiles")
+    }
+}
+
+// Part of a linked map that got edited from the current map
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LinkEdit {
+    // Layer and tile position
+    Tile(usize, usize),
+    Attribute(usize),
+}
+
+pub struct LinkMapData {
+    pub pos: MapPosition,
+    pub mapdata: MapData,
+    // Edited and not yet written into the temp file
+    pub changed: bool,
+    // Edited since the current map was last saved, only these get saved along with it
+    pub edits: IndexSet<LinkEdit>,
+    // Loaded from its temp file, the map got unsaved edits of its own
+    pub from_temp: bool,
+}
+
+#[derive(Default, Clone)]
+pub struct ChangeHistory {
+    pub undo: VecDeque<EditorChange>,
+    pub redo: VecDeque<EditorChange>,
+}
+
+// speedy has no VecDeque support, so the history file keeps the changes in a Vec
+#[derive(Readable, Writable)]
+struct ChangeHistoryFile {
+    undo: Vec<EditorChange>,
+    redo: Vec<EditorChange>,
+}
+
+impl ChangeHistory {
+    pub fn to_bytes(&self) -> Vec<u8> {
+        ChangeHistoryFile {
+            undo: self.undo.iter().cloned().collect(),
+            redo: self.redo.iter().cloned().collect(),
+        }
+        .write_to_vec()
+        .unwrap()
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        let history = ChangeHistoryFile::read_from_buffer(bytes).ok()?;
+        Some(ChangeHistory {
+            undo: history.undo.into(),
+            redo: history.redo.into(),
+        })
+    }
 }
 
 pub struct EditorData {
     pub pos: Option<MapPosition>,
     pub mapdata: MapData,
+    // Neighbour maps that can be edited across the map border
+    pub link_map: Vec<Option<LinkMapData>>,
     pub changed: bool,
     pub temp_saved: bool,
     pub undo: VecDeque<EditorChange>,
     pub redo: VecDeque<EditorChange>,
+    // History of the other maps that were opened during this session
+    pub map_history: HashMap<MapPosition, ChangeHistory>,
+    pub history_depth: usize,
+    pub history_updated: bool,
 
     pub last_pos: Vec<Vec2>,
     pub change_placeholder: ChangePlaceHolder,
     pub tile_placeholder: TileChanges,
     pub attr_placeholder: AttrChanges,
+    pub dirblock_placeholder: DirBlockChanges,
+    pub zonespawn_placeholder: ZoneSpawnChanges,
     pub unsaved_map: IndexSet<MapPosition>,
     pub exiting_save: bool,
 }
 
 impl EditorData {
-    pub fn new() -> Self {
+    pub fn new(history_depth: usize) -> Self {
         EditorData {
             pos: None,
             mapdata: MapData::default(0, 0, 0),
+            link_map: Vec::with_capacity(8),
             changed: false,
             temp_saved: true,
             undo: VecDeque::with_capacity(64),
             redo: VecDeque::with_capacity(64),
+            map_history: HashMap::default(),
+            history_depth,
+            history_updated: true,
 
             last_pos: Vec::with_capacity(1024),
             change_placeholder: ChangePlaceHolder::None,
             tile_placeholder: TileChanges::default(),
             attr_placeholder: AttrChanges::default(),
+            dirblock_placeholder: DirBlockChanges::default(),
+            zonespawn_placeholder: ZoneSpawnChanges::default(),
             unsaved_map: IndexSet::default(),
             exiting_save: false,
         }
     }
 
     pub fn record_placeholder(&mut self) {
-        match self.change_placeholder {
+        let change = match self.change_placeholder {
             ChangePlaceHolder::None => return,
-            ChangePlaceHolder::Attributes => {
-                self.undo
-                    .push_back(EditorChange::Attr(self.attr_placeholder.clone()));
+            ChangePlaceHolder::Attributes => EditorChange::Attr(self.attr_placeholder.clone()),
+            ChangePlaceHolder::Tiles => EditorChange::Tile(self.tile_placeholder.clone()),
+            ChangePlaceHolder::DirBlocks => {
+                EditorChange::DirBlock(self.dirblock_placeholder.clone())
             }
-            ChangePlaceHolder::Tiles => {
-                self.undo
-                    .push_back(EditorChange::Tile(self.tile_placeholder.clone()));
+            ChangePlaceHolder::ZoneSpawns => {
+                EditorChange::ZoneSpawn(self.zonespawn_placeholder.clone())
             }
-        }
+        };
+        self.record_change(change);
+
+        self.last_pos.clear();
+        self.switch_placeholder(ChangePlaceHolder::None);
+    }
 
-        if self.undo.len() > MAX_CHANGES {
+    // Changes that are not painted by dragging gets recorded directly
+    pub fn record_change(&mut self, change: EditorChange) {
+        self.undo.push_back(change);
+
+        while self.undo.len() > self.history_depth {
             let _ = self.undo.pop_front();
         }
 
-        self.last_pos.clear();
         self.redo.clear();
-        self.change_placeholder = ChangePlaceHolder::None;
-        self.tile_placeholder.change.clear();
-        self.attr_placeholder.change.clear();
+        self.history_updated = true;
     }
 
-    pub fn record_tile(&mut self, x: u16, y: u16, layer: usize, from_id: usize, to_id: usize) {
-        let new_pos = Vec2::new(x as f32, y as f32);
+    // Changes made on a map that is not opened are undone once that map gets opened,
+    // a saved history file is replacing the session one so the change goes in it too
+    pub fn record_map_change(
+        &mut self,
+        pos: MapPosition,
+        change: EditorChange,
+        save_file: bool,
+    ) -> Result<()> {
+        if save_file
+            && let Some(bytes) = load_history_file(pos.x, pos.y, pos.group as u64, true)?
+            && let Some(mut history) = ChangeHistory::from_bytes(&bytes)
+        {
+            history.undo.push_back(change.clone());
+            while history.undo.len() > self.history_depth {
+                let _ = history.undo.pop_front();
+            }
+            history.redo.clear();
+
+            save_history_file(pos.x, pos.y, pos.group as u64, &history.to_bytes(), true)?;
+        }
+
+        let history = self.map_history.entry(pos).or_default();
+        history.undo.push_back(change);
+
+        while history.undo.len() > self.history_depth {
+            let _ = history.undo.pop_front();
+        }
+
+        history.redo.clear();
+        Ok(())
+    }
+
+    fn switch_placeholder(&mut self, placeholder: ChangePlaceHolder) {
+        if self.change_placeholder != placeholder {
+            self.tile_placeholder.change.clear();
+            self.attr_placeholder.change.clear();
+            self.dirblock_placeholder.change.clear();
+            self.zonespawn_placeholder.change.clear();
+            self.tile_placeholder.collision.clear();
+            self.tile_placeholder.anim_time.clear();
+            self.tile_placeholder.fill = false;
+            self.attr_placeholder.fill = false;
+        }
+        self.change_placeholder = placeholder;
+    }
+
+    fn is_new_pos(&mut self, new_pos: Vec2) -> bool {
         if self.last_pos.contains(&new_pos) {
-            return;
+            return false;
         }
         self.last_pos.push(new_pos);
+        true
+    }
 
-        self.change_placeholder = ChangePlaceHolder::Tiles;
-        if !self.attr_placeholder.change.is_empty() {
-            self.attr_placeholder.change.clear();
+    pub fn record_tile(&mut self, x: u16, y: u16, layer: usize, from_id: usize, to_id: usize) {
+        if !self.is_new_pos(Vec2::new(x as f32, y as f32)) {
+            return;
         }
 
+        self.switch_placeholder(ChangePlaceHolder::Tiles);
         self.tile_placeholder.change.push(TileChangeData {
             x,
             y,
             layer,
+            link: None,
             from: from_id,
             to: to_id,
         });
     }
 
-    pub fn record_attr(&mut self, x: u16, y: u16, from: MapAttribute, to: MapAttribute) {
-        let new_pos = Vec2::new(x as f32, y as f32);
-        if self.last_pos.contains(&new_pos) {
+    pub fn record_link_tile(
+        &mut self,
+        link: usize,
+        x: u16,
+        y: u16,
+        layer: usize,
+        from_id: usize,
+        to_id: usize,
+    ) {
+        // Positions are kept relative to the current map so they don't collide
+        let offset = get_link_offset(link);
+        if !self.is_new_pos(Vec2::new(
+            (x as i32 + offset.0) as f32,
+            (y as i32 + offset.1) as f32,
+        )) {
             return;
         }
-        self.last_pos.push(new_pos);
 
-        self.change_placeholder = ChangePlaceHolder::Attributes;
-        if !self.tile_placeholder.change.is_empty() {
-            self.tile_placeholder.change.clear();
+        self.switch_placeholder(ChangePlaceHolder::Tiles);
+        self.tile_placeholder.change.push(TileChangeData {
+            x,
+            y,
+            layer,
+            link: Some(link),
+            from: from_id,
+            to: to_id,
+        });
+    }
+
+    // Goes along with the tiles being painted, a tile painted over again keeps its first collision
+    pub fn record_tile_collision(&mut self, data: CollisionChangeData) {
+        if self.change_placeholder != ChangePlaceHolder::Tiles {
+            return;
         }
 
+        let collision = &mut self.tile_placeholder.collision;
+        if let Some(change) = collision
+            .iter_mut()
+            .find(|change| change.x == data.x && change.y == data.y)
+        {
+            change.to_attr = data.to_attr;
+            change.to_dirblock = data.to_dirblock;
+        } else {
+            collision.push(data);
+        }
+    }
+
+    // Goes along with the tiles being painted, like the tile collision
+    pub fn record_tile_anim_time(&mut self, data: AnimTimeChangeData) {
+        if self.change_placeholder != ChangePlaceHolder::Tiles {
+            return;
+        }
+
+        let anim_time = &mut self.tile_placeholder.anim_time;
+        if let Some(change) = anim_time
+            .iter_mut()
+            .find(|change| change.x == data.x && change.y == data.y)
+        {
+            change.to = data.to;
+        } else {
+            anim_time.push(data);
+        }
+    }
+
+    pub fn record_attr(&mut self, x: u16, y: u16, from: MapAttribute, to: MapAttribute) {
+        if !self.is_new_pos(Vec2::new(x as f32, y as f32)) {
+            return;
+        }
+
+        self.switch_placeholder(ChangePlaceHolder::Attributes);
         self.attr_placeholder
             .change
             .push(AttrChangeData { x, y, from, to });
     }
-}
 
-pub fn apply_undo(content: &mut Content, systems: &mut SystemHolder) {
-    if content.data.undo.is_empty() {
-        return;
+    pub fn record_dirblock(&mut self, x: u16, y: u16, from: u8, to: u8) {
+        if !self.is_new_pos(Vec2::new(x as f32, y as f32)) {
+            return;
+        }
+
+        self.switch_placeholder(ChangePlaceHolder::DirBlocks);
+        self.dirblock_placeholder
+            .change
+            .push(DirBlockChangeData { x, y, from, to });
     }
 
-    if let Some(data) = content.data.undo.pop_back() {
-        content.data.redo.push_back(data.clone());
+    pub fn record_zonespawn(&mut self, x: u16, y: u16, zone: usize, set: bool) {
+        if !self.is_new_pos(Vec2::new(x as f32, y as f32)) {
+            return;
+        }
 
-        match data {
-            EditorChange::Attr(attr) => {
-                for changes in attr.change.iter() {
-                    let tile_pos = get_tile_pos(changes.x as i32, changes.y as i32);
-                    let (color, text) = get_attribute_visual(&changes.from);
-                    content.data.mapdata.attribute[tile_pos] = changes.from.clone();
-
-                    let view_attr = content.map_view.attribute[tile_pos];
-                    systems.gfx.set_text(&view_attr.text, &text);
-                    systems
-                        .gfx
-                        .center_text(&mut systems.renderer, &view_attr.text);
-                    systems.gfx.set_color(&view_attr.bg, color);
-                }
-            }
-            EditorChange::Tile(tile) => {
-                for changes in tile.change.iter() {
-                    content.map_view.map.set_tile(
-                        UVec3::new(changes.x as u32, changes.y as u32, changes.layer as u32),
-                        TileData {
-                            id: changes.from,
-                            color: Color::rgb(255, 255, 255),
-                            anim_time: 250,
-                        },
-                    );
+        self.switch_placeholder(ChangePlaceHolder::ZoneSpawns);
+        self.zonespawn_placeholder
+            .change
+            .push(ZoneSpawnChangeData { x, y, zone, set });
+    }
 
-                    let tile_pos = get_tile_pos(changes.x as i32, changes.y as i32);
-                    content.data.mapdata.tile[changes.layer].id[tile_pos] = changes.from as u32;
-                }
-            }
+    // Stores the history of the current map and restores the one of the map being opened
+    pub fn switch_history(&mut self, new_pos: Option<MapPosition>) {
+        let history = ChangeHistory {
+            undo: std::mem::take(&mut self.undo),
+            redo: std::mem::take(&mut self.redo),
+        };
+        self.history_updated = true;
+
+        // Reloading the same map discards the changes, so does its history
+        if self.pos == new_pos {
+            return;
         }
 
-        content.data.changed = true;
-        content.data.temp_saved = false;
-        if let Some(map_pos) = content.data.pos {
-            content
-                .interface
-                .footer
-                .set_map_pos(systems, map_pos, false);
+        if let Some(pos) = self.pos
+            && (!history.undo.is_empty() || !history.redo.is_empty())
+        {
+            self.map_history.insert(pos, history);
+        }
+
+        if let Some(history) = new_pos.and_then(|pos| self.map_history.remove(&pos)) {
+            self.undo = history.undo;
+            self.redo = history.redo;
         }
     }
+
+    pub fn save_history(&self, save_file: bool) -> Result<()> {
+        if !save_file {
+            return Ok(());
+        }
+
+        let bytes = ChangeHistory {
+            undo: self.undo.clone(),
+            redo: self.redo.clone(),
+        }
+        .to_bytes();
+
+        if let Some(pos) = self.pos {
+            save_history_file(pos.x, pos.y, pos.group as u64, &bytes, true)
+        } else {
+            save_history_file(0, 0, 0, &bytes, false)
+        }
+    }
+
+    pub fn load_history(&mut self) -> Result<()> {
+        let bytes = if let Some(pos) = self.pos {
+            load_history_file(pos.x, pos.y, pos.group as u64, true)?
+        } else {
+            load_history_file(0, 0, 0, false)?
+        };
+
+        if let Some(bytes) = bytes
+            && let Some(history) = ChangeHistory::from_bytes(&bytes)
+        {
+            self.undo = history.undo;
+            self.redo = history.redo;
+            self.history_updated = true;
+        }
+        Ok(())
+    }
+
+    pub fn record_zone_setting(
+        &mut self,
+        zone: usize,
+        textbox: Option<(usize, Option<usize>)>,
+        from: ZoneData,
+        to: ZoneData,
+    ) {
+        // Typing into the same textbox is merged into a single change
+        if self.redo.is_empty()
+            && textbox.is_some()
+            && let Some(EditorChange::ZoneSetting(last)) = self.undo.back_mut()
+            && last.zone == zone
+            && last.textbox == textbox
+        {
+            last.to = to;
+            return;
+        }
+
+        self.record_change(EditorChange::ZoneSetting(ZoneSettingChange {
+            zone,
+            textbox,
+            from,
+            to,
+        }));
+    }
 }
 
-pub fn apply_redo(content: &mut Content, systems: &mut SystemHolder) {
-    if content.data.redo.is_empty() {
-        return;
+pub fn apply_undo(content: &mut Content, systems: &mut SystemHolder) {
+    if let Some(data) = content.data.undo.pop_back() {
+        apply_change(content, systems, &data, true);
+        content.data.redo.push_back(data);
+        content.data.history_updated = true;
     }
+}
 
+pub fn apply_redo(content: &mut Content, systems: &mut SystemHolder) {
     if let Some(data) = content.data.redo.pop_back() {
-        content.data.undo.push_back(data.clone());
+        apply_change(content, systems, &data, false);
+        content.data.undo.push_back(data);
+        content.data.history_updated = true;
+    }
+}
 
-        match data {
-            EditorChange::Attr(attr) => {
-                for changes in attr.change.iter() {
-                    let tile_pos = get_tile_pos(changes.x as i32, changes.y as i32);
-                    let (color, text) = get_attribute_visual(&changes.to);
-                    content.data.mapdata.attribute[tile_pos] = changes.to.clone();
-
-                    let view_attr = content.map_view.attribute[tile_pos];
-                    systems.gfx.set_text(&view_attr.text, &text);
-                    systems
-                        .gfx
-                        .center_text(&mut systems.renderer, &view_attr.text);
-                    systems.gfx.set_color(&view_attr.bg, color);
-                }
+// Undo or redo until the amount of applied changes matches the index
+pub fn jump_to_history(content: &mut Content, systems: &mut SystemHolder, index: usize) {
+    while content.data.undo.len() > index {
+        apply_undo(content, systems);
+    }
+
+    while content.data.undo.len() < index && !content.data.redo.is_empty() {
+        apply_redo(content, systems);
+    }
+}
+
+fn apply_collision_change(
+    content: &mut Content,
+    systems: &mut SystemHolder,
+    collision: &[CollisionChangeData],
+    undo: bool,
+) {
+    for changes in collision.iter() {
+        let (attribute, dirblock) = if undo {
+            (&changes.from_attr, changes.from_dirblock)
+        } else {
+            (&changes.to_attr, changes.to_dirblock)
+        };
+        set_tile_collision(
+            content,
+            systems,
+            changes.x,
+            changes.y,
+            attribute.clone(),
+            dirblock,
+        );
+    }
+}
+
+fn apply_change(
+    content: &mut Content,
+    systems: &mut SystemHolder,
+    data: &EditorChange,
+    undo: bool,
+) {
+    let cur_layer = convert_layer_button_to_index(content.interface.tool.cur_layer);
+
+    match data {
+        EditorChange::Attr(attr) => {
+            for changes in attr.change.iter() {
+                let attribute = if undo { &changes.from } else { &changes.to };
+                let tile_pos = get_tile_pos(changes.x as i32, changes.y as i32);
+                let (color, text) = get_attribute_visual(attribute);
+                content.data.mapdata.attribute[tile_pos] = attribute.clone();
+
+                let view_attr = content.map_view.attribute[tile_pos];
+                systems.gfx.set_text(&view_attr.text, &text);
+                systems
+                    .gfx
+                    .center_text(&mut systems.renderer, &view_attr.text);
+                systems.gfx.set_color(&view_attr.bg, color);
             }
-            EditorChange::Tile(tile) => {
-                for changes in tile.change.iter() {
-                    content.map_view.map.set_tile(
-                        UVec3::new(changes.x as u32, changes.y as u32, changes.layer as u32),
-                        TileData {
-                            id: changes.to,
-                            color: Color::rgb(255, 255, 255),
-                            anim_time: 250,
-                        },
+        }
+        EditorChange::Tile(tile) => {
+            for changes in tile.change.iter() {
+                let id = if undo { changes.from } else { changes.to };
+                if let Some(link) = changes.link {
+                    set_link_tile(
+                        content,
+                        systems,
+                        link,
+                        (changes.x as u32, changes.y as u32),
+                        changes.layer,
+                        id,
                     );
+                    continue;
+                }
 
-                    let tile_pos = get_tile_pos(changes.x as i32, changes.y as i32);
-                    content.data.mapdata.tile[changes.layer].id[tile_pos] = changes.to as u32;
+                let tile_pos = get_tile_pos(changes.x as i32, changes.y as i32);
+                content.map_view.map.set_tile(
+                    UVec3::new(changes.x as u32, changes.y as u32, changes.layer as u32),
+                    TileData {
+                        id,
+                        color: get_layer_color(systems, changes.layer, cur_layer),
+                        anim_time: content.data.mapdata.anim_time[tile_pos],
+                    },
+                );
+
+                content.data.mapdata.tile[changes.layer].id[tile_pos] = id as u32;
+            }
+            apply_collision_change(content, systems, &tile.collision, undo);
+            for changes in tile.anim_time.iter() {
+                let anim_time = if undo { changes.from } else { changes.to };
+                set_tile_anim_time(content, changes.x, changes.y, anim_time);
+            }
+        }
+        EditorChange::Collision(collision) => {
+            apply_collision_change(content, systems, &collision.change, undo);
+        }
+        EditorChange::DirBlock(dirblock) => {
+            for changes in dirblock.change.iter() {
+                let dir = if undo { changes.from } else { changes.to };
+                let tile_pos = get_tile_pos(changes.x as i32, changes.y as i32);
+                content.data.mapdata.dir_block[tile_pos] = dir;
+
+                let dirblock_uv = get_dirblock_uv(dir);
+                systems.gfx.set_uv(
+                    &content.map_view.dir_block[tile_pos],
+                    Vec4::new(
+                        20.0 * dirblock_uv.0 as f32,
+                        20.0 * dirblock_uv.1 as f32,
+                        20.0,
+                        20.0,
+                    ),
+                );
+            }
+        }
+        EditorChange::ZoneSpawn(zonespawn) => {
+            for changes in zonespawn.change.iter() {
+                let data = (changes.x, changes.y);
+                let Some(spawns) = content.data.mapdata.zonespawns.get_mut(changes.zone) else {
+                    continue;
+                };
+
+                // Undo removes what was added and adds back what was removed
+                if changes.set != undo {
+                    if !spawns.contains(&data) {
+                        spawns.push(data);
+                    }
+                } else if let Some(index) = spawns.iter().position(|check| *check == data) {
+                    spawns.remove(index);
                 }
             }
+
+            update_zone_visible(content, systems);
+        }
+        EditorChange::ZoneSetting(setting) => {
+            if let Some(zone) = content.data.mapdata.zones.get_mut(setting.zone) {
+                *zone = if undo {
+                    setting.from.clone()
+                } else {
+                    setting.to.clone()
+                };
+            }
+
+            update_zone_list(content, systems);
+            if content.interface.side_window.zone.cur_zone == setting.zone {
+                update_zone_textbox(content, systems);
+            }
         }
+        EditorChange::ZoneList(change) => {
+            let mapdata = &mut content.data.mapdata;
+
+            // Undo removes what was added and adds back what was removed
+            if change.add != undo {
+                let index = change.zone.min(mapdata.zones.len());
+                mapdata.zones.insert(index, change.data.clone());
+                mapdata.zonespawns.insert(index, change.spawns.clone());
+            } else if change.zone < mapdata.zones.len() {
+                mapdata.zones.remove(change.zone);
+                mapdata.zonespawns.remove(change.zone);
+            }
 
-        content.data.changed = true;
-        content.data.temp_saved = false;
-        if let Some(map_pos) = content.data.pos {
-            content
-                .interface
-                .footer
-                .set_map_pos(systems, map_pos, false);
+            content.interface.side_window.zone.cur_zone = change.zone;
+            content.interface.side_window.zone.cur_npc = None;
+            update_zone_list(content, systems);
+            update_zone_textbox(content, systems);
+            update_zone_visible(content, systems);
         }
+        EditorChange::Weather(from, to) => {
+            let weather = if undo { *from } else { *to };
+            content.data.mapdata.weather = weather;
+
+            let gui = &mut content.interface.side_window.weather;
+            gui.weather_list
+                .list
+                .set_select(systems, Some(weather as usize), true);
+            gui.weather_list.update_label(systems, weather as usize);
+        }
+        EditorChange::Music(from, to) => {
+            let music = if undo { from.clone() } else { to.clone() };
+
+            let music_index = content
+                .audio_collection
+                .audio
+                .iter()
+                .position(|data| Some(data) == music.as_ref())
+                .unwrap_or(0);
+            content.data.mapdata.music = music;
+
+            content.interface.side_window.music.music_list.set_select(
+                systems,
+                Some(music_index),
+                true,
+            );
+        }
+        EditorChange::Transform(_, from, to) => {
+            if let Ok(mapdata) = MapData::read_from_buffer(if undo { from } else { to }) {
+                apply_map_data(content, systems, &mapdata);
+                content.data.mapdata = mapdata;
+            }
+        }
+    }
+
+    content.data.changed = true;
+    content.data.temp_saved = false;
+    if let Some(map_pos) = content.data.pos {
+        content
+            .interface
+            .footer
+            .set_map_pos(systems, map_pos, false);
     }
 }
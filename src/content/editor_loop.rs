@@ -1,7 +1,9 @@
 use ascending_graphics::MapRenderer;
 
 use crate::{
-    content::Content,
+    content::{
+        Content, history_update_list, inspector_update, save_link_map_temp, warp_source_update_list,
+    },
     data_types::Result,
     database::{delete_recovery_map_file, is_recovery_map_file_exist, save_temp_file},
     renderer::SystemHolder,
@@ -42,8 +44,32 @@ pub fn editor_loop(
     }
 
     if seconds > loop_timer.tileset_frame {
-        content.interface.side_window.presets.update_frames(systems);
-        loop_timer.tileset_frame = seconds + 0.2;
+        let presets = &mut content.interface.side_window.presets;
+        presets.update_frames(systems);
+        loop_timer.tileset_frame = seconds + (presets.base.anim_time as f32 / 1000.0);
+    }
+
+    if content.data.history_updated {
+        content.data.history_updated = false;
+        content.interface.side_window.inspector.need_update = true;
+        if content.interface.side_window.history.visible {
+            history_update_list(content, systems);
+        }
+    }
+
+    {
+        let gui = &content.interface.side_window.warp_source;
+        if gui.visible && (gui.need_update || gui.scanned_pos != content.data.pos) {
+            warp_source_update_list(content, systems)?;
+        }
+    }
+
+    {
+        let gui = &content.interface.side_window.inspector;
+        let hovered = !gui.pinned && gui.shown_pos != Some(content.map_view.tile.cur_pos);
+        if gui.visible && (gui.need_update || hovered || gui.shown_map != content.data.pos) {
+            inspector_update(content, systems);
+        }
     }
 
     if seconds > loop_timer.file_tmr {
@@ -56,6 +82,9 @@ pub fn editor_loop(
                     &content.data.mapdata,
                     true,
                 )?;
+                content
+                    .data
+                    .save_history(systems.config.save_undo_history)?;
 
                 content.interface.notification.add_msg(
                     systems,
@@ -69,6 +98,9 @@ pub fn editor_loop(
                 let _ = content.data.unsaved_map.insert(mappos);
             } else {
                 save_temp_file(0, 0, 0, &content.data.mapdata, false)?;
+                content
+                    .data
+                    .save_history(systems.config.save_undo_history)?;
 
                 content.interface.notification.add_msg(
                     systems,
@@ -79,6 +111,7 @@ pub fn editor_loop(
 
             content.data.temp_saved = true;
         }
+        save_link_map_temp(content)?;
 
         loop_timer.file_tmr = seconds + 60.0;
     }
@@ -5,18 +5,17 @@ use winit::event_loop::ActiveEventLoop;
 
 use crate::{
     content::{
-        Content, MapPosInputType, apply_redo, apply_undo, get_link_map_pos, get_tile_pos,
-        interface_input, load_and_apply_map, map_view, picker_attribute_update,
-        picker_layer_update, save_map_change, set_preset, update_attribute_fill,
-        update_map_attribute, update_map_dirblock, update_map_tile, update_map_zone,
-        update_tile_fill,
-        widget::{
-            Alert, AlertBuilder, AlertIndex, Tooltip, in_drawing_area, in_layer_area,
-            in_view_screen,
-        },
+        Content, MapPosInputType, PresetWindowType, apply_rect_paint, apply_redo, apply_undo,
+        get_link_map_pos, get_tile_pos, goto_warp_target, interface_input, is_paired_warp_mode,
+        is_rect_paint, map_view, open_map, paint_terrain, pick_warp_target,
+        picker_attribute_update, picker_layer_update, place_paired_warp, preset_update_list,
+        save_map_change, set_preset, start_rect_paint, update_attribute_fill, update_dirblock_fill,
+        update_layer_visual, update_map_attribute, update_map_dirblock, update_map_tile,
+        update_map_zone, update_measure, update_tile_fill, update_zone_fill, update_zone_setting,
+        widget::{Alert, AlertBuilder, Tooltip, in_drawing_area, in_layer_area, in_view_screen},
     },
     data_types::{MouseInputType, Result, SelectedTextbox, TEXTURE_SIZE, TabButton, ToolType},
-    database::{EditorMapAttribute, is_temp_map_exist},
+    database::EditorMapAttribute,
     renderer::{Graphics, SystemHolder},
 };
 
@@ -83,12 +82,12 @@ pub fn handle_input(
 
         match inputtype {
             MouseInputType::LeftDown => {
-                if cur_tool == ToolType::Move {
+                if cur_tool == ToolType::Move && !content.input.shift_down {
                     content.map_view.set_map_drag(mouse_pos)
                 }
             }
             MouseInputType::LeftDownMove => {
-                if cur_tool == ToolType::Move {
+                if cur_tool == ToolType::Move && !content.map_view.selection.in_hold {
                     content.map_view.last_camera_pos =
                         content.map_view.update_map_drag(graphics, mouse_pos);
                 }
@@ -122,31 +121,7 @@ pub fn handle_input(
                     && let Some(check_pos) = content.data.pos
                 {
                     let mappos = get_link_map_pos(check_pos, link_index);
-
-                    if is_temp_map_exist(mappos.x, mappos.y, mappos.group as u64) {
-                        alert.show_alert(
-                            systems,
-                            AlertBuilder::new_confirm(
-                                "Temp File",
-                                "Temporary file found! Would you like to load this file?",
-                            )
-                            .with_index(AlertIndex::LoadTempFile(mappos)),
-                        );
-                    } else if load_and_apply_map(systems, content, mappos, seconds)? {
-                        content.interface.notification.add_msg(
-                            systems,
-                            format!(
-                                "Map [X: {} Y: {} Group: {}] Loaded!",
-                                mappos.x, mappos.y, mappos.group
-                            ),
-                            seconds,
-                        );
-                    } else {
-                        alert.show_alert(
-                            systems,
-                            &AlertBuilder::new_info("Error", "Failed to load map"),
-                        );
-                    }
+                    open_map(content, systems, alert, mappos, None, seconds)?;
 
                     return Ok(());
                 }
@@ -161,11 +136,49 @@ pub fn handle_input(
 
         if in_drawing_area(content, systems, mouse_pos) {
             match inputtype {
+                MouseInputType::LeftDown if content.input.shift_down => {
+                    content.map_view.hover_tile(systems, mouse_pos);
+                    content.map_view.start_selection(systems);
+                }
+                MouseInputType::LeftDown | MouseInputType::RightDown
+                    if cur_tool == ToolType::Paint && is_rect_paint(content) =>
+                {
+                    content.map_view.hover_tile(systems, mouse_pos);
+                    start_rect_paint(
+                        content,
+                        systems,
+                        matches!(inputtype, MouseInputType::LeftDown),
+                    );
+                }
+                MouseInputType::LeftDown
+                    if content
+                        .interface
+                        .side_window
+                        .attributes
+                        .attr_position
+                        .picking =>
+                {
+                    content.map_view.hover_tile(systems, mouse_pos);
+                    pick_warp_target(content, systems, alert, seconds)?;
+                }
+                MouseInputType::DoubleLeftDown if cur_tool == ToolType::Move => {
+                    content.map_view.hover_tile(systems, mouse_pos);
+                    goto_warp_target(content, systems, graphics, alert, seconds)?;
+                }
+                MouseInputType::LeftDownMove | MouseInputType::RightDownMove
+                    if content.map_view.selection.in_hold =>
+                {
+                    content.map_view.hover_tile(systems, mouse_pos);
+                    content.map_view.update_selection(systems);
+                }
                 MouseInputType::LeftDown => {
                     content.map_view.last_camera_pos = content.map_view.camera_pos;
                     match cur_tool {
                         ToolType::Paint => match cur_tab {
                             TabButton::Tileset => update_map_tile(content, systems, true),
+                            TabButton::Attributes if is_paired_warp_mode(content) => {
+                                place_paired_warp(content, systems, alert, seconds)?
+                            }
                             TabButton::Attributes => update_map_attribute(content, systems, true),
                             TabButton::CustomTiles => set_preset(content, systems),
                             TabButton::DirBlock => update_map_dirblock(content, systems, true),
@@ -184,6 +197,8 @@ pub fn handle_input(
                         ToolType::Fill => match cur_tab {
                             TabButton::Tileset => update_tile_fill(content, systems, true),
                             TabButton::Attributes => update_attribute_fill(content, systems, true),
+                            TabButton::DirBlock => update_dirblock_fill(content, systems, true),
+                            TabButton::Zones => update_zone_fill(content, systems, true),
                             _ => {}
                         },
                         ToolType::Picker => match cur_tab {
@@ -191,6 +206,8 @@ pub fn handle_input(
                             TabButton::Attributes => picker_attribute_update(content, systems),
                             _ => {}
                         },
+                        ToolType::Measure => update_measure(content, systems),
+                        ToolType::Terrain => paint_terrain(content, systems),
                         _ => {}
                     }
                 }
@@ -199,6 +216,8 @@ pub fn handle_input(
                     match cur_tool {
                         ToolType::Paint => match cur_tab {
                             TabButton::Tileset => update_map_tile(content, systems, true),
+                            // A paired warp is placed once per click
+                            TabButton::Attributes if is_paired_warp_mode(content) => {}
                             TabButton::Attributes => update_map_attribute(content, systems, true),
                             TabButton::CustomTiles => set_preset(content, systems),
                             TabButton::DirBlock => update_map_dirblock(content, systems, true),
@@ -214,6 +233,7 @@ pub fn handle_input(
                             TabButton::Zones => update_map_zone(content, systems, false),
                             _ => {}
                         },
+                        ToolType::Terrain => paint_terrain(content, systems),
                         _ => {}
                     }
                 }
@@ -247,13 +267,24 @@ pub fn handle_input(
                         ToolType::Fill => match cur_tab {
                             TabButton::Tileset => update_tile_fill(content, systems, false),
                             TabButton::Attributes => update_attribute_fill(content, systems, false),
+                            TabButton::DirBlock => update_dirblock_fill(content, systems, false),
+                            TabButton::Zones => update_zone_fill(content, systems, false),
                             _ => {}
                         },
                         _ => {}
                     }
                 }
                 MouseInputType::Move => match cur_tool {
-                    ToolType::Paint | ToolType::Eraser | ToolType::Fill | ToolType::Picker => {
+                    ToolType::Paint
+                    | ToolType::Eraser
+                    | ToolType::Fill
+                    | ToolType::Picker
+                    | ToolType::Measure
+                    | ToolType::Terrain => {
+                        content.map_view.hover_tile(systems, mouse_pos);
+                    }
+                    // The inspector follows the hovered tile regardless of the tool
+                    ToolType::Move if cur_tab == TabButton::Inspector => {
                         content.map_view.hover_tile(systems, mouse_pos);
                     }
                     _ => {}
@@ -277,6 +308,8 @@ pub fn handle_input(
 
     if let MouseInputType::Release = inputtype {
         content.map_view.clear_map_drag();
+        apply_rect_paint(content, systems);
+        content.map_view.selection.in_hold = false;
         content.data.record_placeholder();
 
         content.map_view.camera_pos = content.map_view.last_camera_pos;
@@ -284,17 +317,19 @@ pub fn handle_input(
     Ok(())
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn handle_key_input(
     key: &Key,
     pressed: bool,
     content: &mut Content,
     systems: &mut SystemHolder,
+    graphics: &mut Graphics<FlatControls>,
     alert: &mut Alert,
     elwt: &ActiveEventLoop,
     seconds: f32,
 ) -> Result<()> {
     if alert.visible {
-        if alert.alert_key_input(content, systems, elwt, seconds, key, pressed)? {
+        if alert.alert_key_input(content, systems, graphics, elwt, seconds, key, pressed)? {
             return Ok(());
         }
 
@@ -374,24 +409,32 @@ pub fn handle_key_input(
         }
         SelectedTextbox::ZoneTextbox => {
             if let Some(index) = content.interface.side_window.zone.cur_textbox {
-                content.interface.side_window.zone.textbox[index]
-                    .enter_text(systems, key, pressed, true);
-
-                let result = content.interface.side_window.zone.textbox[index]
-                    .text
-                    .parse::<u64>()
-                    .ok();
-
-                if index == 0 {
-                    if let Some(val) = result {
-                        content.data.mapdata.zones[content.interface.side_window.zone.cur_zone].0 =
-                            val;
-                    }
-                } else {
-                    let id = index.saturating_sub(1);
-                    content.data.mapdata.zones[content.interface.side_window.zone.cur_zone].1[id] =
-                        result;
-                }
+                // Only the zone name takes text
+                content.interface.side_window.zone.textbox[index].enter_text(
+                    systems,
+                    key,
+                    pressed,
+                    index != 0,
+                );
+                update_zone_setting(content, systems, index);
+            }
+        }
+        SelectedTextbox::TilesetTextbox => {
+            let gui = &mut content.interface.side_window.tilesets;
+            if let Some(index) = gui.cur_textbox {
+                // Only the anim frames take a number
+                gui.textbox[index].enter_text(systems, key, pressed, index == 3);
+                gui.update_tileset_setting(systems, index);
+            }
+        }
+        SelectedTextbox::PresetTextbox => {
+            let gui = &mut content.interface.side_window.presets;
+            // Only the frame time of the editor takes a number
+            let is_editor = gui.window_type == PresetWindowType::Editor;
+            gui.get_cur_textbox()
+                .enter_text(systems, key, pressed, is_editor);
+            if !is_editor {
+                preset_update_list(content, systems);
             }
         }
         SelectedTextbox::MapPosTextbox => {
@@ -403,6 +446,11 @@ pub fn handle_key_input(
         SelectedTextbox::None => match key {
             Key::Named(Named::Control) => content.input.ctrl_down = pressed,
             Key::Named(Named::Shift) => content.input.shift_down = pressed,
+            Key::Named(Named::Escape) => {
+                if pressed {
+                    content.map_view.clear_selection(systems);
+                }
+            }
             Key::Character('o') | Key::Character('O') => {
                 if pressed && content.input.ctrl_down {
                     content
@@ -482,6 +530,10 @@ pub fn handle_mouse_wheel(
         content.interface.tool.cur_layer = cur_layer;
         content.interface.tool.layer_button[content.interface.tool.cur_layer]
             .set_disable(systems, true);
+
+        if systems.config.dim_layers {
+            update_layer_visual(content, systems);
+        }
     } else if in_view_screen(systems, mouse_pos) {
         let zoom_value = content.interface.tool.zoom_scroll.value;
 
@@ -24,6 +24,7 @@ use menu_bar::*;
 use footer::*;
 pub use map_pos_input::*;
 use notification::*;
+pub use side_window::PresetWindowType;
 use side_window::*;
 use widget::*;
 
@@ -94,6 +95,14 @@ impl Interface {
                     self.side_window.zone.textbox[index].set_hold(false);
                 }
             }
+            SelectedTextbox::TilesetTextbox => {
+                if let Some(index) = self.side_window.tilesets.cur_textbox {
+                    self.side_window.tilesets.textbox[index].set_hold(false);
+                }
+            }
+            SelectedTextbox::PresetTextbox => {
+                self.side_window.presets.get_cur_textbox().set_hold(false)
+            }
             SelectedTextbox::MapPosTextbox => {
                 if let Some(index) = self.mappos_input.cur_textbox {
                     self.mappos_input.textbox[index].set_hold(false)
@@ -146,6 +155,16 @@ impl Interface {
                     self.side_window.zone.textbox[index].hold_move(systems, screen_pos);
                 }
             }
+            SelectedTextbox::TilesetTextbox => {
+                if let Some(index) = self.side_window.tilesets.cur_textbox {
+                    self.side_window.tilesets.textbox[index].hold_move(systems, screen_pos);
+                }
+            }
+            SelectedTextbox::PresetTextbox => self
+                .side_window
+                .presets
+                .get_cur_textbox()
+                .hold_move(systems, screen_pos),
             SelectedTextbox::MapPosTextbox => {
                 if let Some(index) = self.mappos_input.cur_textbox {
                     self.mappos_input.textbox[index].hold_move(systems, screen_pos);
@@ -196,6 +215,16 @@ impl Interface {
                     self.side_window.zone.textbox[index].set_select(systems, false);
                 }
             }
+            SelectedTextbox::TilesetTextbox => {
+                if let Some(index) = self.side_window.tilesets.cur_textbox {
+                    self.side_window.tilesets.textbox[index].set_select(systems, false);
+                }
+            }
+            SelectedTextbox::PresetTextbox => self
+                .side_window
+                .presets
+                .get_cur_textbox()
+                .set_select(systems, false),
             SelectedTextbox::MapPosTextbox => {
                 if let Some(index) = self.mappos_input.cur_textbox {
                     self.mappos_input.textbox[index].set_select(systems, false);
@@ -240,6 +269,12 @@ impl Interface {
             SelectedTextbox::ZoneTextbox => {
                 self.side_window.zone.click_textbox(systems, screen_pos)
             }
+            SelectedTextbox::TilesetTextbox => {
+                self.side_window.tilesets.click_textbox(systems, screen_pos)
+            }
+            SelectedTextbox::PresetTextbox => {
+                self.side_window.presets.click_textbox(systems, screen_pos)
+            }
             SelectedTextbox::MapPosTextbox => {
                 if self.mappos_input.visible {
                     self.mappos_input.click_textbox(systems, screen_pos)
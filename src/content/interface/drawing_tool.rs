@@ -3,7 +3,8 @@ use ascending_graphics::*;
 use crate::{
     content::widget::{
         Button, ButtonChangeType, ButtonContentImg, ButtonContentText, ButtonContentType,
-        ButtonImage, ButtonRect, ButtonType, Scrollbar, ScrollbarBackground, ScrollbarRect,
+        ButtonImage, ButtonRect, ButtonType, CheckRect, CheckType, Checkbox, CheckboxChangeType,
+        CheckboxRect, CheckboxText, CheckboxType, Scrollbar, ScrollbarBackground, ScrollbarRect,
         create_label,
     },
     data_types::*,
@@ -19,6 +20,14 @@ pub struct DrawingTool {
     pub zoom_scroll: Scrollbar,
     pub zoom_label: GfxType,
     pub layer_button: Vec<Button>,
+    pub layer_visible: Vec<Checkbox>,
+    pub layer_lock: Vec<Checkbox>,
+    pub dim_layer: Checkbox,
+    fill_bg: GfxType,
+    pub fill_option: Vec<Checkbox>,
+    terrain_bg: GfxType,
+    pub terrain_button: Vec<Button>,
+    pub cur_terrain: usize,
 
     pub cur_tool: ToolType,
     pub cur_layer: usize,
@@ -90,6 +99,8 @@ impl DrawingTool {
                     2 => "Eraser Tool".to_string(),
                     3 => "Fill Tool".to_string(),
                     4 => "Picker Tool".to_string(),
+                    5 => "Measure Tool".to_string(),
+                    6 => "Terrain Tool".to_string(),
                     _ => "Move Tool".to_string(),
                 }),
                 false,
@@ -97,6 +108,148 @@ impl DrawingTool {
         }
         tool_button[0].set_disable(systems, true);
 
+        let max_fill = FillOption::Count as usize;
+        let fill_bg_pos = Vec2::new(
+            tool_bg_pos.x + tool_bg_size.x + (6.0 * systems.scale as f32).floor(),
+            tool_bg_pos.y,
+        );
+        let fill_bg_size =
+            (Vec2::new(10.0 + (86.0 * max_fill as f32), 34.0) * systems.scale as f32).floor();
+
+        let mut rect = Rect::new(
+            &mut systems.renderer,
+            Vec3::new(fill_bg_pos.x, fill_bg_pos.y, ORDER_WINDOW),
+            fill_bg_size,
+            Color::rgb(90, 90, 90),
+            0,
+        );
+        rect.set_border_color(Color::rgb(0, 0, 0)).set_radius(5.0);
+        let fill_bg = systems.gfx.add_rect(
+            rect,
+            RENDER_GUI,
+            "Fill Option BG",
+            false,
+            CameraView::SubView1,
+        );
+
+        let mut fill_option = Vec::with_capacity(max_fill);
+        for i in 0..max_fill {
+            fill_option.push(Checkbox::new(
+                systems,
+                CheckboxType::Rect(CheckboxRect {
+                    rect_color: Color::rgb(150, 150, 150),
+                    got_border: true,
+                    border_color: Color::rgb(0, 0, 0),
+                    border_radius: 0.0,
+                    hover_change: CheckboxChangeType::ColorChange(Color::rgb(180, 180, 180)),
+                    click_change: CheckboxChangeType::ColorChange(Color::rgb(120, 120, 120)),
+                    disable_change: CheckboxChangeType::None,
+                }),
+                CheckType::SetRect(CheckRect {
+                    rect_color: Color::rgb(60, 60, 60),
+                    got_border: false,
+                    border_color: Color::rgb(0, 0, 0),
+                    border_radius: 0.0,
+                    pos: Vec2::new(2.0, 2.0),
+                    size: Vec2::new(10.0, 10.0),
+                }),
+                fill_bg_pos,
+                Vec2::new(8.0 + (86.0 * i as f32), 10.0),
+                ORDER_WINDOW,
+                Vec2::new(14.0, 14.0),
+                RENDER_GUI,
+                1,
+                RENDER_GUI,
+                2,
+                Some(CheckboxText {
+                    text: match FillOption::from_index(i) {
+                        FillOption::EightWay => "8-Way".to_string(),
+                        FillOption::AllLayers => "All Layers".to_string(),
+                        FillOption::Selection => "Selection".to_string(),
+                        FillOption::Pattern => "Pattern".to_string(),
+                        FillOption::LinkedMaps => "Linked Maps".to_string(),
+                        _ => "Global".to_string(),
+                    },
+                    offset_pos: Vec2::new(3.0, -3.0),
+                    buffer_layer: RENDER_GUI_TEXT,
+                    order_layer: 1,
+                    label_size: Vec2::new(66.0, 20.0),
+                    color: Color::rgb(255, 255, 255),
+                    hover_change: CheckboxChangeType::None,
+                    click_change: CheckboxChangeType::None,
+                    disable_change: CheckboxChangeType::None,
+                }),
+                false,
+                Some(match FillOption::from_index(i) {
+                    FillOption::EightWay => "Fill diagonal neighbours".to_string(),
+                    FillOption::AllLayers => "Match tiles using every layer".to_string(),
+                    FillOption::Selection => "Only fill inside the map selection".to_string(),
+                    FillOption::Pattern => "Repeat the tileset selection".to_string(),
+                    FillOption::LinkedMaps => "Continue the fill into the linked maps".to_string(),
+                    _ => "Replace every matching tile".to_string(),
+                }),
+            ));
+        }
+
+        // Shares the spot of the fill options as only one of the tools can be active
+        let terrain_bg_size = (Vec2::new(238.0, 34.0) * systems.scale as f32).floor();
+
+        let mut rect = Rect::new(
+            &mut systems.renderer,
+            Vec3::new(fill_bg_pos.x, fill_bg_pos.y, ORDER_WINDOW),
+            terrain_bg_size,
+            Color::rgb(90, 90, 90),
+            0,
+        );
+        rect.set_border_color(Color::rgb(0, 0, 0)).set_radius(5.0);
+        let terrain_bg =
+            systems
+                .gfx
+                .add_rect(rect, RENDER_GUI, "Terrain BG", false, CameraView::SubView1);
+
+        let terrain_button_rect = ButtonRect {
+            rect_color: Color::rgb(80, 80, 80),
+            got_border: true,
+            border_color: Color::rgb(0, 0, 0),
+            border_radius: 0.0,
+            hover_change: ButtonChangeType::ColorChange(Color::rgb(50, 50, 50)),
+            click_change: ButtonChangeType::ColorChange(Color::rgb(80, 80, 80)),
+            alert_change: ButtonChangeType::None,
+            disable_change: ButtonChangeType::None,
+        };
+        let terrain_button = [
+            ("Terrain: None", Vec2::new(5.0, 5.0), Vec2::new(160.0, 24.0)),
+            ("Reload", Vec2::new(171.0, 5.0), Vec2::new(62.0, 24.0)),
+        ]
+        .into_iter()
+        .map(|(text, adjust_pos, size)| {
+            Button::new(
+                systems,
+                ButtonType::Rect(terrain_button_rect),
+                ButtonContentType::Text(ButtonContentText {
+                    text: text.to_string(),
+                    pos: Vec2::new(0.0, 0.0),
+                    color: Color::rgb(255, 255, 255),
+                    order_layer: 2,
+                    buffer_layer: RENDER_GUI_TEXT,
+                    hover_change: ButtonChangeType::None,
+                    click_change: ButtonChangeType::None,
+                    alert_change: ButtonChangeType::None,
+                    disable_change: ButtonChangeType::None,
+                }),
+                fill_bg_pos,
+                adjust_pos,
+                ORDER_WINDOW,
+                size,
+                1,
+                RENDER_GUI,
+                false,
+                None,
+                false,
+            )
+        })
+        .collect();
+
         let zoom_bg_size = (Vec2::new(160.0, 20.0) * systems.scale as f32).floor();
         let zoom_bg_pos = Vec2::new(
             (250.0 * systems.scale as f32).floor() + 10.0,
@@ -188,6 +341,7 @@ impl DrawingTool {
             .set_text(&zoom_label, &format!("{}%", 100 + (10 * zoom_scroll.value)));
         systems.gfx.center_text(&mut systems.renderer, &zoom_label);
 
+        let layer_pos = get_layer_pos(systems);
         let mut layer_button = Vec::with_capacity(MapLayers::Count as usize);
         for layer in 0..MapLayers::Count as usize {
             layer_button.push(Button::new(
@@ -204,10 +358,7 @@ impl DrawingTool {
                     disable_change: ButtonChangeType::ImageFrame(2),
                 }),
                 ButtonContentType::None,
-                Vec2::new(
-                    systems.size.width - (28.0 * systems.scale as f32).floor() - 14.0,
-                    10.0 + (20.0 * systems.scale as f32).floor(),
-                ),
+                layer_pos,
                 Vec2::new(
                     if matches!(layer, 1..=4) { 0.0 } else { 4.0 },
                     11.0 * layer as f32 + if matches!(layer, 7 | 8) { 10.0 } else { 0.0 },
@@ -227,6 +378,82 @@ impl DrawingTool {
         }
         layer_button[0].set_disable(systems, true);
 
+        let checkbox_rect = CheckboxRect {
+            rect_color: Color::rgb(150, 150, 150),
+            got_border: true,
+            border_color: Color::rgb(0, 0, 0),
+            border_radius: 0.0,
+            hover_change: CheckboxChangeType::ColorChange(Color::rgb(180, 180, 180)),
+            click_change: CheckboxChangeType::ColorChange(Color::rgb(120, 120, 120)),
+            disable_change: CheckboxChangeType::None,
+        };
+        let check_rect = CheckRect {
+            rect_color: Color::rgb(60, 60, 60),
+            got_border: false,
+            border_color: Color::rgb(0, 0, 0),
+            border_radius: 0.0,
+            pos: Vec2::new(2.0, 2.0),
+            size: Vec2::new(5.0, 5.0),
+        };
+
+        let mut layer_visible = Vec::with_capacity(MapLayers::Count as usize);
+        let mut layer_lock = Vec::with_capacity(MapLayers::Count as usize);
+        for layer in 0..MapLayers::Count as usize {
+            let map_layer = convert_layer_button_to_index(layer);
+            let y_pos = 11.0 * layer as f32
+                + if matches!(layer, 7 | 8) { 10.0 } else { 0.0 }
+                + if matches!(layer, 6 | 8) { 4.0 } else { 2.0 };
+
+            for (i, x_pos) in [-30.0, -19.0].into_iter().enumerate() {
+                let mut checkbox = Checkbox::new(
+                    systems,
+                    CheckboxType::Rect(checkbox_rect),
+                    CheckType::SetRect(check_rect),
+                    layer_pos,
+                    Vec2::new(x_pos, y_pos),
+                    ORDER_WINDOW,
+                    Vec2::new(9.0, 9.0),
+                    RENDER_GUI,
+                    1,
+                    RENDER_GUI,
+                    2,
+                    None,
+                    true,
+                    Some(if i == 0 {
+                        format!("Show {}", map_layer_name(map_layer))
+                    } else {
+                        format!("Lock {}", map_layer_name(map_layer))
+                    }),
+                );
+
+                if i == 0 {
+                    checkbox.set_value(systems, systems.config.layer_visible[map_layer]);
+                    layer_visible.push(checkbox);
+                } else {
+                    checkbox.set_value(systems, systems.config.layer_locked[map_layer]);
+                    layer_lock.push(checkbox);
+                }
+            }
+        }
+
+        let mut dim_layer = Checkbox::new(
+            systems,
+            CheckboxType::Rect(checkbox_rect),
+            CheckType::SetRect(check_rect),
+            layer_pos,
+            Vec2::new(-30.0, 119.0),
+            ORDER_WINDOW,
+            Vec2::new(9.0, 9.0),
+            RENDER_GUI,
+            1,
+            RENDER_GUI,
+            2,
+            None,
+            true,
+            Some("Dim Inactive Layers".to_string()),
+        );
+        dim_layer.set_value(systems, systems.config.dim_layers);
+
         DrawingTool {
             tool_bg,
             zoom_bg,
@@ -235,6 +462,14 @@ impl DrawingTool {
             zoom_scroll,
             zoom_label,
             layer_button,
+            layer_visible,
+            layer_lock,
+            dim_layer,
+            fill_bg,
+            fill_option,
+            terrain_bg,
+            terrain_button,
+            cur_terrain: 0,
             cur_layer: 0,
         }
     }
@@ -254,15 +489,38 @@ impl DrawingTool {
             button.set_pos(systems, tool_bg_pos);
         }
 
+        let tool_bg_size = systems.gfx.get_size(&self.tool_bg);
+        let fill_bg_pos = Vec2::new(
+            tool_bg_pos.x + tool_bg_size.x + (6.0 * systems.scale as f32).floor(),
+            tool_bg_pos.y,
+        );
+        systems.gfx.set_pos(
+            &self.fill_bg,
+            Vec3::new(fill_bg_pos.x, fill_bg_pos.y, ORDER_WINDOW),
+        );
+        for checkbox in self.fill_option.iter_mut() {
+            checkbox.set_pos(systems, fill_bg_pos);
+        }
+        systems.gfx.set_pos(
+            &self.terrain_bg,
+            Vec3::new(fill_bg_pos.x, fill_bg_pos.y, ORDER_WINDOW),
+        );
+        for button in self.terrain_button.iter_mut() {
+            button.set_pos(systems, fill_bg_pos);
+        }
+
+        let layer_pos = get_layer_pos(systems);
         for button in self.layer_button.iter_mut() {
-            button.set_pos(
-                systems,
-                Vec2::new(
-                    systems.size.width - (28.0 * systems.scale as f32).floor() - 10.0,
-                    10.0 + (20.0 * systems.scale as f32).floor(),
-                ),
-            );
+            button.set_pos(systems, layer_pos);
+        }
+        for checkbox in self
+            .layer_visible
+            .iter_mut()
+            .chain(self.layer_lock.iter_mut())
+        {
+            checkbox.set_pos(systems, layer_pos);
         }
+        self.dim_layer.set_pos(systems, layer_pos);
 
         let zoom_bg_pos = Vec2::new(
             (250.0 * systems.scale as f32).floor() + 10.0,
@@ -300,4 +558,36 @@ impl DrawingTool {
             .gfx
             .center_text(&mut systems.renderer, &self.zoom_label);
     }
+
+    pub fn set_fill_option_visible(&mut self, systems: &mut SystemHolder, visible: bool) {
+        systems.gfx.set_visible(&self.fill_bg, visible);
+        for checkbox in self.fill_option.iter_mut() {
+            checkbox.set_visible(systems, visible);
+        }
+    }
+
+    pub fn set_terrain_visible(&mut self, systems: &mut SystemHolder, visible: bool) {
+        systems.gfx.set_visible(&self.terrain_bg, visible);
+        for button in self.terrain_button.iter_mut() {
+            button.set_visible(systems, visible);
+        }
+    }
+
+    pub fn set_terrain_label(&mut self, systems: &mut SystemHolder, name: Option<&str>) {
+        self.terrain_button[0].change_text(systems, format!("Terrain: {}", name.unwrap_or("None")));
+    }
+
+    pub fn is_fill_option(&self, option: FillOption) -> bool {
+        self.fill_option
+            .get(option as usize)
+            .is_some_and(|checkbox| checkbox.value)
+    }
+}
+
+// Layer buttons and their checkboxes share the same base position
+fn get_layer_pos(systems: &SystemHolder) -> Vec2 {
+    Vec2::new(
+        systems.size.width - (28.0 * systems.scale as f32).floor() - 14.0,
+        10.0 + (20.0 * systems.scale as f32).floor(),
+    )
 }
@@ -12,6 +12,7 @@ pub struct Footer {
     bg: GfxType,
     map_pos: GfxType,
     tile_pos: GfxType,
+    measure: GfxType,
 }
 
 impl Footer {
@@ -95,10 +96,38 @@ impl Footer {
         );
         systems.gfx.set_text(&tile_pos, &message);
 
+        let text_pos = pos + Vec3::new((260.0 * systems.scale as f32).floor(), 0.0, 0.0);
+        let text_size = (Vec2::new(300.0, 20.0) * systems.scale as f32).floor();
+
+        let text = create_label(
+            systems,
+            text_pos,
+            text_size,
+            Bounds::new(
+                text_pos.x,
+                text_pos.y,
+                text_pos.x + text_size.x,
+                text_pos.y + text_size.y,
+            ),
+            Color::rgb(255, 255, 255),
+            1,
+            16.0,
+            16.0,
+            true,
+        );
+        let measure = systems.gfx.add_text(
+            text,
+            RENDER_GUI_TEXT,
+            "Measure Text",
+            true,
+            CameraView::SubView1,
+        );
+
         Footer {
             bg,
             map_pos,
             tile_pos,
+            measure,
         }
     }
 
@@ -148,6 +177,20 @@ impl Footer {
                 text_pos.y + text_size.y,
             )),
         );
+
+        let text_pos = pos + Vec3::new((260.0 * systems.scale as f32).floor(), 0.0, 0.0);
+        let text_size = (Vec2::new(300.0, 20.0) * systems.scale as f32).floor();
+
+        systems.gfx.set_pos(&self.measure, text_pos);
+        systems.gfx.set_bound(
+            &self.measure,
+            Some(Bounds::new(
+                text_pos.x,
+                text_pos.y,
+                text_pos.x + text_size.x,
+                text_pos.y + text_size.y,
+            )),
+        );
     }
 
     pub fn set_map_pos(&mut self, systems: &mut SystemHolder, map_pos: MapPosition, saved: bool) {
@@ -170,6 +213,10 @@ impl Footer {
         );
     }
 
+    pub fn set_measure(&mut self, systems: &mut SystemHolder, msg: &str) {
+        systems.gfx.set_text(&self.measure, msg);
+    }
+
     pub fn remove_map_pos(&mut self, systems: &mut SystemHolder) {
         systems.gfx.set_text(&self.map_pos, "Unsaved");
     }
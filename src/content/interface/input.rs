@@ -41,7 +41,7 @@ pub fn interface_input(
 
     if alert.visible {
         alert.alert_mouse_input(
-            systems, content, elwt, inputtype, tooltip, mouse_pos, seconds,
+            systems, graphics, content, elwt, inputtype, tooltip, mouse_pos, seconds,
         )?;
         return Ok(true);
     }
@@ -56,7 +56,7 @@ pub fn interface_input(
     match inputtype {
         MouseInputType::LeftDown => {
             if menu_bar_click_widget(content, systems, alert, mouse_pos, seconds)?
-                || tool_click_widget(content, systems, mouse_pos)
+                || tool_click_widget(content, systems, alert, mouse_pos)?
                 || side_click_widget(content, systems, alert, mouse_pos)?
             //|| sample_click_widget(content, systems, alert, mouse_pos)
             {
@@ -2,11 +2,24 @@ use ascending_camera::controls::FlatControls;
 use ascending_graphics::Vec2;
 
 use crate::{
-    content::{Content, interface::drawing_tool::DrawingTool, widget::Tooltip},
-    data_types::ToolType,
+    content::{
+        Content, clear_measure,
+        interface::drawing_tool::DrawingTool,
+        update_layer_visual,
+        widget::{Alert, AlertBuilder, Tooltip},
+    },
+    data_types::{Result, ToolType, convert_layer_button_to_index},
+    database::Terrains,
     renderer::{Graphics, SystemHolder},
 };
 
+#[derive(Clone, Copy)]
+pub enum LayerToggle {
+    Visible(usize),
+    Lock(usize),
+    Dim,
+}
+
 impl DrawingTool {
     pub fn hover_widgets(
         &mut self,
@@ -36,6 +49,38 @@ impl DrawingTool {
             }
         }
 
+        for checkbox in self
+            .layer_visible
+            .iter_mut()
+            .chain(self.layer_lock.iter_mut())
+            .chain(std::iter::once(&mut self.dim_layer))
+        {
+            let in_hover = checkbox.in_area(systems, mouse_pos);
+            checkbox.set_hover(systems, in_hover);
+
+            if in_hover && let Some(msg) = &checkbox.tooltip {
+                tooltip.init_tooltip(systems, mouse_pos, msg.clone(), false);
+            }
+        }
+
+        if self.cur_tool == ToolType::Fill {
+            for checkbox in self.fill_option.iter_mut() {
+                let in_hover = checkbox.in_area(systems, mouse_pos);
+                checkbox.set_hover(systems, in_hover);
+
+                if in_hover && let Some(msg) = &checkbox.tooltip {
+                    tooltip.init_tooltip(systems, mouse_pos, msg.clone(), false);
+                }
+            }
+        }
+
+        if self.cur_tool == ToolType::Terrain {
+            for button in self.terrain_button.iter_mut() {
+                let in_hover = button.in_area(systems, mouse_pos);
+                button.set_hover(systems, in_hover);
+            }
+        }
+
         let scrollbar_hover = self.zoom_scroll.in_scroll(mouse_pos);
         self.zoom_scroll.set_hover(systems, scrollbar_hover);
     }
@@ -44,9 +89,22 @@ impl DrawingTool {
         for button in self.tool_button.iter_mut() {
             button.set_click(systems, false);
         }
-        for button in self.layer_button.iter_mut() {
+        for button in self
+            .layer_button
+            .iter_mut()
+            .chain(self.terrain_button.iter_mut())
+        {
             button.set_click(systems, false);
         }
+        for checkbox in self
+            .layer_visible
+            .iter_mut()
+            .chain(self.layer_lock.iter_mut())
+            .chain(std::iter::once(&mut self.dim_layer))
+            .chain(self.fill_option.iter_mut())
+        {
+            checkbox.set_click(systems, false);
+        }
         self.zoom_scroll.set_hold(systems, false, mouse_pos);
     }
 
@@ -88,6 +146,92 @@ impl DrawingTool {
 
         None
     }
+
+    pub fn click_layer_toggle(
+        &mut self,
+        systems: &mut SystemHolder,
+        mouse_pos: Vec2,
+    ) -> Option<LayerToggle> {
+        for (index, checkbox) in self.layer_visible.iter_mut().enumerate() {
+            if checkbox.in_area(systems, mouse_pos) {
+                checkbox.set_click(systems, true);
+                return Some(LayerToggle::Visible(index));
+            }
+        }
+
+        for (index, checkbox) in self.layer_lock.iter_mut().enumerate() {
+            if checkbox.in_area(systems, mouse_pos) {
+                checkbox.set_click(systems, true);
+                return Some(LayerToggle::Lock(index));
+            }
+        }
+
+        if self.dim_layer.in_area(systems, mouse_pos) {
+            self.dim_layer.set_click(systems, true);
+            return Some(LayerToggle::Dim);
+        }
+
+        None
+    }
+
+    pub fn click_fill_option(&mut self, systems: &mut SystemHolder, mouse_pos: Vec2) -> bool {
+        if self.cur_tool != ToolType::Fill {
+            return false;
+        }
+
+        for checkbox in self.fill_option.iter_mut() {
+            if checkbox.in_area(systems, mouse_pos) {
+                checkbox.set_click(systems, true);
+                return true;
+            }
+        }
+
+        false
+    }
+
+    pub fn click_terrain_button(
+        &mut self,
+        systems: &mut SystemHolder,
+        mouse_pos: Vec2,
+    ) -> Option<usize> {
+        if self.cur_tool != ToolType::Terrain {
+            return None;
+        }
+
+        for (index, button) in self.terrain_button.iter_mut().enumerate() {
+            if button.in_area(systems, mouse_pos) {
+                button.set_click(systems, true);
+                return Some(index);
+            }
+        }
+
+        None
+    }
+}
+
+// The terrain file is edited by hand, so it gets read again whenever the tool is picked
+fn reload_terrain(content: &mut Content, systems: &mut SystemHolder, alert: &mut Alert) {
+    match Terrains::load_data() {
+        Ok(data) => content.terrain = data,
+        Err(e) => {
+            content.terrain.load_failed = true;
+            alert.show_alert(
+                systems,
+                &AlertBuilder::new_info("Error", &format!("Failed to load terrains.json: {e}")),
+            );
+        }
+    }
+
+    let gui = &mut content.interface.tool;
+    if gui.cur_terrain >= content.terrain.terrain.len() {
+        gui.cur_terrain = 0;
+    }
+    let name = content
+        .terrain
+        .terrain
+        .get(gui.cur_terrain)
+        .map(|terrain| terrain.name.as_str());
+    gui.set_terrain_label(systems, name);
 }
 
 pub fn drawingtool_hold_move_scrollbar(
@@ -120,22 +264,52 @@ pub fn drawingtool_hold_move_scrollbar(
 pub fn tool_click_widget(
     content: &mut Content,
     systems: &mut SystemHolder,
+    alert: &mut Alert,
     mouse_pos: Vec2,
-) -> bool {
+) -> Result<bool> {
     let gui = &mut content.interface.tool;
 
     if gui.hold_scrollbar(systems, mouse_pos) {
-        return true;
+        return Ok(true);
     }
 
     if let Some(index) = gui.click_tool_button(systems, mouse_pos) {
         let tool = ToolType::from_index(index);
         if tool != gui.cur_tool {
+            let old_tool = gui.cur_tool;
             gui.tool_button[gui.cur_tool as usize].set_disable(systems, false);
             gui.cur_tool = tool;
             gui.tool_button[gui.cur_tool as usize].set_disable(systems, true);
+            gui.set_fill_option_visible(systems, tool == ToolType::Fill);
+            gui.set_terrain_visible(systems, tool == ToolType::Terrain);
+
+            if old_tool == ToolType::Measure {
+                clear_measure(content, systems);
+            }
+            if tool == ToolType::Terrain {
+                reload_terrain(content, systems, alert);
+            }
         }
-        return true;
+        return Ok(true);
+    }
+
+    if let Some(index) = gui.click_terrain_button(systems, mouse_pos) {
+        match index {
+            0 => {
+                let count = content.terrain.terrain.len();
+                if count > 0 {
+                    gui.cur_terrain = (gui.cur_terrain + 1) % count;
+                }
+                let name = content
+                    .terrain
+                    .terrain
+                    .get(gui.cur_terrain)
+                    .map(|terrain| terrain.name.as_str());
+                gui.set_terrain_label(systems, name);
+            } // Switch Terrain
+            _ => reload_terrain(content, systems, alert), // Reload
+        }
+        return Ok(true);
     }
 
     if let Some(index) = gui.click_layer_button(systems, mouse_pos) {
@@ -143,9 +317,37 @@ pub fn tool_click_widget(
             gui.layer_button[gui.cur_layer].set_disable(systems, false);
             gui.cur_layer = index;
             gui.layer_button[gui.cur_layer].set_disable(systems, true);
+
+            if systems.config.dim_layers {
+                update_layer_visual(content, systems);
+            }
+        }
+        return Ok(true);
+    }
+
+    if gui.click_fill_option(systems, mouse_pos) {
+        return Ok(true);
+    }
+
+    if let Some(toggle) = gui.click_layer_toggle(systems, mouse_pos) {
+        match toggle {
+            LayerToggle::Visible(index) => {
+                systems.config.layer_visible[convert_layer_button_to_index(index)] =
+                    gui.layer_visible[index].value;
+                update_layer_visual(content, systems);
+            }
+            LayerToggle::Lock(index) => {
+                systems.config.layer_locked[convert_layer_button_to_index(index)] =
+                    gui.layer_lock[index].value;
+            }
+            LayerToggle::Dim => {
+                systems.config.dim_layers = gui.dim_layer.value;
+                update_layer_visual(content, systems);
+            }
         }
-        return true;
+        systems.config.save_config()?;
+        return Ok(true);
     }
 
-    false
+    Ok(false)
 }
@@ -4,11 +4,14 @@ use crate::{
     content::{
         Content,
         interface::map_pos_input::{MapPosInput, MapPosInputType},
-        load_and_apply_map, save_map_change,
+        open_map, save_map_change,
         widget::{Alert, AlertBuilder, AlertIndex, Tooltip},
     },
     data_types::{MouseInputType, Result, SelectedTextbox},
-    database::{MapPosition, delete_temp_map_file, is_temp_map_exist, map},
+    database::{
+        MapPosition, create_map_from_template, delete_temp_map_file, is_map_exist,
+        is_temp_map_exist, map,
+    },
     renderer::SystemHolder,
 };
 use ascending_input::{Key, Named};
@@ -110,6 +113,11 @@ pub fn mappos_mouse_input(
                 .mappos_input
                 .click_buttons(systems, screen_pos)
             {
+                if index == 2 {
+                    content.interface.mappos_input.switch_template(systems);
+                    return Ok(());
+                }
+
                 if index == 0 {
                     let mappos = MapPosition {
                         x: content.interface.mappos_input.textbox[0]
@@ -129,20 +137,25 @@ pub fn mappos_mouse_input(
                     // Confirm
                     match content.interface.mappos_input.input_type {
                         MapPosInputType::LoadMap => {
-                            if is_temp_map_exist(mappos.x, mappos.y, mappos.group as u64) {
-                                alert.show_alert(
-                                    systems,
-                                    AlertBuilder::new_confirm(
-                                        "Temp File",
-                                        "Temporary file found! Would you like to load this file?",
-                                    )
-                                    .with_index(AlertIndex::LoadTempFile(mappos)),
-                                );
-                            } else if load_and_apply_map(systems, content, mappos, seconds)? {
+                            if let Some(template) = content.interface.mappos_input.get_template()
+                                && !is_map_exist(mappos.x, mappos.y, mappos.group as u64)
+                            {
+                                create_map_from_template(
+                                    mappos.x,
+                                    mappos.y,
+                                    mappos.group as u64,
+                                    template,
+                                )?;
+                            }
+
+                            open_map(content, systems, alert, mappos, None, seconds)?;
+                        }
+                        MapPosInputType::SaveMap => {
+                            if save_map_change(content, mappos)? {
                                 content.interface.notification.add_msg(
                                     systems,
                                     format!(
-                                        "Map [X: {} Y: {} Group: {}] Loaded!",
+                                        "Map [X: {} Y: {} Group: {}] Saved!",
                                         mappos.x, mappos.y, mappos.group
                                     ),
                                     seconds,
@@ -150,25 +163,64 @@ pub fn mappos_mouse_input(
                             } else {
                                 alert.show_alert(
                                     systems,
-                                    &AlertBuilder::new_info("Error", "Failed to load map"),
+                                    &AlertBuilder::new_info("Error", "Failed to save map"),
                                 );
                             }
                         }
-                        MapPosInputType::SaveMap => {
-                            if save_map_change(content, mappos)? {
-                                content.interface.notification.add_msg(
+                        MapPosInputType::DuplicateMap | MapPosInputType::MoveMap => {
+                            let is_move = matches!(
+                                content.interface.mappos_input.input_type,
+                                MapPosInputType::MoveMap
+                            );
+
+                            if content.data.pos == Some(mappos) {
+                                alert.show_alert(
                                     systems,
-                                    format!(
-                                        "Map [X: {} Y: {} Group: {}] Saved!",
-                                        mappos.x, mappos.y, mappos.group
+                                    &AlertBuilder::new_info(
+                                        "Error",
+                                        "The map is already on this position",
                                     ),
-                                    seconds,
                                 );
                             } else {
-                                alert.show_alert(
-                                    systems,
-                                    &AlertBuilder::new_info("Error", "Failed to save map"),
+                                let mut msg = format!(
+                                    "{} map to [X: {} Y: {} Group: {}]?",
+                                    if is_move { "Move" } else { "Duplicate" },
+                                    mappos.x,
+                                    mappos.y,
+                                    mappos.group
+                                );
+                                if is_map_exist(mappos.x, mappos.y, mappos.group as u64) {
+                                    msg.push_str(" The existing map will be overwritten!");
+                                }
+                                // Edits of a linked map only reach its temp file once saved
+                                let got_unsaved =
+                                    is_temp_map_exist(mappos.x, mappos.y, mappos.group as u64)
+                                        || content.data.link_map.iter().flatten().any(
+                                            |link_data| {
+                                                link_data.pos == mappos && link_data.changed
+                                            },
+                                        );
+                                if got_unsaved {
+                                    msg.push_str(" Its unsaved changes will be discarded!");
+                                }
+
+                                let mut builder = AlertBuilder::new_confirm(
+                                    if is_move {
+                                        "Move / Rename Map"
+                                    } else {
+                                        "Duplicate Map"
+                                    },
+                                    &msg,
                                 );
+                                builder.with_width(400);
+                                if is_move {
+                                    builder
+                                        .with_index(AlertIndex::MoveMap(mappos))
+                                        .with_checkbox("Update warps leading to this map");
+                                } else {
+                                    builder.with_index(AlertIndex::DuplicateMap(mappos));
+                                }
+                                alert.show_alert(systems, &builder);
                             }
                         }
                         MapPosInputType::None => {}
@@ -2,9 +2,12 @@ use ascending_graphics::Vec2;
 
 use crate::{
     content::{
-        Content, apply_redo, apply_undo,
-        interface::{map_pos_input::MapPosInputType, menu_bar::MenuBar},
-        load_and_apply_map, save_map_change,
+        Content, MapTransform, apply_redo, apply_undo, clear_measure, get_export_presets,
+        interface::{
+            map_pos_input::MapPosInputType,
+            menu_bar::{MenuBar, get_view_menu_list},
+        },
+        load_and_apply_map, recompute_tile_collision, save_map_change, transform_map,
         widget::{Alert, AlertBuilder, AlertIndex},
     },
     data_types::Result,
@@ -21,6 +24,7 @@ impl MenuBar {
 
         self.file_menu.hover_list(systems, mouse_pos);
         self.edit_menu.hover_list(systems, mouse_pos);
+        self.view_menu.hover_list(systems, mouse_pos);
     }
 
     pub fn reset_widgets(&mut self, systems: &mut SystemHolder, _mouse_pos: Vec2) {
@@ -65,17 +69,21 @@ pub fn menu_bar_click_widget(
             match index {
                 0 => {
                     gui.edit_menu.set_visible(systems, false, true);
+                    gui.view_menu.set_visible(systems, false, true);
                     gui.file_menu
                         .set_visible(systems, !gui.file_menu.visible, true);
                 }
                 1 => {
                     gui.file_menu.set_visible(systems, false, true);
+                    gui.view_menu.set_visible(systems, false, true);
                     gui.edit_menu
                         .set_visible(systems, !gui.edit_menu.visible, true);
                 }
                 2 => {
                     gui.file_menu.set_visible(systems, false, true);
                     gui.edit_menu.set_visible(systems, false, true);
+                    gui.view_menu
+                        .set_visible(systems, !gui.view_menu.visible, true);
                 }
                 _ => {}
             }
@@ -150,6 +158,73 @@ pub fn menu_bar_click_widget(
                     );
                 }
             } // Reload Map
+            4 | 5 => {
+                if content.data.pos.is_some() {
+                    content.interface.mappos_input.open(
+                        systems,
+                        if index == 4 {
+                            MapPosInputType::DuplicateMap
+                        } else {
+                            MapPosInputType::MoveMap
+                        },
+                    );
+                } else {
+                    alert.show_alert(
+                        systems,
+                        &AlertBuilder::new_info("Error", "No loaded map to manage"),
+                    );
+                }
+            } // Duplicate Map / Move Map
+            6 => {
+                if let Some(mappos) = content.data.pos {
+                    alert.show_alert(
+                        systems,
+                        AlertBuilder::new_confirm(
+                            "Delete Map",
+                            &format!(
+                                "Delete map [X: {} Y: {} Group: {}] and its unsaved changes? This cannot be undone!",
+                                mappos.x, mappos.y, mappos.group
+                            ),
+                        )
+                        .with_width(400)
+                        .with_index(AlertIndex::DeleteMap),
+                    );
+                } else {
+                    alert.show_alert(
+                        systems,
+                        &AlertBuilder::new_info("Error", "No loaded map to delete"),
+                    );
+                }
+            } // Delete Map
+            7 => {
+                alert.show_alert(
+                    systems,
+                    AlertBuilder::new_txt_input("Template Name")
+                        .with_index(AlertIndex::SaveTemplate),
+                );
+            } // Save As Template
+            8 => {
+                let count = get_export_presets(content).len();
+                if count == 0 {
+                    alert.show_alert(
+                        systems,
+                        &AlertBuilder::new_info("Error", "No preset selected to export"),
+                    );
+                } else {
+                    alert.show_alert(
+                        systems,
+                        AlertBuilder::new_txt_input(&format!("Export {count} Presets as Bundle"))
+                            .with_index(AlertIndex::ExportPresets),
+                    );
+                }
+            } // Export Presets
+            9 => {
+                alert.show_alert(
+                    systems,
+                    AlertBuilder::new_txt_input("Import Bundle Name")
+                        .with_index(AlertIndex::ImportPresets),
+                );
+            } // Import Presets
             _ => {}
         }
         content
@@ -169,6 +244,26 @@ pub fn menu_bar_click_widget(
         match index {
             0 => apply_undo(content, systems), // Undo
             1 => apply_redo(content, systems), // Redo
+            2 | 3 => {
+                alert.show_alert(
+                    systems,
+                    AlertBuilder::new_txt_input("Shift Map X,Y")
+                        .with_index(AlertIndex::ShiftMap(index == 2)),
+                );
+            } // Shift Map
+            4 => transform_map(content, systems, MapTransform::FlipHorizontal),
+            5 => transform_map(content, systems, MapTransform::FlipVertical),
+            6 => transform_map(content, systems, MapTransform::RotateClockwise),
+            7 => transform_map(content, systems, MapTransform::RotateCounterClockwise),
+            8 => {
+                if !recompute_tile_collision(content, systems) {
+                    content.interface.notification.add_msg(
+                        systems,
+                        "Collisions already match the tiles".to_string(),
+                        seconds,
+                    );
+                }
+            } // Recompute Collisions
             _ => {}
         }
         content
@@ -179,10 +274,46 @@ pub fn menu_bar_click_widget(
         return Ok(true);
     }
 
+    if let Some(index) = content
+        .interface
+        .menu_bar
+        .view_menu
+        .select_list_by_pos(systems, mouse_pos, false)
+    {
+        match index {
+            0 => {
+                systems.config.show_grid = !systems.config.show_grid;
+                content
+                    .map_view
+                    .set_grid_visible(systems, systems.config.show_grid);
+                systems.config.save_config()?;
+            } // Grid
+            1 => {
+                systems.config.show_ruler = !systems.config.show_ruler;
+                content
+                    .map_view
+                    .set_ruler_visible(systems, systems.config.show_ruler);
+                systems.config.save_config()?;
+            } // Rulers
+            2 => clear_measure(content, systems), // Clear Measure
+            3 => {
+                systems.config.auto_collision = !systems.config.auto_collision;
+                systems.config.save_config()?;
+            } // Tile Collision
+            _ => {}
+        }
+        let list = get_view_menu_list(systems);
+        let gui = &mut content.interface.menu_bar.view_menu;
+        gui.update_list(systems, list, None);
+        gui.set_visible(systems, false, true);
+        return Ok(true);
+    }
+
     {
         let gui = &mut content.interface;
         gui.menu_bar.file_menu.set_visible(systems, false, true);
         gui.menu_bar.edit_menu.set_visible(systems, false, true);
+        gui.menu_bar.view_menu.set_visible(systems, false, true);
     }
 
     Ok(false)
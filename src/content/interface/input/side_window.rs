@@ -12,19 +12,25 @@ use crate::{
 
 mod attributes;
 mod dirblocks;
+mod history;
+mod inspector;
 mod music;
 mod presets;
 //mod properties;
 mod tilesets;
+mod warp_source;
 mod weather;
 mod zones;
 
 pub use attributes::*;
 use dirblocks::*;
+pub use history::*;
+pub use inspector::*;
 use music::*;
 pub use presets::*;
 //use properties::*;
-use tilesets::*;
+pub use tilesets::*;
+pub use warp_source::*;
 use weather::*;
 use zones::*;
 
@@ -49,6 +55,9 @@ impl SideWindow {
         self.presets.hover_widgets(systems, mouse_pos, tooltip);
         self.dirblocks.hover_widgets(systems, mouse_pos, tooltip);
         self.music.hover_widgets(systems, mouse_pos, tooltip);
+        self.history.hover_widgets(systems, mouse_pos, tooltip);
+        self.warp_source.hover_widgets(systems, mouse_pos, tooltip);
+        self.inspector.hover_widgets(systems, mouse_pos, tooltip);
         //self.properties.hover_widgets(systems, mouse_pos, tooltip);
         self.weather.hover_widgets(systems, mouse_pos, tooltip);
         self.zone.hover_widgets(systems, mouse_pos, tooltip);
@@ -64,6 +73,9 @@ impl SideWindow {
         self.presets.reset_widgets(systems, mouse_pos);
         self.dirblocks.reset_widgets(systems, mouse_pos);
         self.music.reset_widgets(systems, mouse_pos);
+        self.history.reset_widgets(systems, mouse_pos);
+        self.warp_source.reset_widgets(systems, mouse_pos);
+        self.inspector.reset_widgets(systems, mouse_pos);
         //self.properties.reset_widgets(systems, mouse_pos);
         self.weather.reset_widgets(systems, mouse_pos);
         self.zone.reset_widgets(systems, mouse_pos);
@@ -75,6 +87,9 @@ impl SideWindow {
             || self.presets.hold_scrollbar(systems, mouse_pos)
             || self.dirblocks.hold_scrollbar(systems, mouse_pos)
             || self.music.hold_scrollbar(systems, mouse_pos)
+            || self.history.hold_scrollbar(systems, mouse_pos)
+            || self.warp_source.hold_scrollbar(systems, mouse_pos)
+            || self.inspector.hold_scrollbar(systems, mouse_pos)
             //|| self.properties.hold_scrollbar(systems, mouse_pos)
             || self.weather.hold_scrollbar(systems, mouse_pos)
             || self.zone.hold_scrollbar(systems, mouse_pos)
@@ -91,6 +106,9 @@ impl SideWindow {
         self.presets.hold_move_scrollbar(systems, mouse_pos);
         self.dirblocks.hold_move_scrollbar(systems, mouse_pos);
         self.music.hold_move_scrollbar(systems, mouse_pos);
+        self.history.hold_move_scrollbar(systems, mouse_pos);
+        self.warp_source.hold_move_scrollbar(systems, mouse_pos);
+        self.inspector.hold_move_scrollbar(systems, mouse_pos);
         //self.properties.hold_move_scrollbar(systems, mouse_pos);
         self.weather.hold_move_scrollbar(systems, mouse_pos);
         self.zone.hold_move_scrollbar(systems, mouse_pos);
@@ -133,10 +151,13 @@ pub fn side_click_widget(
     }
 
     if side_attribute_click_widget(content, systems, mouse_pos)
-        || side_tileset_click_widget(content, systems, mouse_pos)
+        || side_tileset_click_widget(content, systems, alert, mouse_pos)
         || side_preset_click_widget(content, systems, alert,mouse_pos)?
-        || side_dirblock_click_widget(content, systems, mouse_pos)
+        || side_dirblock_click_widget(content, systems, alert, mouse_pos)
         || side_music_click_widget(content, systems, mouse_pos)?
+        || side_history_click_widget(content, systems, mouse_pos)
+        || side_warp_source_click_widget(content, systems, mouse_pos)
+        || side_inspector_click_widget(content, systems, mouse_pos)
         //|| side_properties_click_widget(content, systems, mouse_pos)
         || side_weather_click_widget(content, systems, mouse_pos)
         || side_zone_click_widget(content, systems, mouse_pos)
@@ -181,6 +202,11 @@ pub fn switch_tab(content: &mut Content, systems: &mut SystemHolder, tool: TabBu
     gui.dirblocks
         .set_visible(systems, tool == TabButton::DirBlock);
     gui.music.set_visible(systems, tool == TabButton::Music);
+    gui.history.set_visible(systems, tool == TabButton::History);
+    gui.warp_source
+        .set_visible(systems, tool == TabButton::WarpSource);
+    gui.inspector
+        .set_visible(systems, tool == TabButton::Inspector);
     //gui.properties
     //    .set_visible(systems, tool == TabButton::Properties);
     gui.weather.set_visible(systems, tool == TabButton::Weather);
@@ -188,24 +214,24 @@ pub fn switch_tab(content: &mut Content, systems: &mut SystemHolder, tool: TabBu
 
     match tool {
         TabButton::Zones => {
-            let zone_data = content.data.mapdata.zones[gui.zone.cur_zone];
-
-            gui.zone.textbox[0].set_text(systems, format!("{}", zone_data.0));
-
-            for i in 0..5 {
-                gui.zone.textbox[i + 1].set_text(
-                    systems,
-                    if let Some(data) = zone_data.1[i] {
-                        format!("{data}")
-                    } else {
-                        String::new()
-                    },
-                );
-            }
+            gui.zone
+                .update_zone_list(systems, &content.data.mapdata.zones);
+            gui.zone
+                .update_zone_data(systems, &content.data.mapdata.zones);
         }
         TabButton::CustomTiles => {
             preset_update_list(content, systems);
         }
+        TabButton::History => {
+            history_update_list(content, systems);
+        }
+        TabButton::WarpSource => {
+            // Other maps might have changed since the last scan
+            gui.warp_source.need_update = true;
+        }
+        TabButton::Inspector => {
+            gui.inspector.need_update = true;
+        }
         _ => {}
     }
 
@@ -218,4 +244,7 @@ pub fn switch_tab(content: &mut Content, systems: &mut SystemHolder, tool: TabBu
     content
         .map_view
         .set_dirblock_visible(systems, tool == TabButton::DirBlock);
+    content
+        .map_view
+        .set_warp_marker_visible(systems, tool == TabButton::WarpSource);
 }
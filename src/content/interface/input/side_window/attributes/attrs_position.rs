@@ -18,10 +18,22 @@ impl AttrPosition {
 
         let in_scroll = self.content_scroll.in_scroll(mouse_pos);
         self.content_scroll.set_hover(systems, in_scroll);
+
+        for button in self.button.iter_mut() {
+            let in_area = button.in_area(systems, mouse_pos);
+            button.set_hover(systems, in_area);
+        }
+        let in_hover = self.two_way.in_area(systems, mouse_pos);
+        self.two_way.set_hover(systems, in_hover);
     }
 
     pub fn reset_widgets(&mut self, systems: &mut SystemHolder, mouse_pos: Vec2) {
         self.content_scroll.set_hold(systems, false, mouse_pos);
+
+        for button in self.button.iter_mut() {
+            button.set_click(systems, false);
+        }
+        self.two_way.set_click(systems, false);
     }
 
     pub fn hold_scrollbar(&mut self, systems: &mut SystemHolder, mouse_pos: Vec2) -> bool {
@@ -63,6 +75,21 @@ impl AttrPosition {
 
         did_click
     }
+
+    pub fn click_button(&mut self, systems: &mut SystemHolder, mouse_pos: Vec2) -> Option<usize> {
+        if !self.visible {
+            return None;
+        }
+
+        for (index, button) in self.button.iter_mut().enumerate() {
+            if button.in_area(systems, mouse_pos) && !button.disabled {
+                button.set_click(systems, true);
+                return Some(index);
+            }
+        }
+
+        None
+    }
 }
 
 pub fn attr_position_click_widget(
@@ -80,5 +107,23 @@ pub fn attr_position_click_widget(
         return true;
     }
 
+    if let Some(index) = gui.attr_position.click_button(systems, mouse_pos) {
+        match index {
+            0 => {
+                let picking = !gui.attr_position.picking;
+                gui.attr_position.set_picking(systems, picking);
+                gui.attr_position.pick_source = if picking { content.data.pos } else { None };
+            } // Pick Target
+            1 => gui.attr_position.switch_exit_dir(systems, 0), // Source Exit Side
+            _ => gui.attr_position.switch_exit_dir(systems, 1), // Target Exit Side
+        }
+        return true;
+    }
+
+    if gui.attr_position.visible && gui.attr_position.two_way.in_area(systems, mouse_pos) {
+        gui.attr_position.two_way.set_click(systems, true);
+        return true;
+    }
+
     false
 }
@@ -1,7 +1,11 @@
 use ascending_graphics::*;
 
 use crate::{
-    content::{Content, interface::side_window::DirBlockWindow, widget::Tooltip},
+    content::{
+        Content, auto_edge_dirblock,
+        interface::side_window::DirBlockWindow,
+        widget::{Alert, AlertBuilder, Tooltip},
+    },
     renderer::SystemHolder,
 };
 
@@ -10,20 +14,33 @@ impl DirBlockWindow {
         &mut self,
         systems: &mut SystemHolder,
         mouse_pos: Vec2,
-        _tooltip: &mut Tooltip,
+        tooltip: &mut Tooltip,
     ) {
         if !self.visible {
             return;
         }
 
-        for checkbox in self.blocks.iter_mut() {
+        for checkbox in self.blocks.iter_mut().chain(self.option.iter_mut()) {
             let in_hover = checkbox.in_area(systems, mouse_pos);
             checkbox.set_hover(systems, in_hover);
         }
+
+        for button in self.button.iter_mut() {
+            let in_hover = button.in_area(systems, mouse_pos);
+            button.set_hover(systems, in_hover);
+
+            if in_hover && let Some(msg) = &button.tooltip {
+                tooltip.init_tooltip(systems, mouse_pos, msg.clone(), false);
+            }
+        }
     }
 
     pub fn reset_widgets(&mut self, systems: &mut SystemHolder, _mouse_pos: Vec2) {
-        for button in self.blocks.iter_mut() {
+        for button in self.blocks.iter_mut().chain(self.option.iter_mut()) {
+            button.set_click(systems, false);
+        }
+
+        for button in self.button.iter_mut() {
             button.set_click(systems, false);
         }
     }
@@ -49,11 +66,33 @@ impl DirBlockWindow {
 
         None
     }
+
+    pub fn click_option(&mut self, systems: &mut SystemHolder, mouse_pos: Vec2) -> Option<usize> {
+        for (index, checkbox) in self.option.iter_mut().enumerate() {
+            if checkbox.in_area(systems, mouse_pos) {
+                return Some(index);
+            }
+        }
+
+        None
+    }
+
+    pub fn click_button(&mut self, systems: &mut SystemHolder, mouse_pos: Vec2) -> Option<usize> {
+        for (index, button) in self.button.iter_mut().enumerate() {
+            if button.in_area(systems, mouse_pos) && !button.disabled {
+                button.set_click(systems, true);
+                return Some(index);
+            }
+        }
+
+        None
+    }
 }
 
 pub fn side_dirblock_click_widget(
     content: &mut Content,
     systems: &mut SystemHolder,
+    alert: &mut Alert,
     mouse_pos: Vec2,
 ) -> bool {
     if !content.interface.side_window.dirblocks.visible {
@@ -71,5 +110,20 @@ pub fn side_dirblock_click_widget(
         return true;
     }
 
+    if let Some(index) = gui.click_option(systems, mouse_pos) {
+        gui.option[index].set_click(systems, true);
+        return true;
+    }
+
+    if gui.click_button(systems, mouse_pos).is_some() {
+        if !auto_edge_dirblock(content, systems) {
+            alert.show_alert(
+                systems,
+                &AlertBuilder::new_info("Error", "Select a region with Shift + Drag first"),
+            );
+        }
+        return true;
+    } // Auto Edge
+
     false
 }
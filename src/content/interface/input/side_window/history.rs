@@ -0,0 +1,105 @@
+use ascending_graphics::*;
+
+use crate::{
+    content::{Content, interface::side_window::HistoryWindow, jump_to_history, widget::Tooltip},
+    renderer::SystemHolder,
+};
+
+impl HistoryWindow {
+    pub fn hover_widgets(
+        &mut self,
+        systems: &mut SystemHolder,
+        mouse_pos: Vec2,
+        _tooltip: &mut Tooltip,
+    ) {
+        if !self.visible {
+            return;
+        }
+
+        self.history_list.hover_list(systems, mouse_pos);
+        self.history_list.hover_scrollbar(systems, mouse_pos);
+    }
+
+    pub fn reset_widgets(&mut self, systems: &mut SystemHolder, mouse_pos: Vec2) {
+        self.history_list
+            .scrollbar
+            .set_hold(systems, false, mouse_pos);
+    }
+
+    pub fn hold_scrollbar(&mut self, systems: &mut SystemHolder, mouse_pos: Vec2) -> bool {
+        if !self.visible {
+            return false;
+        }
+
+        if self.history_list.scrollbar.in_scroll(mouse_pos) {
+            self.history_list
+                .scrollbar
+                .set_hold(systems, true, mouse_pos);
+            return true;
+        }
+
+        false
+    }
+
+    pub fn hold_move_scrollbar(&mut self, systems: &mut SystemHolder, mouse_pos: Vec2) {
+        if !self.visible {
+            return;
+        }
+
+        if self.history_list.visible {
+            self.history_list
+                .scrollbar
+                .set_move_scroll(systems, mouse_pos);
+            self.history_list.update_list_scroll(systems);
+        }
+    }
+}
+
+pub fn side_history_click_widget(
+    content: &mut Content,
+    systems: &mut SystemHolder,
+    mouse_pos: Vec2,
+) -> bool {
+    if !content.interface.side_window.history.visible {
+        return false;
+    }
+
+    let gui = &mut content.interface.side_window.history;
+
+    if gui.hold_scrollbar(systems, mouse_pos) {
+        return true;
+    }
+
+    if let Some(list_index) = gui
+        .history_list
+        .select_list_by_pos(systems, mouse_pos, true)
+    {
+        // The newest change is listed first
+        let total = content.data.undo.len() + content.data.redo.len();
+        jump_to_history(content, systems, total.saturating_sub(list_index));
+        return true;
+    }
+
+    false
+}
+
+pub fn history_update_list(content: &mut Content, systems: &mut SystemHolder) {
+    let undo_count = content.data.undo.len();
+    let total = undo_count + content.data.redo.len();
+
+    let mut list = Vec::with_capacity(total + 1);
+    for change in content.data.redo.iter() {
+        list.push(change.get_label());
+    }
+    for change in content.data.undo.iter().rev() {
+        list.push(change.get_label());
+    }
+    list.push("Initial State".to_string());
+
+    content
+        .interface
+        .side_window
+        .history
+        .history_list
+        .update_list(systems, list, Some(total - undo_count));
+}
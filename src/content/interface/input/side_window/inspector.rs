@@ -0,0 +1,198 @@
+use ascending_graphics::*;
+use bit_op::{BitOp, bit_u8::*};
+
+use crate::{
+    content::{Content, get_tile_pos, interface::side_window::InspectorWindow, widget::Tooltip},
+    data_types::*,
+    database::MapAttribute,
+    renderer::SystemHolder,
+};
+
+impl InspectorWindow {
+    pub fn hover_widgets(
+        &mut self,
+        systems: &mut SystemHolder,
+        mouse_pos: Vec2,
+        _tooltip: &mut Tooltip,
+    ) {
+        if !self.visible {
+            return;
+        }
+
+        self.info_list.hover_scrollbar(systems, mouse_pos);
+
+        for button in self.button.iter_mut() {
+            let in_area = button.in_area(systems, mouse_pos);
+            button.set_hover(systems, in_area);
+        }
+    }
+
+    pub fn reset_widgets(&mut self, systems: &mut SystemHolder, mouse_pos: Vec2) {
+        self.info_list.scrollbar.set_hold(systems, false, mouse_pos);
+
+        for button in self.button.iter_mut() {
+            button.set_click(systems, false);
+        }
+    }
+
+    pub fn hold_scrollbar(&mut self, systems: &mut SystemHolder, mouse_pos: Vec2) -> bool {
+        if !self.visible {
+            return false;
+        }
+
+        if self.info_list.scrollbar.in_scroll(mouse_pos) {
+            self.info_list.scrollbar.set_hold(systems, true, mouse_pos);
+            return true;
+        }
+
+        false
+    }
+
+    pub fn hold_move_scrollbar(&mut self, systems: &mut SystemHolder, mouse_pos: Vec2) {
+        if !self.visible {
+            return;
+        }
+
+        if self.info_list.visible {
+            self.info_list.scrollbar.set_move_scroll(systems, mouse_pos);
+            self.info_list.update_list_scroll(systems);
+        }
+    }
+
+    pub fn click_button(&mut self, systems: &mut SystemHolder, mouse_pos: Vec2) -> Option<usize> {
+        for (index, button) in self.button.iter_mut().enumerate() {
+            if button.in_area(systems, mouse_pos) && !button.disabled {
+                button.set_click(systems, true);
+                return Some(index);
+            }
+        }
+
+        None
+    }
+}
+
+pub fn side_inspector_click_widget(
+    content: &mut Content,
+    systems: &mut SystemHolder,
+    mouse_pos: Vec2,
+) -> bool {
+    if !content.interface.side_window.inspector.visible {
+        return false;
+    }
+
+    let gui = &mut content.interface.side_window.inspector;
+
+    if gui.hold_scrollbar(systems, mouse_pos) {
+        return true;
+    }
+
+    if let Some(index) = gui.click_button(systems, mouse_pos) {
+        if index == 0 {
+            let pinned = !gui.pinned;
+            gui.set_pinned(systems, pinned);
+            if !pinned {
+                gui.need_update = true;
+            }
+        } // Pin Tile
+        return true;
+    }
+
+    false
+}
+
+fn get_attribute_info(attribute: &MapAttribute) -> Vec<String> {
+    match attribute {
+        MapAttribute::Walkable => vec!["Attribute: Walkable".to_string()],
+        MapAttribute::Blocked => vec!["Attribute: Blocked".to_string()],
+        MapAttribute::NpcBlocked => vec!["Attribute: Npc Blocked".to_string()],
+        MapAttribute::Warp(data) => vec![
+            "Attribute: Warp".to_string(),
+            format!("  Map: {},{},{}", data.map_x, data.map_y, data.map_group),
+            format!("  Tile: {},{}", data.tile_x, data.tile_y),
+        ],
+        MapAttribute::Sign(text) => vec!["Attribute: Sign".to_string(), format!("  Text: {text}")],
+        MapAttribute::ItemSpawn(data) => vec![
+            "Attribute: Item Spawn".to_string(),
+            format!("  Index: {}", data.index),
+            format!("  Amount: {}", data.amount),
+            format!("  Timer: {}", data.timer),
+        ],
+        MapAttribute::Storage => vec!["Attribute: Storage".to_string()],
+        MapAttribute::Shop(index) => {
+            vec!["Attribute: Shop".to_string(), format!("  Index: {index}")]
+        }
+        MapAttribute::Count => Vec::new(),
+    }
+}
+
+pub fn inspector_update(content: &mut Content, systems: &mut SystemHolder) {
+    let gui = &content.interface.side_window.inspector;
+    let tile = if gui.pinned
+        && let Some(pos) = gui.shown_pos
+    {
+        pos
+    } else {
+        content.map_view.tile.cur_pos
+    };
+    let (x, y) = (tile.x as u32, tile.y as u32);
+    let tile_num = get_tile_pos(x as i32, y as i32);
+    let mapdata = &content.data.mapdata;
+
+    let mut list = vec![format!("Tile: {x},{y}")];
+
+    for layer in 0..MapLayers::Count as usize {
+        let id = mapdata.tile[layer].id[tile_num] as usize;
+        let info = if id > 0
+            && let Some(tilepos) = systems.resource.tile_index_loc.get(&id)
+        {
+            format!(
+                "{} [{},{}]",
+                systems.resource.tilesheet[tilepos.file as usize].get_name(),
+                tilepos.x / TEXTURE_SIZE,
+                tilepos.y / TEXTURE_SIZE
+            )
+        } else {
+            "-".to_string()
+        };
+        list.push(format!("{}: {info}", map_layer_name(layer)));
+    }
+
+    list.extend(get_attribute_info(&mapdata.attribute[tile_num]));
+
+    let dirblock = mapdata.dir_block[tile_num];
+    let blocked: Vec<&str> = [(B1, "Up"), (B2, "Left"), (B0, "Down"), (B3, "Right")]
+        .iter()
+        .filter(|(bit, _)| dirblock.get(*bit) != 0)
+        .map(|(_, name)| *name)
+        .collect();
+    list.push(format!(
+        "Dir Block: {}",
+        if blocked.is_empty() {
+            "-".to_string()
+        } else {
+            blocked.join(", ")
+        }
+    ));
+
+    let zones: Vec<String> = mapdata
+        .zonespawns
+        .iter()
+        .zip(mapdata.zones.iter())
+        .filter(|(spawns, _)| spawns.contains(&(x as u16, y as u16)))
+        .map(|(_, zone)| zone.name.clone())
+        .collect();
+    list.push(format!(
+        "Zone: {}",
+        if zones.is_empty() {
+            "-".to_string()
+        } else {
+            zones.join(", ")
+        }
+    ));
+
+    let gui = &mut content.interface.side_window.inspector;
+    gui.info_list.update_list(systems, list, None);
+    gui.shown_pos = Some(tile);
+    gui.shown_map = content.data.pos;
+    gui.need_update = false;
+}
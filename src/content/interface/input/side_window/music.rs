@@ -1,7 +1,7 @@
 use ascending_graphics::*;
 
 use crate::{
-    content::{Content, interface::side_window::MusicWindow, widget::Tooltip},
+    content::{Content, EditorChange, interface::side_window::MusicWindow, widget::Tooltip},
     data_types::Result,
     renderer::SystemHolder,
 };
@@ -97,6 +97,14 @@ pub fn side_music_click_widget(
             .cloned()
             .filter(|text| text != "None");
 
+        if content.data.mapdata.music == data {
+            return Ok(true);
+        }
+
+        content.data.record_change(EditorChange::Music(
+            content.data.mapdata.music.clone(),
+            data.clone(),
+        ));
         content.data.mapdata.music = data;
         content.data.changed = true;
         content.data.temp_saved = false;
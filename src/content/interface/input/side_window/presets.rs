@@ -2,14 +2,17 @@ use ascending_graphics::*;
 
 use crate::{
     content::{
-        Content,
+        Content, get_preset_tiles,
         interface::side_window::{PresetWindow, PresetWindowType},
-        widget::{Alert, AlertBuilder, AlertIndex, Tooltip, is_within_area},
+        widget::{Alert, AlertBuilder, AlertIndex, TextList, Tooltip, is_within_area},
     },
     data_types::*,
-    database::{PresetFrames, PresetPos, PresetTypeList},
+    database::{
+        BundleFrame, BundlePreset, DEFAULT_ANIM_TIME, PresetBundle, PresetData, PresetFrames,
+        PresetPos, PresetTypeList,
+    },
+    gfx_collection::GfxType,
     renderer::SystemHolder,
-    resource::TilePos,
 };
 
 impl PresetWindow {
@@ -29,6 +32,11 @@ impl PresetWindow {
                 self.base.edit_button.set_hover(systems, in_hover);
                 self.base.preset_list.hover_list(systems, mouse_pos);
                 self.base.preset_list.hover_scrollbar(systems, mouse_pos);
+                for button in self.base.button.iter_mut() {
+                    let in_hover = button.in_area(systems, mouse_pos);
+                    button.set_hover(systems, in_hover);
+                }
+                self.base.category_list.hover_widget(systems, mouse_pos);
             }
             PresetWindowType::Editor => {
                 let in_scroll = self.editor.scrollbar.in_scroll(mouse_pos);
@@ -51,6 +59,10 @@ impl PresetWindow {
             .preset_list
             .scrollbar
             .set_hold(systems, false, mouse_pos);
+        for button in self.base.button.iter_mut() {
+            button.set_click(systems, false);
+        }
+        self.base.category_list.reset_widget(systems, mouse_pos);
 
         self.editor.scrollbar.set_hold(systems, false, mouse_pos);
         self.editor.frame_scroll.set_hold(systems, false, mouse_pos);
@@ -75,6 +87,17 @@ impl PresetWindow {
                         .set_hold(systems, true, mouse_pos);
                     return true;
                 }
+
+                if self.base.category_list.list.visible
+                    && self.base.category_list.list.scrollbar.in_scroll(mouse_pos)
+                {
+                    self.base
+                        .category_list
+                        .list
+                        .scrollbar
+                        .set_hold(systems, true, mouse_pos);
+                    return true;
+                }
             }
             PresetWindowType::Editor => {
                 if self.editor.scrollbar.in_scroll(mouse_pos) {
@@ -127,6 +150,15 @@ impl PresetWindow {
                         .set_move_scroll(systems, mouse_pos);
                     self.base.preset_list.update_list_scroll(systems);
                 }
+
+                if self.base.category_list.list.visible {
+                    self.base
+                        .category_list
+                        .list
+                        .scrollbar
+                        .set_move_scroll(systems, mouse_pos);
+                    self.base.category_list.list.update_list_scroll(systems);
+                }
             }
             PresetWindowType::Editor => {
                 self.editor.scrollbar.set_move_scroll(systems, mouse_pos);
@@ -148,11 +180,8 @@ impl PresetWindow {
                         self.editor.frames[id].end.x as f32,
                         self.editor.frames[id].end.y as f32,
                     );
-                    let end_pos = if matches!(
-                        self.editor.cur_type,
-                        PresetTypeList::AutoTile | PresetTypeList::AutotileAnimated
-                    ) {
-                        self.editor.selection.start_pos + Vec2::new(4.0, 2.0)
+                    let end_pos = if let Some((x, y)) = self.editor.cur_type.autotile_size() {
+                        self.editor.selection.start_pos + Vec2::new(x as f32 - 1.0, y as f32 - 1.0)
                     } else {
                         self.editor.selection.end_pos
                     };
@@ -195,6 +224,79 @@ impl PresetWindow {
         in_area
     }
 
+    pub fn click_base_button(
+        &mut self,
+        systems: &mut SystemHolder,
+        mouse_pos: Vec2,
+    ) -> Option<usize> {
+        for (index, button) in self.base.button.iter_mut().enumerate() {
+            if button.in_area(systems, mouse_pos) {
+                button.set_click(systems, true);
+                return Some(index);
+            }
+        }
+
+        None
+    }
+
+    pub fn click_category_list(
+        &mut self,
+        systems: &mut SystemHolder,
+        mouse_pos: Vec2,
+    ) -> Option<usize> {
+        let in_button_area = self.base.category_list.button.in_area(systems, mouse_pos);
+
+        if self.base.category_list.list.visible {
+            let result = self
+                .base
+                .category_list
+                .list
+                .select_list_by_pos(systems, mouse_pos, true);
+
+            if let Some(index) = result {
+                self.base.category_list.update_label(systems, index);
+                self.base
+                    .category_list
+                    .list
+                    .set_visible(systems, false, false);
+                return result;
+            }
+        }
+
+        if in_button_area {
+            self.base.category_list.button.set_click(systems, true);
+            self.base.category_list.list.set_visible(
+                systems,
+                !self.base.category_list.list.visible,
+                false,
+            );
+        }
+
+        None
+    }
+
+    pub fn click_textbox(&mut self, systems: &mut SystemHolder, mouse_pos: Vec2) -> bool {
+        let list_open = match self.window_type {
+            PresetWindowType::Base => self.base.category_list.list.visible,
+            PresetWindowType::Editor => {
+                self.editor.type_list.list.visible || self.editor.tile_list.list.visible
+            }
+        };
+        if !self.visible || list_open {
+            return false;
+        }
+
+        let textbox = self.get_cur_textbox();
+        if !textbox.in_area(systems, mouse_pos) {
+            return false;
+        }
+
+        textbox.set_select(systems, true);
+        textbox.set_hold(true);
+        textbox.select_text(systems, mouse_pos);
+        true
+    }
+
     pub fn click_save_button(&mut self, systems: &mut SystemHolder, mouse_pos: Vec2) -> bool {
         let in_area = self.editor.save_button.in_area(systems, mouse_pos);
         if in_area {
@@ -331,17 +433,14 @@ impl PresetWindow {
                 self.editor.selection.end_pos = tile_pos;
             }
 
-            let end_pos = if matches!(
-                self.editor.cur_type,
-                PresetTypeList::AutoTile | PresetTypeList::AutotileAnimated
-            ) {
+            let end_pos = if let Some((x, y)) = self.editor.cur_type.autotile_size() {
                 self.editor.selection.start_pos = self.editor.selection.start_pos.min(Vec2::new(
-                    TILESET_COUNT_X.saturating_sub(5) as f32,
-                    TILESET_COUNT_Y.saturating_sub(3) as f32,
+                    TILESET_COUNT_X.saturating_sub(x) as f32,
+                    TILESET_COUNT_Y.saturating_sub(y) as f32,
                 ));
                 self.editor.selection.end_pos = self.editor.selection.start_pos;
 
-                self.editor.selection.start_pos + Vec2::new(4.0, 2.0)
+                self.editor.selection.start_pos + Vec2::new(x as f32 - 1.0, y as f32 - 1.0)
             } else {
                 self.editor.selection.end_pos
             };
@@ -374,6 +473,7 @@ pub fn side_preset_click_widget(
         return Ok(false);
     }
 
+    let ctrl_down = content.input.ctrl_down;
     let gui = &mut content.interface.side_window.presets;
 
     match gui.window_type {
@@ -382,20 +482,94 @@ pub fn side_preset_click_widget(
                 return Ok(true);
             }
 
+            if let Some(index) = gui.click_category_list(systems, mouse_pos) {
+                let categories = content.preset.get_categories();
+                gui.base.category = match index {
+                    0 => None,
+                    1 => Some(String::new()),
+                    _ => categories.get(index - 2).cloned(),
+                };
+                preset_update_list(content, systems);
+                return Ok(true);
+            }
+
+            if gui.base.category_list.list.visible {
+                return Ok(false);
+            }
+
             if gui.click_edit_button(systems, mouse_pos) {
-                gui.switch_state(systems, PresetWindowType::Editor);
-                preset_update_editor(content, systems);
+                if gui.selected_index < content.preset.data.len() {
+                    gui.edit_new = false;
+                    gui.switch_state(systems, PresetWindowType::Editor);
+                    preset_update_editor(content, systems);
+                }
+                return Ok(true);
+            }
+
+            if let Some(index) = gui.click_base_button(systems, mouse_pos) {
+                let selected_index = gui.selected_index;
+                let selected = content.preset.data.get(selected_index);
+                match index {
+                    0 => {
+                        gui.edit_new = true;
+                        gui.switch_state(systems, PresetWindowType::Editor);
+                        preset_update_editor(content, systems);
+                    } // New
+                    1 if selected.is_some() => {
+                        alert.show_alert(
+                            systems,
+                            AlertBuilder::new_txt_input("Preset Name")
+                                .with_index(AlertIndex::RenamePreset),
+                        );
+                    }
+                    2 if selected.is_some() => {
+                        alert.show_alert(
+                            systems,
+                            AlertBuilder::new_txt_input("Preset Category")
+                                .with_index(AlertIndex::SetPresetCategory),
+                        );
+                    }
+                    3 => {
+                        if let Some(preset) = selected {
+                            let msg = format!("Delete preset {}?", preset.name);
+                            if let Some(terrain) = get_preset_terrain(content, selected_index) {
+                                alert.show_alert(
+                                    systems,
+                                    &AlertBuilder::new_info(
+                                        "Error",
+                                        &format!("The preset is used by the terrain {terrain}"),
+                                    ),
+                                );
+                            } else {
+                                alert.show_alert(
+                                    systems,
+                                    AlertBuilder::new_confirm("Delete Preset", &msg)
+                                        .with_index(AlertIndex::DeletePreset),
+                                );
+                            }
+                        }
+                    }
+                    4 | 5 => move_preset(content, systems, index == 4)?,
+                    _ => {}
+                }
                 return Ok(true);
             }
 
-            if let Some(index) = gui
+            if let Some(row) = gui
                 .base
                 .preset_list
                 .select_list_by_pos(systems, mouse_pos, true)
             {
-                if gui.selected_index != index {
-                    gui.selected_index = index;
-                    preset_update_preview(content, systems);
+                if let Some(index) = gui.base.list_index.get(row).copied() {
+                    if ctrl_down {
+                        if !gui.export_select.swap_remove(&index) {
+                            let _ = gui.export_select.insert(index);
+                        }
+                        preset_update_list(content, systems);
+                    } else if gui.selected_index != index {
+                        gui.selected_index = index;
+                        preset_update_preview(content, systems);
+                    }
                 }
                 return Ok(true);
             }
@@ -410,23 +584,17 @@ pub fn side_preset_click_widget(
                 gui.editor.frame_scroll.set_value(systems, 0);
                 gui.editor.frame_scroll.set_max_value(
                     systems,
-                    if matches!(
-                        gui.editor.cur_type,
-                        PresetTypeList::Normal | PresetTypeList::AutoTile
-                    ) {
-                        0
-                    } else {
+                    if gui.editor.cur_type.is_animated() {
                         3
+                    } else {
+                        0
                     },
                 );
                 gui.editor.frames = [PresetFrames::default(); 4];
                 gui.editor.selection.start_pos = Vec2::new(0.0, 0.0);
                 gui.editor.selection.end_pos = Vec2::new(0.0, 0.0);
-                let end_pos = if matches!(
-                    gui.editor.cur_type,
-                    PresetTypeList::AutotileAnimated | PresetTypeList::AutoTile
-                ) {
-                    Vec2::new(4.0, 2.0)
+                let end_pos = if let Some((x, y)) = gui.editor.cur_type.autotile_size() {
+                    Vec2::new(x as f32 - 1.0, y as f32 - 1.0)
                 } else {
                     Vec2::new(0.0, 0.0)
                 };
@@ -476,10 +644,7 @@ pub fn side_preset_clickdrag_widget(
     match gui.window_type {
         PresetWindowType::Base => {}
         PresetWindowType::Editor => {
-            if matches!(
-                gui.editor.cur_type,
-                PresetTypeList::AutotileAnimated | PresetTypeList::AutoTile
-            ) {
+            if gui.editor.cur_type.is_autotile() {
                 return false;
             }
 
@@ -492,31 +657,131 @@ pub fn side_preset_clickdrag_widget(
     false
 }
 
+// Lists the presets that match the category and the search text
 pub fn preset_update_list(content: &mut Content, systems: &mut SystemHolder) {
-    let mut list = Vec::with_capacity(MAX_PRESETS);
-    for (i, data) in content.preset.data.iter().enumerate() {
-        list.push(format!("{}: {}", i + 1, data.name));
+    let categories = content.preset.get_categories();
+    let gui = &mut content.interface.side_window.presets;
+
+    // The category is gone once its last preset got moved or deleted
+    if let Some(category) = &gui.base.category
+        && !category.is_empty()
+        && !categories.contains(category)
+    {
+        gui.base.category = None;
     }
-    content
-        .interface
-        .side_window
-        .presets
+    let category_index = match &gui.base.category {
+        None => 0,
+        Some(category) if category.is_empty() => 1,
+        Some(category) => categories
+            .iter()
+            .position(|name| name == category)
+            .map(|index| index + 2)
+            .unwrap_or_default(),
+    };
+    let mut category_text = vec!["All Categories".to_string(), "No Category".to_string()];
+    category_text.extend(categories);
+    gui.base
+        .category_list
+        .list
+        .update_list(systems, category_text, Some(category_index));
+    gui.base.category_list.update_label(systems, category_index);
+
+    gui.base.list_index = get_filtered_presets(
+        &content.preset.data,
+        &gui.base.category,
+        &gui.base.search.text,
+    );
+
+    let list: Vec<String> = gui
         .base
-        .preset_list
-        .update_list(
-            systems,
-            list,
-            Some(content.interface.side_window.presets.selected_index),
-        );
+        .list_index
+        .iter()
+        .map(|index| {
+            format!(
+                "{}{}: {}",
+                if gui.export_select.contains(index) {
+                    "* "
+                } else {
+                    ""
+                },
+                index + 1,
+                content.preset.data[*index].name
+            )
+        })
+        .collect();
+    let selected_row = gui
+        .base
+        .list_index
+        .iter()
+        .position(|index| *index == gui.selected_index);
+    set_preset_list(systems, &mut gui.base.preset_list, list, selected_row);
 
     preset_update_preview(content, systems);
 }
 
+// Index of the presets within the category whose name or category holds the search text
+fn get_filtered_presets(
+    data: &[PresetData],
+    category: &Option<String>,
+    search: &str,
+) -> Vec<usize> {
+    let search = search.trim().to_lowercase();
+    data.iter()
+        .enumerate()
+        .filter(|(_, data)| {
+            category
+                .as_ref()
+                .is_none_or(|category| data.category == *category)
+                && (search.is_empty()
+                    || data.name.to_lowercase().contains(&search)
+                    || data.category.to_lowercase().contains(&search))
+        })
+        .map(|(index, _)| index)
+        .collect()
+}
+
+// Keeps the list where it was scrolled to, unless the selected preset moved out of view
+fn set_preset_list(
+    systems: &mut SystemHolder,
+    list: &mut TextList,
+    text: Vec<String>,
+    selected: Option<usize>,
+) {
+    let mut scroll = list.scrollbar.value;
+    if let Some(row) = selected {
+        if row < scroll {
+            scroll = row;
+        } else if row >= scroll + list.max_list {
+            scroll = (row + 1).saturating_sub(list.max_list);
+        }
+    }
+
+    list.update_list(systems, text, None);
+    list.scrollbar
+        .set_value(systems, scroll.min(list.scrollbar.max_value));
+    list.update_list_scroll(systems);
+    list.set_select(systems, selected, true);
+}
+
 pub fn preset_update_preview(content: &mut Content, systems: &mut SystemHolder) {
     let selected_index = content.interface.side_window.presets.selected_index;
 
     let gui = &mut content.interface.side_window.presets;
 
+    if selected_index >= content.preset.data.len() {
+        systems.gfx.set_text(&gui.base.preview_name, "");
+        systems.gfx.set_text(&gui.base.preview_info, "No Preset");
+        systems
+            .gfx
+            .center_text(&mut systems.renderer, &gui.base.preview_info);
+        for gfx in gui.base.preview.iter_mut() {
+            systems.gfx.remove_gfx(&mut systems.renderer, gfx);
+            *gfx = GfxType::default();
+        }
+        content.preset.selected_preset_tiles.clear();
+        return;
+    }
+
     systems.gfx.set_text(
         &gui.base.preview_name,
         &content.preset.data[selected_index].name,
@@ -525,13 +790,21 @@ pub fn preset_update_preview(content: &mut Content, systems: &mut SystemHolder)
         .gfx
         .center_text(&mut systems.renderer, &gui.base.preview_name);
 
+    let data = &content.preset.data[selected_index];
+    let type_name = match data.draw_type {
+        PresetTypeList::Normal => "Normal",
+        PresetTypeList::Animated => "Animated",
+        PresetTypeList::AutoTile => "AutoTile",
+        PresetTypeList::AutotileAnimated => "Animated AutoTile",
+        PresetTypeList::BlobTile => "Blob Tile",
+        PresetTypeList::BlobTileAnimated => "Animated Blob Tile",
+    };
     systems.gfx.set_text(
         &gui.base.preview_info,
-        match content.preset.data[selected_index].draw_type {
-            PresetTypeList::Normal => "Normal",
-            PresetTypeList::Animated => "Animated",
-            PresetTypeList::AutoTile => "AutoTile",
-            PresetTypeList::AutotileAnimated => "Animated AutoTile",
+        &if data.draw_type.is_animated() {
+            format!("{type_name} ({}ms)", data.anim_time)
+        } else {
+            type_name.to_string()
         },
     );
     systems
@@ -540,19 +813,12 @@ pub fn preset_update_preview(content: &mut Content, systems: &mut SystemHolder)
 
     gui.base.frames = content.preset.data[selected_index].frames;
     gui.base.preset_type = content.preset.data[selected_index].draw_type;
+    gui.base.anim_time = content.preset.data[selected_index].anim_time;
 
     let p_type = gui.base.preset_type;
-    let is_autotile = matches!(
-        p_type,
-        PresetTypeList::AutoTile | PresetTypeList::AutotileAnimated
-    );
-    let is_animated = matches!(
-        p_type,
-        PresetTypeList::Animated | PresetTypeList::AutotileAnimated
-    );
+    let is_animated = p_type.is_animated();
 
     let tile_size = (20.0 * systems.scale as f32).floor();
-    let mut selected_preset_tiles = Vec::with_capacity(52);
     for (i, gfx) in gui.base.preview.iter_mut().enumerate() {
         systems.gfx.remove_gfx(&mut systems.renderer, gfx);
 
@@ -562,8 +828,8 @@ pub fn preset_update_preview(content: &mut Content, systems: &mut SystemHolder)
             continue;
         }
 
-        let size = if is_autotile {
-            Vec2::new(5.0, 3.0)
+        let size = if let Some((x, y)) = p_type.autotile_size() {
+            Vec2::new(x as f32, y as f32)
         } else {
             Vec2::new(
                 frame.start.x.abs_diff(frame.end.x) as f32 + 1.0,
@@ -583,9 +849,17 @@ pub fn preset_update_preview(content: &mut Content, systems: &mut SystemHolder)
             } as f32,
         );
 
+        // The preview got room for 5x3 tiles, larger autotiles show their upper left part
+        let (view_pos, view_size) = if p_type.is_autotile() {
+            let view_size = size.min(Vec2::new(5.0, 3.0));
+            (Vec2::new(pos.x, pos.y + size.y - view_size.y), view_size)
+        } else {
+            (pos, size)
+        };
+
         let preview_pos = systems.gfx.get_pos(&gui.base.preview_bg);
         let offset_pos = {
-            let preview_size = (size - Vec2::ONE).max(Vec2::ONE);
+            let preview_size = (view_size - Vec2::ONE).max(Vec2::ONE);
             (Vec2::new(3.0, 2.0) - preview_size).max(Vec2::ZERO)
         };
 
@@ -597,12 +871,12 @@ pub fn preset_update_preview(content: &mut Content, systems: &mut SystemHolder)
                 preview_pos.y + (2.0 * systems.scale as f32).floor() + (offset_pos.y * tile_size),
                 ORDER_WINDOW_CONTENT,
             ),
-            (Vec2::new(size.x * 20.0, size.y * 20.0) * systems.scale as f32).floor(),
+            (Vec2::new(view_size.x * 20.0, view_size.y * 20.0) * systems.scale as f32).floor(),
             Vec4::new(
-                pos.x * 20.0,
-                (TILESET_COUNT_Y as f32 - (pos.y + size.y)) * 20.0,
-                size.x * 20.0,
-                size.y * 20.0,
+                view_pos.x * 20.0,
+                (TILESET_COUNT_Y as f32 - (view_pos.y + view_size.y)) * 20.0,
+                view_size.x * 20.0,
+                view_size.y * 20.0,
             ),
             2,
         );
@@ -613,37 +887,10 @@ pub fn preset_update_preview(content: &mut Content, systems: &mut SystemHolder)
             gui.visible && gui.window_type == PresetWindowType::Base && i == 0,
             CameraView::SubView1,
         );
-
-        let start_pos = Vec2::new(frame.start.x as f32, frame.start.y as f32);
-        let end_pos = Vec2::new(frame.end.x as f32, frame.end.y as f32);
-        let tilesheet_pos = Vec2::new(
-            start_pos.x.min(end_pos.x),
-            TILESET_COUNT_Y.saturating_sub(1) as f32 - start_pos.y.min(end_pos.y),
-        );
-        let tile_size = (size.x as u32, size.y as u32);
-        for x in 0..tile_size.0 {
-            for y in 0..tile_size.1 {
-                if is_autotile && y == 0 && x > 2 {
-                    continue;
-                }
-
-                if let Some(id) = systems
-                    .resource
-                    .tile_pos_loc
-                    .get(&TilePos {
-                        x: (tilesheet_pos.x as u32 + x) * TEXTURE_SIZE,
-                        y: (tilesheet_pos.y as u32 - y) * TEXTURE_SIZE,
-                        file: frame.tileset as u32,
-                    })
-                    .copied()
-                {
-                    selected_preset_tiles.push(id);
-                }
-            }
-        }
     }
 
-    content.preset.selected_preset_tiles = selected_preset_tiles;
+    content.preset.selected_preset_tiles =
+        get_preset_tiles(systems, &content.preset.data[selected_index]);
 }
 
 pub fn preset_update_editor(content: &mut Content, systems: &mut SystemHolder) {
@@ -653,17 +900,25 @@ pub fn preset_update_editor(content: &mut Content, systems: &mut SystemHolder) {
 
     let gui = &mut content.interface.side_window.presets;
 
-    gui.editor.cur_type = content.preset.data[gui.selected_index].draw_type;
+    let data = if gui.edit_new {
+        PresetData::default()
+    } else {
+        content
+            .preset
+            .data
+            .get(gui.selected_index)
+            .cloned()
+            .unwrap_or_default()
+    };
+
+    gui.editor.cur_type = data.draw_type;
     gui.editor.frame_scroll.set_value(systems, 0);
     gui.editor.frame_scroll.set_max_value(
         systems,
-        if matches!(
-            gui.editor.cur_type,
-            PresetTypeList::Normal | PresetTypeList::AutoTile
-        ) {
-            0
-        } else {
+        if gui.editor.cur_type.is_animated() {
             3
+        } else {
+            0
         },
     );
     systems.gfx.set_text(&gui.editor.frame_label, "Frm: 1");
@@ -674,7 +929,10 @@ pub fn preset_update_editor(content: &mut Content, systems: &mut SystemHolder) {
     gui.editor
         .type_list
         .update_label(systems, gui.editor.cur_type as usize);
-    gui.editor.frames = content.preset.data[gui.selected_index].frames;
+    gui.editor.frames = data.frames;
+    gui.editor
+        .anim_time
+        .set_text(systems, data.anim_time.to_string());
     gui.editor.selection.start_pos = Vec2::new(
         gui.editor.frames[0].start.x as f32,
         gui.editor.frames[0].start.y as f32,
@@ -683,11 +941,8 @@ pub fn preset_update_editor(content: &mut Content, systems: &mut SystemHolder) {
         gui.editor.frames[0].end.x as f32,
         gui.editor.frames[0].end.y as f32,
     );
-    let end_pos = if matches!(
-        gui.editor.cur_type,
-        PresetTypeList::AutoTile | PresetTypeList::AutotileAnimated
-    ) {
-        gui.editor.selection.start_pos + Vec2::new(4.0, 2.0)
+    let end_pos = if let Some((x, y)) = gui.editor.cur_type.autotile_size() {
+        gui.editor.selection.start_pos + Vec2::new(x as f32 - 1.0, y as f32 - 1.0)
     } else {
         gui.editor.selection.end_pos
     };
@@ -704,13 +959,307 @@ pub fn preset_update_editor(content: &mut Content, systems: &mut SystemHolder) {
 
 pub fn save_preset(content: &mut Content, systems: &mut SystemHolder, name: String) -> Result<()> {
     let gui = &mut content.interface.side_window.presets;
-    content.preset.data[gui.selected_index]
-        .name
-        .clone_from(&name);
-    content.preset.data[gui.selected_index].draw_type = gui.editor.cur_type;
-    content.preset.data[gui.selected_index].frames = gui.editor.frames;
-    content.preset.save_preset(gui.selected_index)?;
+
+    // New presets go into the category that is being viewed
+    if gui.edit_new || gui.selected_index >= content.preset.data.len() {
+        content.preset.data.push(PresetData {
+            category: gui.base.category.clone().unwrap_or_default(),
+            ..Default::default()
+        });
+        gui.selected_index = content.preset.data.len() - 1;
+        gui.edit_new = false;
+    }
+
+    let data = &mut content.preset.data[gui.selected_index];
+    data.name = name.trim().to_string();
+    data.draw_type = gui.editor.cur_type;
+    data.frames = gui.editor.frames;
+    data.anim_time = gui
+        .editor
+        .anim_time
+        .text
+        .parse::<u32>()
+        .ok()
+        .filter(|anim_time| *anim_time > 0)
+        .unwrap_or(DEFAULT_ANIM_TIME);
+    content.preset.save_data()?;
     gui.switch_state(systems, PresetWindowType::Base);
     preset_update_list(content, systems);
     Ok(())
 }
+
+pub fn rename_preset(content: &mut Content, systems: &mut SystemHolder, name: &str) -> Result<()> {
+    let selected_index = content.interface.side_window.presets.selected_index;
+    let Some(data) = content.preset.data.get_mut(selected_index) else {
+        return Ok(());
+    };
+
+    data.name = name.trim().to_string();
+    content.preset.save_data()?;
+    preset_update_list(content, systems);
+    Ok(())
+}
+
+// An empty category takes the preset out of its folder
+pub fn set_preset_category(
+    content: &mut Content,
+    systems: &mut SystemHolder,
+    category: &str,
+) -> Result<()> {
+    let selected_index = content.interface.side_window.presets.selected_index;
+    let Some(data) = content.preset.data.get_mut(selected_index) else {
+        return Ok(());
+    };
+
+    data.category = category.trim().to_string();
+    content.preset.save_data()?;
+    preset_update_list(content, systems);
+    Ok(())
+}
+
+// Name of the first terrain that paints with the preset
+fn get_preset_terrain(content: &Content, preset: usize) -> Option<String> {
+    let terrains = &content.terrain;
+    terrains
+        .terrain
+        .iter()
+        .find(|terrain| terrain.preset == preset)
+        .map(|terrain| terrain.name.clone())
+        .or_else(|| {
+            terrains
+                .transition
+                .iter()
+                .find(|transition| transition.preset == preset)
+                .and_then(|transition| terrains.terrain.get(transition.to))
+                .map(|terrain| terrain.name.clone())
+        })
+}
+
+// Terrains point at their presets by index, they follow the presets that moved
+fn remap_terrain_presets(content: &mut Content, remap: impl Fn(usize) -> usize) -> Result<()> {
+    let terrains = &mut content.terrain;
+    let mut changed = false;
+
+    let presets = terrains
+        .terrain
+        .iter_mut()
+        .map(|terrain| &mut terrain.preset)
+        .chain(
+            terrains
+                .transition
+                .iter_mut()
+                .map(|transition| &mut transition.preset),
+        );
+    for preset in presets {
+        let new_preset = remap(*preset);
+        if new_preset != *preset {
+            *preset = new_preset;
+            changed = true;
+        }
+    }
+
+    if changed {
+        terrains.save_data()?;
+    }
+    Ok(())
+}
+
+pub fn delete_preset(content: &mut Content, systems: &mut SystemHolder) -> Result<()> {
+    let selected_index = content.interface.side_window.presets.selected_index;
+    if selected_index >= content.preset.data.len() {
+        return Ok(());
+    }
+
+    content.preset.data.remove(selected_index);
+    content.preset.save_data()?;
+    remap_terrain_presets(content, |preset| {
+        if preset > selected_index {
+            preset - 1
+        } else {
+            preset
+        }
+    })?;
+
+    let gui = &mut content.interface.side_window.presets;
+    gui.export_select = gui
+        .export_select
+        .iter()
+        .filter(|index| **index != selected_index)
+        .map(|index| {
+            if *index > selected_index {
+                index - 1
+            } else {
+                *index
+            }
+        })
+        .collect();
+    content.interface.side_window.presets.selected_index =
+        selected_index.min(content.preset.data.len().saturating_sub(1));
+    preset_update_list(content, systems);
+    Ok(())
+}
+
+// Swaps the selected preset with the one listed above or below it
+pub fn move_preset(content: &mut Content, systems: &mut SystemHolder, up: bool) -> Result<()> {
+    let gui = &mut content.interface.side_window.presets;
+    let Some(row) = gui
+        .base
+        .list_index
+        .iter()
+        .position(|index| *index == gui.selected_index)
+    else {
+        return Ok(());
+    };
+    let target_row = if up {
+        row.checked_sub(1)
+    } else {
+        Some(row + 1)
+    };
+    let Some(target) = target_row.and_then(|row| gui.base.list_index.get(row).copied()) else {
+        return Ok(());
+    };
+
+    let from = gui.selected_index;
+    content.preset.data.swap(from, target);
+    gui.selected_index = target;
+    gui.export_select = gui
+        .export_select
+        .iter()
+        .map(|index| {
+            if *index == from {
+                target
+            } else if *index == target {
+                from
+            } else {
+                *index
+            }
+        })
+        .collect();
+    content.preset.save_data()?;
+    remap_terrain_presets(content, |preset| {
+        if preset == from {
+            target
+        } else if preset == target {
+            from
+        } else {
+            preset
+        }
+    })?;
+
+    preset_update_list(content, systems);
+    Ok(())
+}
+
+// Presets the bundle export writes, the ones picked with ctrl click or else the selected one
+pub fn get_export_presets(content: &Content) -> Vec<usize> {
+    let gui = &content.interface.side_window.presets;
+    let mut list_index: Vec<usize> = if gui.export_select.is_empty() {
+        vec![gui.selected_index]
+    } else {
+        gui.export_select.iter().copied().collect()
+    };
+    list_index.retain(|index| *index < content.preset.data.len());
+    list_index.sort_unstable();
+    list_index
+}
+
+// Writes the selected presets into a bundle, returns how many got exported
+pub fn export_presets(
+    content: &mut Content,
+    systems: &mut SystemHolder,
+    name: &str,
+) -> Result<usize> {
+    let list_index = get_export_presets(content);
+
+    let presets: Vec<BundlePreset> = list_index
+        .iter()
+        .map(|index| {
+            let data = &content.preset.data[*index];
+            let is_animated = data.draw_type.is_animated();
+            let frames = std::array::from_fn(|i| {
+                let frame = data.frames[i];
+                // Unused frames keep their default tile sheet, which may not exist elsewhere
+                let tileset = if i == 0 || is_animated {
+                    systems
+                        .resource
+                        .tilesheet
+                        .get(frame.tileset as usize)
+                        .map(|sheet| sheet.get_name())
+                        .unwrap_or_default()
+                } else {
+                    String::new()
+                };
+                BundleFrame {
+                    start: frame.start,
+                    end: frame.end,
+                    tileset,
+                }
+            });
+            BundlePreset {
+                name: data.name.clone(),
+                category: data.category.clone(),
+                draw_type: data.draw_type,
+                frames,
+                anim_time: data.anim_time,
+            }
+        })
+        .collect();
+
+    let count = presets.len();
+    PresetBundle { presets }.save_data(name)?;
+    Ok(count)
+}
+
+// Adds the presets of a bundle, tile sheets are matched by name as their index differs
+// between projects. Presets using a missing sheet are skipped and the sheet names returned
+pub fn import_presets(
+    content: &mut Content,
+    systems: &mut SystemHolder,
+    name: &str,
+) -> Result<(usize, Vec<String>)> {
+    let bundle = PresetBundle::load_data(name)?;
+    let sheets = &systems.resource.tilesheet;
+    let mut missing: Vec<String> = Vec::new();
+    let mut count = 0;
+
+    for preset in bundle.presets {
+        let mut frames = [PresetFrames::default(); 4];
+        let mut found = true;
+
+        for (frame, bundle_frame) in frames.iter_mut().zip(preset.frames.iter()) {
+            frame.start = bundle_frame.start;
+            frame.end = bundle_frame.end;
+            if bundle_frame.tileset.is_empty() {
+                continue;
+            }
+
+            if let Some(index) = sheets.iter().position(|sheet| {
+                sheet.get_name() == bundle_frame.tileset || sheet.name == bundle_frame.tileset
+            }) {
+                frame.tileset = index as u16;
+            } else {
+                found = false;
+                if !missing.contains(&bundle_frame.tileset) {
+                    missing.push(bundle_frame.tileset.clone());
+                }
+            }
+        }
+
+        if found {
+            content.preset.data.push(PresetData {
+                name: preset.name,
+                category: preset.category,
+                draw_type: preset.draw_type,
+                frames,
+                anim_time: preset.anim_time,
+            });
+            count += 1;
+        }
+    }
+
+    if count > 0 {
+        content.preset.save_data()?;
+        preset_update_list(content, systems);
+    }
+    Ok((count, missing))
+}
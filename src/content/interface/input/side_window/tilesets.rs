@@ -4,7 +4,7 @@ use crate::{
     content::{
         Content,
         interface::side_window::TilesetWindow,
-        widget::{Tooltip, is_within_area},
+        widget::{Alert, AlertBuilder, AlertIndex, Tooltip, is_within_area},
     },
     data_types::*,
     renderer::SystemHolder,
@@ -25,9 +25,27 @@ impl TilesetWindow {
         self.scrollbar.set_hover(systems, in_scroll);
 
         self.tile_list.hover_widget(systems, mouse_pos);
+
+        for button in self.button.iter_mut() {
+            let in_hover = button.visible && button.in_area(systems, mouse_pos);
+            button.set_hover(systems, in_hover);
+        }
+
+        for checkbox in self.collision.iter_mut() {
+            let in_hover = self.in_properties && checkbox.in_area(systems, mouse_pos);
+            checkbox.set_hover(systems, in_hover);
+        }
     }
 
     pub fn reset_widgets(&mut self, systems: &mut SystemHolder, mouse_pos: Vec2) {
+        for button in self.button.iter_mut() {
+            button.set_click(systems, false);
+        }
+
+        for checkbox in self.collision.iter_mut() {
+            checkbox.set_click(systems, false);
+        }
+
         self.scrollbar.set_hold(systems, false, mouse_pos);
         self.tile_list.reset_widget(systems, mouse_pos);
         self.selection.in_hold = false;
@@ -103,6 +121,110 @@ impl TilesetWindow {
         None
     }
 
+    pub fn click_button(&mut self, systems: &mut SystemHolder, mouse_pos: Vec2) -> Option<usize> {
+        if self.tile_list.list.visible {
+            return None;
+        }
+
+        for (index, button) in self.button.iter_mut().enumerate() {
+            if button.visible && button.in_area(systems, mouse_pos) {
+                button.set_click(systems, true);
+                return Some(index);
+            }
+        }
+
+        None
+    }
+
+    // Applies the clicked collision to all of the selected tiles
+    pub fn click_collision(&mut self, systems: &mut SystemHolder, mouse_pos: Vec2) -> bool {
+        if !self.in_properties {
+            return false;
+        }
+
+        let Some(index) = self
+            .collision
+            .iter()
+            .position(|checkbox| checkbox.in_area(systems, mouse_pos))
+        else {
+            return false;
+        };
+        self.collision[index].set_click(systems, true);
+        let value = self.collision[index].value;
+
+        for (x, y) in self.get_selected_tiles() {
+            let collision = &mut self.edit_meta.get_tile_mut(x, y).collision;
+            match index {
+                0 => collision.blocked = value,
+                1 => collision.npc_blocked = value,
+                _ => {
+                    let bit = match index {
+                        2 => 0b0010, // Up
+                        3 => 0b0001, // Down
+                        4 => 0b0100, // Left
+                        _ => 0b1000, // Right
+                    };
+                    if value {
+                        collision.dir_block |= bit;
+                    } else {
+                        collision.dir_block &= !bit;
+                    }
+                }
+            }
+        }
+        true
+    }
+
+    pub fn click_textbox(&mut self, systems: &mut SystemHolder, mouse_pos: Vec2) -> bool {
+        if !self.visible || self.tile_list.list.visible {
+            return false;
+        }
+
+        let mut did_click = false;
+
+        for (index, inputbox) in self.textbox.iter_mut().enumerate() {
+            if (index == 0) == self.in_properties {
+                continue;
+            }
+
+            if inputbox.in_area(systems, mouse_pos) {
+                inputbox.set_select(systems, true);
+                inputbox.set_hold(true);
+                inputbox.select_text(systems, mouse_pos);
+                self.cur_textbox = Some(index);
+                did_click = true;
+                break;
+            }
+        }
+
+        did_click
+    }
+
+    // Filter Tag, Name, Tags, Frames
+    pub fn update_tileset_setting(&mut self, systems: &mut SystemHolder, index: usize) {
+        let text = self.textbox[index].text.clone();
+        match index {
+            0 => self.update_filter(systems),
+            1 => self.edit_meta.name = text.trim().to_string(),
+            2 => {
+                let tags: Vec<String> = text
+                    .split(',')
+                    .map(|tag| tag.trim().to_string())
+                    .filter(|tag| !tag.is_empty())
+                    .collect();
+                for (x, y) in self.get_selected_tiles() {
+                    self.edit_meta.get_tile_mut(x, y).tags = tags.clone();
+                }
+            }
+            _ => {
+                let frames = text.parse::<u32>().unwrap_or_default().min(MAX_ANIM_FRAMES);
+                for (x, y) in self.get_selected_tiles() {
+                    self.edit_meta.get_tile_mut(x, y).anim_frames = frames;
+                }
+            }
+        }
+    }
+
     pub fn click_tilesheet(&mut self, systems: &mut SystemHolder, mouse_pos: Vec2) -> bool {
         let tileset_size = Vec2::new(
             ((TILESET_COUNT_X * 20) as f32 * systems.scale as f32).floor(),
@@ -147,15 +269,89 @@ impl TilesetWindow {
             }
 
             self.select_tile(systems, self.selection.start_pos, self.selection.end_pos);
+            if self.in_properties {
+                self.update_properties(systems);
+            }
             return true;
         }
         false
     }
 }
 
+pub fn switch_tileset(content: &mut Content, systems: &mut SystemHolder, tileset: usize) {
+    let gui = &mut content.interface.side_window.tilesets;
+
+    gui.tile_list.list.set_select(systems, Some(tileset), true);
+    gui.tile_list.update_label(systems, tileset);
+    gui.change_tileset(systems, tileset);
+    if gui.in_properties {
+        open_tileset_properties(content, systems);
+    }
+}
+
+pub fn open_tileset_properties(content: &mut Content, systems: &mut SystemHolder) {
+    let gui = &mut content.interface.side_window.tilesets;
+
+    let Some(data) = systems.resource.tilesheet.get(gui.cur_tileset) else {
+        return;
+    };
+    gui.edit_meta = data.meta.clone();
+    gui.set_properties(systems, true);
+    gui.update_properties(systems);
+}
+
+// Writes the properties next to the tile sheet image and updates the sheet names in the lists
+pub fn save_tileset_properties(
+    content: &mut Content,
+    systems: &mut SystemHolder,
+    alert: &mut Alert,
+) {
+    let gui = &mut content.interface.side_window.tilesets;
+    let cur_tileset = gui.cur_tileset;
+
+    let Some(path) = systems
+        .resource
+        .tilesheet
+        .get(cur_tileset)
+        .map(|data| data.path.clone())
+    else {
+        return;
+    };
+
+    if let Err(e) = gui.edit_meta.save_data(&path) {
+        alert.show_alert(
+            systems,
+            &AlertBuilder::new_info("Error", &format!("Failed to save tileset properties: {e}")),
+        );
+        return;
+    }
+    systems.resource.tilesheet[cur_tileset].meta = gui.edit_meta.clone();
+    gui.set_properties(systems, false);
+
+    let tileset_name_list: Vec<String> = systems
+        .resource
+        .tilesheet
+        .iter()
+        .map(|data| data.get_name())
+        .collect();
+
+    gui.tile_list
+        .list
+        .update_list(systems, tileset_name_list.clone(), Some(cur_tileset));
+    gui.tile_list.update_label(systems, cur_tileset);
+
+    let editor = &mut content.interface.side_window.presets.editor;
+    editor
+        .tile_list
+        .list
+        .update_list(systems, tileset_name_list, Some(editor.cur_tileset));
+    editor.tile_list.update_label(systems, editor.cur_tileset);
+}
+
 pub fn side_tileset_click_widget(
     content: &mut Content,
     systems: &mut SystemHolder,
+    alert: &mut Alert,
     mouse_pos: Vec2,
 ) -> bool {
     if !content.interface.side_window.tilesets.visible {
@@ -169,7 +365,46 @@ pub fn side_tileset_click_widget(
     }
 
     if let Some(list_index) = gui.click_tile_list(systems, mouse_pos) {
-        gui.change_tileset(systems, list_index);
+        // Switching the sheet would drop the properties that are not saved yet
+        if gui.in_properties
+            && list_index != gui.cur_tileset
+            && systems
+                .resource
+                .tilesheet
+                .get(gui.cur_tileset)
+                .is_some_and(|data| data.meta != gui.edit_meta)
+        {
+            let cur_tileset = gui.cur_tileset;
+            gui.tile_list
+                .list
+                .set_select(systems, Some(cur_tileset), true);
+            gui.tile_list.update_label(systems, cur_tileset);
+
+            alert.show_alert(
+                systems,
+                AlertBuilder::new_confirm(
+                    "Tileset Properties",
+                    "Discard the unsaved properties of this tileset?",
+                )
+                .with_index(AlertIndex::SwitchTileset(list_index)),
+            );
+            return true;
+        }
+
+        switch_tileset(content, systems, list_index);
+        return true;
+    }
+
+    if let Some(index) = gui.click_button(systems, mouse_pos) {
+        match index {
+            0 => open_tileset_properties(content, systems),
+            1 => save_tileset_properties(content, systems, alert),
+            _ => gui.set_properties(systems, false),
+        }
+        return true;
+    }
+
+    if gui.click_collision(systems, mouse_pos) {
         return true;
     }
 
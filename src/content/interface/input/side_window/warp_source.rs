@@ -0,0 +1,190 @@
+use ascending_graphics::*;
+
+use crate::{
+    content::{
+        Content,
+        interface::side_window::{WarpSource, WarpSourceWindow},
+        widget::Tooltip,
+    },
+    data_types::Result,
+    database::{
+        MapPosition, get_map_file_list, get_temp_map_file_list, is_temp_map_exist, load_map_file,
+        load_temp_map_file,
+    },
+    renderer::SystemHolder,
+};
+
+impl WarpSourceWindow {
+    pub fn hover_widgets(
+        &mut self,
+        systems: &mut SystemHolder,
+        mouse_pos: Vec2,
+        _tooltip: &mut Tooltip,
+    ) {
+        if !self.visible {
+            return;
+        }
+
+        self.source_list.hover_list(systems, mouse_pos);
+        self.source_list.hover_scrollbar(systems, mouse_pos);
+
+        for button in self.button.iter_mut() {
+            let in_area = button.in_area(systems, mouse_pos);
+            button.set_hover(systems, in_area);
+        }
+    }
+
+    pub fn reset_widgets(&mut self, systems: &mut SystemHolder, mouse_pos: Vec2) {
+        self.source_list
+            .scrollbar
+            .set_hold(systems, false, mouse_pos);
+
+        for button in self.button.iter_mut() {
+            button.set_click(systems, false);
+        }
+    }
+
+    pub fn hold_scrollbar(&mut self, systems: &mut SystemHolder, mouse_pos: Vec2) -> bool {
+        if !self.visible {
+            return false;
+        }
+
+        if self.source_list.scrollbar.in_scroll(mouse_pos) {
+            self.source_list
+                .scrollbar
+                .set_hold(systems, true, mouse_pos);
+            return true;
+        }
+
+        false
+    }
+
+    pub fn hold_move_scrollbar(&mut self, systems: &mut SystemHolder, mouse_pos: Vec2) {
+        if !self.visible {
+            return;
+        }
+
+        if self.source_list.visible {
+            self.source_list
+                .scrollbar
+                .set_move_scroll(systems, mouse_pos);
+            self.source_list.update_list_scroll(systems);
+        }
+    }
+
+    pub fn click_button(&mut self, systems: &mut SystemHolder, mouse_pos: Vec2) -> Option<usize> {
+        for (index, button) in self.button.iter_mut().enumerate() {
+            if button.in_area(systems, mouse_pos) && !button.disabled {
+                button.set_click(systems, true);
+                return Some(index);
+            }
+        }
+
+        None
+    }
+}
+
+pub fn side_warp_source_click_widget(
+    content: &mut Content,
+    systems: &mut SystemHolder,
+    mouse_pos: Vec2,
+) -> bool {
+    if !content.interface.side_window.warp_source.visible {
+        return false;
+    }
+
+    let gui = &mut content.interface.side_window.warp_source;
+
+    if gui.hold_scrollbar(systems, mouse_pos) {
+        return true;
+    }
+
+    if let Some(list_index) = gui.source_list.select_list_by_pos(systems, mouse_pos, true) {
+        content
+            .map_view
+            .select_warp_marker(systems, Some(list_index));
+        return true;
+    }
+
+    if let Some(index) = gui.click_button(systems, mouse_pos) {
+        if index == 0 {
+            gui.need_update = true;
+        } // Refresh
+        return true;
+    }
+
+    false
+}
+
+// Saved maps are read from their temp file when they got unsaved edits,
+// the opened and linked maps are read from what is currently in the editor
+fn find_warp_source(content: &Content, target: MapPosition) -> Result<Vec<WarpSource>> {
+    let mut map_list = get_map_file_list()?;
+    for map_pos in get_temp_map_file_list()? {
+        if !map_list.contains(&map_pos) {
+            map_list.push(map_pos);
+        }
+    }
+    map_list.sort_by_key(|map_pos| (map_pos.group, map_pos.x, map_pos.y));
+
+    let mut sources = Vec::new();
+    for map_pos in map_list {
+        let warps = if content.data.pos == Some(map_pos) {
+            content.data.mapdata.get_warp_to(target)
+        } else if let Some(link_data) = content
+            .data
+            .link_map
+            .iter()
+            .flatten()
+            .find(|link_data| link_data.pos == map_pos)
+        {
+            link_data.mapdata.get_warp_to(target)
+        } else if is_temp_map_exist(map_pos.x, map_pos.y, map_pos.group as u64) {
+            load_temp_map_file(map_pos.x, map_pos.y, map_pos.group as u64)?.get_warp_to(target)
+        } else {
+            load_map_file(map_pos.x, map_pos.y, map_pos.group as u64, false)?.get_warp_to(target)
+        };
+
+        sources.extend(warps.into_iter().map(|(x, y, warp)| WarpSource {
+            map_pos,
+            from: (x, y),
+            to: (warp.tile_x, warp.tile_y),
+        }));
+    }
+    Ok(sources)
+}
+
+pub fn warp_source_update_list(content: &mut Content, systems: &mut SystemHolder) -> Result<()> {
+    let sources = if let Some(target) = content.data.pos {
+        find_warp_source(content, target)?
+    } else {
+        Vec::new()
+    };
+
+    let list = sources
+        .iter()
+        .map(|source| {
+            format!(
+                "[{},{},{}] {},{} > {},{}",
+                source.map_pos.x,
+                source.map_pos.y,
+                source.map_pos.group,
+                source.from.0,
+                source.from.1,
+                source.to.0,
+                source.to.1
+            )
+        })
+        .collect();
+    let arrival: Vec<(u32, u32)> = sources.iter().map(|source| source.to).collect();
+
+    let gui = &mut content.interface.side_window.warp_source;
+    gui.source_list.update_list(systems, list, None);
+    gui.sources = sources;
+    gui.scanned_pos = content.data.pos;
+    gui.need_update = false;
+
+    let visible = gui.visible;
+    content.map_view.set_warp_marker(systems, &arrival, visible);
+    Ok(())
+}
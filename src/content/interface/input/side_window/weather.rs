@@ -1,7 +1,7 @@
 use ascending_graphics::*;
 
 use crate::{
-    content::{Content, interface::side_window::WeatherWindow, widget::Tooltip},
+    content::{Content, EditorChange, interface::side_window::WeatherWindow, widget::Tooltip},
     database::Weather,
     renderer::SystemHolder,
 };
@@ -101,7 +101,15 @@ pub fn side_weather_click_widget(
     }
 
     if let Some(list_index) = gui.click_weather_list(systems, mouse_pos) {
-        content.data.mapdata.weather = Weather::from_index(list_index);
+        let weather = Weather::from_index(list_index);
+        if content.data.mapdata.weather == weather {
+            return true;
+        }
+
+        content
+            .data
+            .record_change(EditorChange::Weather(content.data.mapdata.weather, weather));
+        content.data.mapdata.weather = weather;
         content.data.changed = true;
         content.data.temp_saved = false;
         if let Some(map_pos) = content.data.pos {
@@ -1,7 +1,10 @@
 use ascending_graphics::*;
 
 use crate::{
-    content::{Content, interface::side_window::ZoneWindow, update_zone_visible, widget::Tooltip},
+    content::{
+        Content, add_zone, add_zone_npc, interface::side_window::ZoneWindow, remove_zone,
+        remove_zone_npc, update_zone_textbox, update_zone_visible, widget::Tooltip,
+    },
     renderer::SystemHolder,
 };
 
@@ -16,7 +19,17 @@ impl ZoneWindow {
             return;
         }
 
-        for checkbox in self.zones.iter_mut() {
+        for list in [&mut self.zone_list, &mut self.npc_list] {
+            list.hover_list(systems, mouse_pos);
+            list.hover_scrollbar(systems, mouse_pos);
+        }
+
+        for button in self.button.iter_mut() {
+            let in_hover = button.in_area(systems, mouse_pos);
+            button.set_hover(systems, in_hover);
+        }
+
+        for checkbox in self.option.iter_mut() {
             let in_hover = checkbox.in_area(systems, mouse_pos);
             checkbox.set_hover(systems, in_hover);
         }
@@ -26,11 +39,17 @@ impl ZoneWindow {
     }
 
     pub fn reset_widgets(&mut self, systems: &mut SystemHolder, mouse_pos: Vec2) {
-        for button in self.zones.iter_mut() {
+        for button in self.button.iter_mut() {
             button.set_click(systems, false);
         }
 
+        for checkbox in self.option.iter_mut() {
+            checkbox.set_click(systems, false);
+        }
+
         self.scrollbar.set_hold(systems, false, mouse_pos);
+        self.zone_list.scrollbar.set_hold(systems, false, mouse_pos);
+        self.npc_list.scrollbar.set_hold(systems, false, mouse_pos);
     }
 
     pub fn hold_scrollbar(&mut self, systems: &mut SystemHolder, mouse_pos: Vec2) -> bool {
@@ -38,6 +57,13 @@ impl ZoneWindow {
             return false;
         }
 
+        for list in [&mut self.zone_list, &mut self.npc_list] {
+            if list.scrollbar.in_scroll(mouse_pos) {
+                list.scrollbar.set_hold(systems, true, mouse_pos);
+                return true;
+            }
+        }
+
         if self.scrollbar.in_scroll(mouse_pos) {
             self.scrollbar.set_hold(systems, true, mouse_pos);
             return true;
@@ -51,14 +77,38 @@ impl ZoneWindow {
             return;
         }
 
+        if self.zone_list.scrollbar.in_hold {
+            self.zone_list.scrollbar.set_move_scroll(systems, mouse_pos);
+            self.zone_list.update_list_scroll(systems);
+            self.update_zone_color(systems);
+            return;
+        }
+
+        if self.npc_list.scrollbar.in_hold {
+            self.npc_list.scrollbar.set_move_scroll(systems, mouse_pos);
+            self.npc_list.update_list_scroll(systems);
+            return;
+        }
+
         self.scrollbar.set_move_scroll(systems, mouse_pos);
         if self.scrollbar.in_hold {
             self.update_content(systems);
         }
     }
 
-    pub fn click_zones(&mut self, systems: &mut SystemHolder, mouse_pos: Vec2) -> Option<usize> {
-        for (index, checkbox) in self.zones.iter_mut().enumerate() {
+    pub fn click_button(&mut self, systems: &mut SystemHolder, mouse_pos: Vec2) -> Option<usize> {
+        for (index, button) in self.button.iter_mut().enumerate() {
+            if button.in_area(systems, mouse_pos) && !button.disabled {
+                button.set_click(systems, true);
+                return Some(index);
+            }
+        }
+
+        None
+    }
+
+    pub fn click_option(&mut self, systems: &mut SystemHolder, mouse_pos: Vec2) -> Option<usize> {
+        for (index, checkbox) in self.option.iter_mut().enumerate() {
             if checkbox.in_area(systems, mouse_pos) {
                 return Some(index);
             }
@@ -100,29 +150,40 @@ pub fn side_zone_click_widget(
         return true;
     }
 
-    if let Some(index) = gui.click_zones(systems, mouse_pos)
-        && index != gui.cur_zone
-    {
-        gui.zones[gui.cur_zone].set_value(systems, false);
-        gui.cur_zone = index;
-        gui.zones[gui.cur_zone].set_value(systems, true);
+    if let Some(index) = gui.zone_list.select_list_by_pos(systems, mouse_pos, true) {
+        if index != gui.cur_zone {
+            gui.cur_zone = index;
+            gui.cur_npc = None;
 
-        let zone_data = content.data.mapdata.zones[index];
+            update_zone_textbox(content, systems);
+            update_zone_visible(content, systems);
+        }
+        return true;
+    }
 
-        gui.textbox[0].set_text(systems, format!("{}", zone_data.0));
+    if let Some(index) = gui.npc_list.select_list_by_pos(systems, mouse_pos, true) {
+        if gui.cur_npc != Some(index) {
+            gui.cur_npc = Some(index);
+            update_zone_textbox(content, systems);
+        }
+        return true;
+    }
 
-        for i in 0..5 {
-            gui.textbox[i + 1].set_text(
-                systems,
-                if let Some(data) = zone_data.1[i] {
-                    format!("{data}")
-                } else {
-                    String::new()
-                },
-            );
+    if let Some(index) = gui.click_button(systems, mouse_pos) {
+        match index {
+            0 => add_zone(content, systems),
+            1 => remove_zone(content, systems),
+            2 => add_zone_npc(content, systems),
+            _ => remove_zone_npc(content, systems),
         }
+        return true;
+    }
 
-        update_zone_visible(content, systems);
+    if let Some(index) = gui.click_option(systems, mouse_pos) {
+        gui.option[index].set_click(systems, true);
+        if index == 0 {
+            update_zone_visible(content, systems);
+        } // Show All Zones
         return true;
     }
 
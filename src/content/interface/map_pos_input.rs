@@ -3,6 +3,7 @@ use ascending_graphics::*;
 use crate::{
     content::widget::{button::*, create_label, textbox::*},
     data_types::*,
+    database::get_map_template_list,
     gfx_collection::GfxType,
     renderer::SystemHolder,
 };
@@ -11,6 +12,8 @@ pub enum MapPosInputType {
     None,
     LoadMap,
     SaveMap,
+    DuplicateMap,
+    MoveMap,
 }
 
 pub struct MapPosInput {
@@ -23,6 +26,9 @@ pub struct MapPosInput {
     pub button: Vec<Button>,
     pub cur_textbox: Option<usize>,
     pub input_type: MapPosInputType,
+    // Used when the loaded position does not have a map yet
+    pub template_list: Vec<String>,
+    pub cur_template: Option<usize>,
 }
 
 impl MapPosInput {
@@ -44,7 +50,7 @@ impl MapPosInput {
             CameraView::SubView1,
         );
 
-        let bg_size = (Vec2::new(350.0, 120.0) * systems.scale as f32).floor();
+        let bg_size = (Vec2::new(350.0, 150.0) * systems.scale as f32).floor();
         let bg_pos = ((screen_size - bg_size) * 0.5).floor();
 
         let mut window = Rect::new(
@@ -75,7 +81,7 @@ impl MapPosInput {
                 (
                     Vec3::new(
                         bg_pos.x + (10.0 * systems.scale as f32).floor(),
-                        bg_pos.y + (80.0 * systems.scale as f32).floor(),
+                        bg_pos.y + (110.0 * systems.scale as f32).floor(),
                         ORDER_MAPPOS,
                     ),
                     Vec2::new(
@@ -87,7 +93,7 @@ impl MapPosInput {
                 (
                     Vec3::new(
                         bg_pos.x + (10.0 * systems.scale as f32).floor() + add_x,
-                        bg_pos.y + (50.0 * systems.scale as f32).floor(),
+                        bg_pos.y + (80.0 * systems.scale as f32).floor(),
                         ORDER_MAPPOS,
                     ),
                     (Vec2::new(50.0, 20.0) * systems.scale as f32).floor(),
@@ -139,7 +145,7 @@ impl MapPosInput {
             if i < 3 {
                 let textbox_pos = Vec3::new(
                     bg_pos.x + (10.0 * systems.scale as f32).floor() + add_x,
-                    bg_pos.y + (51.0 * systems.scale as f32).floor(),
+                    bg_pos.y + (81.0 * systems.scale as f32).floor(),
                     ORDER_MAPPOS,
                 );
                 let textbox_size = Vec2::new(50.0, 20.0);
@@ -247,7 +253,32 @@ impl MapPosInput {
             false,
         );
 
-        let button = vec![confirm, cancel];
+        let template = Button::new(
+            systems,
+            ButtonType::Rect(button_rect),
+            ButtonContentType::Text(ButtonContentText {
+                text: "Template: None".to_string(),
+                pos: Vec2::new(0.0, 2.0),
+                color: Color::rgb(255, 255, 255),
+                order_layer: 3,
+                buffer_layer: RENDER_MAPPOS_TEXT,
+                hover_change: ButtonChangeType::None,
+                click_change: ButtonChangeType::None,
+                alert_change: ButtonChangeType::None,
+                disable_change: ButtonChangeType::None,
+            }),
+            bg_pos,
+            Vec2::new(10.0, 47.0),
+            ORDER_MAPPOS,
+            Vec2::new(330.0, 24.0),
+            2,
+            RENDER_MAPPOS_GUI,
+            false,
+            None,
+            false,
+        );
+
+        let button = vec![confirm, cancel, template];
 
         MapPosInput {
             visible: false,
@@ -259,6 +290,8 @@ impl MapPosInput {
             button,
             cur_textbox: None,
             input_type: MapPosInputType::None,
+            template_list: Vec::new(),
+            cur_template: None,
         }
     }
 
@@ -270,7 +303,7 @@ impl MapPosInput {
 
         let screen_size = Vec2::new(systems.size.width, systems.size.height);
         println!("Size {screen_size:?}");
-        let bg_size = (Vec2::new(350.0, 120.0) * systems.scale as f32).floor();
+        let bg_size = (Vec2::new(350.0, 150.0) * systems.scale as f32).floor();
         let bg_pos = ((screen_size - bg_size) * 0.5).floor();
 
         systems
@@ -283,7 +316,7 @@ impl MapPosInput {
                 (
                     Vec3::new(
                         bg_pos.x + (10.0 * systems.scale as f32).floor(),
-                        bg_pos.y + (80.0 * systems.scale as f32).floor(),
+                        bg_pos.y + (110.0 * systems.scale as f32).floor(),
                         ORDER_MAPPOS,
                     ),
                     Vec2::new(
@@ -295,7 +328,7 @@ impl MapPosInput {
                 (
                     Vec3::new(
                         bg_pos.x + (10.0 * systems.scale as f32).floor() + add_x,
-                        bg_pos.y + (50.0 * systems.scale as f32).floor(),
+                        bg_pos.y + (80.0 * systems.scale as f32).floor(),
                         ORDER_MAPPOS,
                     ),
                     (Vec2::new(50.0, 20.0) * systems.scale as f32).floor(),
@@ -326,7 +359,7 @@ impl MapPosInput {
             if i < 3 {
                 let textbox_pos = Vec3::new(
                     bg_pos.x + (10.0 * systems.scale as f32).floor() + add_x,
-                    bg_pos.y + (51.0 * systems.scale as f32).floor(),
+                    bg_pos.y + (81.0 * systems.scale as f32).floor(),
                     ORDER_MAPPOS,
                 );
                 self.textbox[i].set_pos(systems, Vec2::new(textbox_pos.x, textbox_pos.y));
@@ -339,6 +372,7 @@ impl MapPosInput {
 
         self.button[0].set_pos(systems, bg_pos);
         self.button[1].set_pos(systems, bg_pos);
+        self.button[2].set_pos(systems, bg_pos);
     }
 
     pub fn set_visible(&mut self, systems: &mut SystemHolder, visible: bool) {
@@ -357,13 +391,44 @@ impl MapPosInput {
         for button in self.button.iter_mut() {
             button.set_visible(systems, visible);
         }
+        // Templates are only picked when opening a map
+        self.button[2].set_visible(
+            systems,
+            visible && matches!(self.input_type, MapPosInputType::LoadMap),
+        );
     }
 
     pub fn open(&mut self, systems: &mut SystemHolder, input_type: MapPosInputType) {
         self.input_type = input_type;
+        self.template_list = get_map_template_list().unwrap_or_default();
+        self.cur_template = None;
+        self.update_template_label(systems);
         self.set_visible(systems, true);
         for textbox in self.textbox.iter_mut() {
             textbox.set_text(systems, "0".to_string());
         }
     }
+
+    pub fn get_template(&self) -> Option<&String> {
+        self.cur_template
+            .and_then(|index| self.template_list.get(index))
+    }
+
+    // Cycles through the saved templates, ending with no template
+    pub fn switch_template(&mut self, systems: &mut SystemHolder) {
+        self.cur_template = match self.cur_template {
+            None if !self.template_list.is_empty() => Some(0),
+            Some(index) if index + 1 < self.template_list.len() => Some(index + 1),
+            _ => None,
+        };
+        self.update_template_label(systems);
+    }
+
+    fn update_template_label(&mut self, systems: &mut SystemHolder) {
+        let text = format!(
+            "Template: {}",
+            self.get_template().map_or("None", |name| name.as_str())
+        );
+        self.button[2].change_text(systems, text);
+    }
 }
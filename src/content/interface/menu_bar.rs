@@ -16,6 +16,7 @@ pub struct MenuBar {
     pub button: Vec<Button>,
     pub file_menu: TextList,
     pub edit_menu: TextList,
+    pub view_menu: TextList,
 }
 
 impl MenuBar {
@@ -101,6 +102,33 @@ impl MenuBar {
                 None,
                 false,
             ),
+            Button::new(
+                systems,
+                ButtonType::Rect(button_rect),
+                ButtonContentType::Text(ButtonContentText {
+                    text: "View".to_string(),
+                    pos: Vec2::new(0.0, 0.0),
+                    color: Color::rgb(255, 255, 255),
+                    order_layer: 3,
+                    buffer_layer: RENDER_GUI_TEXT,
+                    hover_change: ButtonChangeType::None,
+                    click_change: ButtonChangeType::None,
+                    alert_change: ButtonChangeType::None,
+                    disable_change: ButtonChangeType::None,
+                }),
+                Vec2::new(
+                    (102.0 * systems.scale as f32).floor(),
+                    systems.size.height - (20.0 * systems.scale as f32).floor(),
+                ),
+                Vec2::new(0.0, 0.0),
+                ORDER_MENU_BAR,
+                Vec2::new(50.0, 20.0),
+                2,
+                RENDER_GUI,
+                true,
+                None,
+                false,
+            ),
         ];
 
         let bg_rect = TextListBGRect {
@@ -144,11 +172,11 @@ impl MenuBar {
             systems,
             Vec2::new(
                 0.0,
-                systems.size.height - (110.0 * systems.scale as f32).floor(),
+                systems.size.height - (230.0 * systems.scale as f32).floor(),
             ),
             Vec2::new(0.0, 0.0),
             ORDER_MENU_BAR,
-            Vec2::new(120.0, 90.0),
+            Vec2::new(170.0, 210.0),
             false,
             TextListBG::Rect(bg_rect),
             scrollbar_rect,
@@ -158,6 +186,12 @@ impl MenuBar {
                 "Save".to_string(),
                 "Save As...".to_string(),
                 "Reload Map".to_string(),
+                "Duplicate Map...".to_string(),
+                "Move / Rename Map...".to_string(),
+                "Delete Map".to_string(),
+                "Save As Template...".to_string(),
+                "Export Selected Presets...".to_string(),
+                "Import Presets...".to_string(),
             ],
             TextListData {
                 selection_bufferlayer: RENDER_GUI,
@@ -166,7 +200,7 @@ impl MenuBar {
                 text_orderlayer: 5,
                 selection_color,
                 text_color,
-                max_list: 4,
+                max_list: 10,
             },
         );
 
@@ -174,16 +208,51 @@ impl MenuBar {
             systems,
             Vec2::new(
                 (51.0 * systems.scale as f32).floor(),
-                systems.size.height - (70.0 * systems.scale as f32).floor(),
+                systems.size.height - (210.0 * systems.scale as f32).floor(),
+            ),
+            Vec2::new(0.0, 0.0),
+            ORDER_MENU_BAR,
+            Vec2::new(170.0, 190.0),
+            false,
+            TextListBG::Rect(bg_rect),
+            scrollbar_rect,
+            Some(scrollbar_bg),
+            vec![
+                "Undo".to_string(),
+                "Redo".to_string(),
+                "Shift Map (Wrap)...".to_string(),
+                "Shift Map (Clear)...".to_string(),
+                "Flip Horizontal".to_string(),
+                "Flip Vertical".to_string(),
+                "Rotate Clockwise".to_string(),
+                "Rotate Counter CW".to_string(),
+                "Recompute Collisions".to_string(),
+            ],
+            TextListData {
+                selection_bufferlayer: RENDER_GUI,
+                text_bufferlayer: RENDER_GUI_TEXT,
+                selection_orderlayer: 4,
+                text_orderlayer: 5,
+                selection_color,
+                text_color,
+                max_list: 9,
+            },
+        );
+
+        let view_menu = TextList::new(
+            systems,
+            Vec2::new(
+                (102.0 * systems.scale as f32).floor(),
+                systems.size.height - (110.0 * systems.scale as f32).floor(),
             ),
             Vec2::new(0.0, 0.0),
             ORDER_MENU_BAR,
-            Vec2::new(120.0, 50.0),
+            Vec2::new(170.0, 90.0),
             false,
             TextListBG::Rect(bg_rect),
             scrollbar_rect,
             Some(scrollbar_bg),
-            vec!["Undo".to_string(), "Redo".to_string()],
+            get_view_menu_list(systems),
             TextListData {
                 selection_bufferlayer: RENDER_GUI,
                 text_bufferlayer: RENDER_GUI_TEXT,
@@ -191,7 +260,7 @@ impl MenuBar {
                 text_orderlayer: 5,
                 selection_color,
                 text_color,
-                max_list: 8,
+                max_list: 4,
             },
         );
 
@@ -200,6 +269,7 @@ impl MenuBar {
             button,
             file_menu,
             edit_menu,
+            view_menu,
         }
     }
 
@@ -237,15 +307,44 @@ impl MenuBar {
             systems,
             Vec2::new(
                 0.0,
-                systems.size.height - (110.0 * systems.scale as f32).floor(),
+                systems.size.height - (230.0 * systems.scale as f32).floor(),
             ),
         );
         self.edit_menu.set_pos(
             systems,
             Vec2::new(
                 (51.0 * systems.scale as f32).floor(),
-                systems.size.height - (190.0 * systems.scale as f32).floor(),
+                systems.size.height - (210.0 * systems.scale as f32).floor(),
+            ),
+        );
+        self.view_menu.set_pos(
+            systems,
+            Vec2::new(
+                (102.0 * systems.scale as f32).floor(),
+                systems.size.height - (110.0 * systems.scale as f32).floor(),
             ),
         );
     }
 }
+
+// The toggles are named after what clicking them will do
+pub fn get_view_menu_list(systems: &SystemHolder) -> Vec<String> {
+    vec![
+        if systems.config.show_grid {
+            "Hide Grid".to_string()
+        } else {
+            "Show Grid".to_string()
+        },
+        if systems.config.show_ruler {
+            "Hide Rulers".to_string()
+        } else {
+            "Show Rulers".to_string()
+        },
+        "Clear Measure".to_string(),
+        if systems.config.auto_collision {
+            "Disable Tile Collision".to_string()
+        } else {
+            "Enable Tile Collision".to_string()
+        },
+    ]
+}
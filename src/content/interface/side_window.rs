@@ -19,19 +19,25 @@ use crate::{
 
 pub mod attributes;
 pub mod dirblocks;
+pub mod history;
+pub mod inspector;
 pub mod music;
 pub mod presets;
 //pub mod properties;
 pub mod tilesets;
+pub mod warp_source;
 pub mod weather;
 pub mod zones;
 
 pub use attributes::*;
 pub use dirblocks::*;
+pub use history::*;
+pub use inspector::*;
 pub use music::*;
 pub use presets::*;
 //pub use properties::*;
 pub use tilesets::*;
+pub use warp_source::*;
 pub use weather::*;
 pub use zones::*;
 
@@ -45,6 +51,9 @@ pub struct SideWindow {
     pub presets: PresetWindow,
     pub dirblocks: DirBlockWindow,
     pub music: MusicWindow,
+    pub history: HistoryWindow,
+    pub warp_source: WarpSourceWindow,
+    pub inspector: InspectorWindow,
     //pub properties: PropertiesWindow,
     pub weather: WeatherWindow,
     pub zone: ZoneWindow,
@@ -154,6 +163,9 @@ impl SideWindow {
             presets: PresetWindow::new(systems, bg_pos, bg_size),
             dirblocks: DirBlockWindow::new(systems, bg_pos, bg_size),
             music: MusicWindow::new(audio_collection, systems, bg_pos, bg_size),
+            history: HistoryWindow::new(systems, bg_pos, bg_size),
+            warp_source: WarpSourceWindow::new(systems, bg_pos, bg_size),
+            inspector: InspectorWindow::new(systems, bg_pos, bg_size),
             //properties: PropertiesWindow::new(systems, bg_pos, bg_size),
             weather: WeatherWindow::new(systems, bg_pos, bg_size),
             zone: ZoneWindow::new(systems, bg_pos, bg_size),
@@ -196,6 +208,9 @@ impl SideWindow {
         self.presets.screen_resize(systems, bg_pos, bg_size);
         self.dirblocks.screen_resize(systems, bg_pos, bg_size);
         self.music.screen_resize(systems, bg_pos, bg_size);
+        self.history.screen_resize(systems, bg_pos, bg_size);
+        self.warp_source.screen_resize(systems, bg_pos, bg_size);
+        self.inspector.screen_resize(systems, bg_pos, bg_size);
         //self.properties.screen_resize(systems, bg_pos, bg_size);
         self.weather.screen_resize(systems, bg_pos, bg_size);
         self.zone.screen_resize(systems, bg_pos, bg_size);
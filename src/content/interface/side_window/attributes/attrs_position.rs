@@ -1,13 +1,21 @@
 use ascending_graphics::*;
 
 use crate::{
-    content::widget::{Textbox, create_label, scrollbar::*},
+    content::widget::{Textbox, button::*, checkbox::*, create_label, scrollbar::*},
     data_types::*,
     database::{MapPosition, Position},
     gfx_collection::GfxType,
     renderer::SystemHolder,
 };
 
+// Side of a door the player arrives on when placing a two-way warp
+pub const WARP_EXIT_DIR: [(&str, i32, i32); 4] = [
+    ("Down", 0, -1),
+    ("Left", -1, 0),
+    ("Up", 0, 1),
+    ("Right", 1, 0),
+];
+
 pub struct InputTextbox {
     label: GfxType,
     bg: GfxType,
@@ -19,6 +27,13 @@ pub struct AttrPosition {
     pub input_box: Vec<InputTextbox>,
     pub content_scroll: Scrollbar,
     pub cur_textbox: Option<usize>,
+    pub button: Vec<Button>,
+    // While picking, the next tile clicked on the map becomes the warp target
+    pub picking: bool,
+    pub pick_source: Option<MapPosition>,
+    pub two_way: Checkbox,
+    // Exit side of the source door and of the target door
+    pub exit_dir: [usize; 2],
     content_y_size: f32,
     start_pos: Vec2,
     area_size: Vec2,
@@ -128,6 +143,99 @@ impl AttrPosition {
             })
         }
 
+        let buttonrect = ButtonRect {
+            rect_color: Color::rgb(80, 80, 80),
+            got_border: true,
+            border_color: Color::rgb(0, 0, 0),
+            border_radius: 0.0,
+            hover_change: ButtonChangeType::ColorChange(Color::rgb(50, 50, 50)),
+            click_change: ButtonChangeType::ColorChange(Color::rgb(80, 80, 80)),
+            alert_change: ButtonChangeType::None,
+            disable_change: ButtonChangeType::None,
+        };
+
+        let mut button = Vec::with_capacity(3);
+        for i in 0..3 {
+            button.push(Button::new(
+                systems,
+                ButtonType::Rect(buttonrect),
+                ButtonContentType::Text(ButtonContentText {
+                    text: match i {
+                        0 => "Pick Target".to_string(),
+                        1 => format!("Source Exit: {}", WARP_EXIT_DIR[0].0),
+                        _ => format!("Target Exit: {}", WARP_EXIT_DIR[0].0),
+                    },
+                    pos: Vec2::new(0.0, 0.0),
+                    color: Color::rgb(255, 255, 255),
+                    order_layer: 2,
+                    buffer_layer: RENDER_GUI_TEXT,
+                    hover_change: ButtonChangeType::None,
+                    click_change: ButtonChangeType::None,
+                    alert_change: ButtonChangeType::None,
+                    disable_change: ButtonChangeType::None,
+                }),
+                Vec2::new(start_pos.x, start_y_pos),
+                Vec2::new(
+                    10.0,
+                    if i == 0 {
+                        -2.0
+                    } else {
+                        -32.0 - (30.0 * i as f32)
+                    },
+                ),
+                ORDER_WINDOW_CONTENT,
+                Vec2::new(186.0, 24.0),
+                1,
+                RENDER_GUI,
+                visible,
+                None,
+                false,
+            ));
+        }
+
+        let two_way = Checkbox::new(
+            systems,
+            CheckboxType::Rect(CheckboxRect {
+                rect_color: Color::rgb(150, 150, 150),
+                got_border: true,
+                border_color: Color::rgb(0, 0, 0),
+                border_radius: 0.0,
+                hover_change: CheckboxChangeType::ColorChange(Color::rgb(180, 180, 180)),
+                click_change: CheckboxChangeType::ColorChange(Color::rgb(120, 120, 120)),
+                disable_change: CheckboxChangeType::None,
+            }),
+            CheckType::SetRect(CheckRect {
+                rect_color: Color::rgb(90, 90, 90),
+                got_border: false,
+                border_color: Color::rgb(0, 0, 0),
+                border_radius: 0.0,
+                pos: Vec2::new(3.0, 3.0),
+                size: Vec2::new(14.0, 14.0),
+            }),
+            Vec2::new(start_pos.x, start_y_pos),
+            Vec2::new(10.0, -30.0),
+            ORDER_WINDOW_CONTENT,
+            Vec2::new(20.0, 20.0),
+            RENDER_GUI,
+            1,
+            RENDER_GUI,
+            2,
+            Some(CheckboxText {
+                text: "Two-Way Warp".to_string(),
+                offset_pos: Vec2::new(3.0, 0.0),
+                buffer_layer: RENDER_GUI_TEXT,
+                order_layer: 2,
+                label_size: Vec2::new(120.0, 20.0),
+                color: Color::rgb(255, 255, 255),
+                hover_change: CheckboxChangeType::None,
+                click_change: CheckboxChangeType::None,
+                disable_change: CheckboxChangeType::None,
+            }),
+            visible,
+            None,
+        );
+
+        content_y_size += (120.0 * systems.scale as f32).floor();
         content_y_size += (30.0 * systems.scale as f32).floor();
 
         let bar_size = area_size.y - 10.0;
@@ -175,6 +283,11 @@ impl AttrPosition {
             content_scroll,
             input_box,
             cur_textbox: None,
+            button,
+            picking: false,
+            pick_source: None,
+            two_way,
+            exit_dir: [0; 2],
             content_y_size,
             start_pos,
             area_size,
@@ -192,7 +305,31 @@ impl AttrPosition {
             systems.gfx.set_visible(&input_box.label, visible);
             input_box.textbox.set_visible(systems, visible);
         }
+        for button in self.button.iter_mut() {
+            button.set_visible(systems, visible);
+        }
+        self.two_way.set_visible(systems, visible);
         self.content_scroll.set_visible(systems, visible);
+
+        if !visible {
+            self.set_picking(systems, false);
+        }
+    }
+
+    pub fn set_picking(&mut self, systems: &mut SystemHolder, picking: bool) {
+        self.picking = picking;
+        if !picking {
+            self.pick_source = None;
+        }
+        self.button[0].change_text(
+            systems,
+            if picking {
+                "Cancel Pick"
+            } else {
+                "Pick Target"
+            }
+            .to_string(),
+        );
     }
 
     pub fn screen_resize(&mut self, systems: &mut SystemHolder, start_pos: Vec2, area_size: Vec2) {
@@ -248,6 +385,13 @@ impl AttrPosition {
             start_y_pos -= (30.0 * systems.scale as f32).floor();
         }
 
+        for button in self.button.iter_mut() {
+            button.set_pos(systems, Vec2::new(start_pos.x, start_y_pos));
+        }
+        self.two_way
+            .set_pos(systems, Vec2::new(start_pos.x, start_y_pos));
+
+        content_y_size += (120.0 * systems.scale as f32).floor();
         content_y_size += (30.0 * systems.scale as f32).floor();
 
         self.content_y_size = content_y_size;
@@ -307,6 +451,29 @@ impl AttrPosition {
 
             start_y_pos -= (30.0 * systems.scale as f32).floor();
         }
+
+        for button in self.button.iter_mut() {
+            button.set_pos(systems, Vec2::new(self.start_pos.x, start_y_pos));
+        }
+        self.two_way
+            .set_pos(systems, Vec2::new(self.start_pos.x, start_y_pos));
+    }
+
+    pub fn get_exit_offset(&self, door: usize) -> (i32, i32) {
+        let (_, x, y) = WARP_EXIT_DIR[self.exit_dir[door]];
+        (x, y)
+    }
+
+    pub fn switch_exit_dir(&mut self, systems: &mut SystemHolder, door: usize) {
+        self.exit_dir[door] = (self.exit_dir[door] + 1) % WARP_EXIT_DIR.len();
+        self.button[door + 1].change_text(
+            systems,
+            format!(
+                "{} Exit: {}",
+                if door == 0 { "Source" } else { "Target" },
+                WARP_EXIT_DIR[self.exit_dir[door]].0
+            ),
+        );
     }
 
     pub fn get_value(&self) -> Position {
@@ -322,17 +489,17 @@ impl AttrPosition {
                 .parse::<i32>()
                 .unwrap_or_default(),
             map: MapPosition {
-                x: self.input_box[0]
+                x: self.input_box[2]
                     .textbox
                     .text
                     .parse::<i32>()
                     .unwrap_or_default(),
-                y: self.input_box[1]
+                y: self.input_box[3]
                     .textbox
                     .text
                     .parse::<i32>()
                     .unwrap_or_default(),
-                group: self.input_box[2]
+                group: self.input_box[4]
                     .textbox
                     .text
                     .parse::<i32>()
@@ -1,10 +1,18 @@
 use ascending_graphics::*;
 
-use crate::{content::widget::checkbox::*, data_types::*, renderer::SystemHolder};
+use crate::{
+    content::widget::{button::*, checkbox::*},
+    data_types::*,
+    renderer::SystemHolder,
+};
 
 pub struct DirBlockWindow {
     pub visible: bool,
     pub blocks: Vec<Checkbox>,
+    // Rectangle Paint
+    pub option: Vec<Checkbox>,
+    // Auto Edge
+    pub button: Vec<Button>,
 }
 
 impl DirBlockWindow {
@@ -69,9 +77,73 @@ impl DirBlockWindow {
             ));
         }
 
+        let option = vec![Checkbox::new(
+            systems,
+            CheckboxType::Rect(checkbox_rect),
+            CheckType::SetRect(check_rect),
+            blocks_pos,
+            Vec2::new(5.0, -32.0),
+            ORDER_WINDOW_CONTENT,
+            Vec2::new(20.0, 20.0),
+            RENDER_GUI,
+            1,
+            RENDER_GUI,
+            2,
+            Some(CheckboxText {
+                text: "Rectangle Paint".to_string(),
+                offset_pos: Vec2::new(3.0, 0.0),
+                buffer_layer: RENDER_GUI_TEXT,
+                order_layer: 2,
+                label_size: Vec2::new(140.0, 20.0),
+                color: Color::rgb(255, 255, 255),
+                hover_change: CheckboxChangeType::None,
+                click_change: CheckboxChangeType::None,
+                disable_change: CheckboxChangeType::None,
+            }),
+            false,
+            None,
+        )];
+
+        let button_size = (area_size.x / systems.scale as f32).floor() - 20.0;
+        let button = vec![Button::new(
+            systems,
+            ButtonType::Rect(ButtonRect {
+                rect_color: Color::rgb(80, 80, 80),
+                got_border: true,
+                border_color: Color::rgb(0, 0, 0),
+                border_radius: 0.0,
+                hover_change: ButtonChangeType::ColorChange(Color::rgb(50, 50, 50)),
+                click_change: ButtonChangeType::ColorChange(Color::rgb(80, 80, 80)),
+                alert_change: ButtonChangeType::None,
+                disable_change: ButtonChangeType::None,
+            }),
+            ButtonContentType::Text(ButtonContentText {
+                text: "Auto Edge".to_string(),
+                pos: Vec2::new(0.0, 2.0),
+                color: Color::rgb(255, 255, 255),
+                order_layer: 2,
+                buffer_layer: RENDER_GUI_TEXT,
+                hover_change: ButtonChangeType::None,
+                click_change: ButtonChangeType::None,
+                alert_change: ButtonChangeType::None,
+                disable_change: ButtonChangeType::None,
+            }),
+            blocks_pos,
+            Vec2::new(5.0, -66.0),
+            ORDER_WINDOW_CONTENT,
+            Vec2::new(button_size, 24.0),
+            1,
+            RENDER_GUI,
+            false,
+            Some("Sets the outward facing blocks along the border of the selection".to_string()),
+            false,
+        )];
+
         DirBlockWindow {
             visible: false,
             blocks,
+            option,
+            button,
         }
     }
 
@@ -84,6 +156,12 @@ impl DirBlockWindow {
         for zones in self.blocks.iter_mut() {
             zones.set_pos(systems, blocks_pos);
         }
+        for checkbox in self.option.iter_mut() {
+            checkbox.set_pos(systems, blocks_pos);
+        }
+        for button in self.button.iter_mut() {
+            button.set_pos(systems, blocks_pos);
+        }
     }
 
     pub fn set_visible(&mut self, systems: &mut SystemHolder, visible: bool) {
@@ -92,8 +170,15 @@ impl DirBlockWindow {
         }
 
         self.visible = visible;
-        for checkbox in self.blocks.iter_mut() {
+        for checkbox in self.blocks.iter_mut().chain(self.option.iter_mut()) {
             checkbox.set_visible(systems, visible);
         }
+        for button in self.button.iter_mut() {
+            button.set_visible(systems, visible);
+        }
+    }
+
+    pub fn is_rect_paint(&self) -> bool {
+        self.option[0].value
     }
 }
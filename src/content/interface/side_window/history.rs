@@ -0,0 +1,174 @@
+use ascending_graphics::*;
+
+use crate::{
+    content::widget::{create_label, scrollbar::*, text_list::*},
+    data_types::*,
+    gfx_collection::GfxType,
+    renderer::SystemHolder,
+};
+
+pub struct HistoryWindow {
+    pub visible: bool,
+    label: GfxType,
+    pub history_list: TextList,
+}
+
+impl HistoryWindow {
+    pub fn new(systems: &mut SystemHolder, start_pos: Vec2, area_size: Vec2) -> Self {
+        let label_pos = Vec3::new(
+            start_pos.x + (10.0 * systems.scale as f32).floor(),
+            start_pos.y + (area_size.y - (30.0 * systems.scale as f32).floor()),
+            ORDER_WINDOW_CONTENT,
+        );
+        let label_size = Vec2::new(
+            area_size.x - (20.0 * systems.scale as f32).floor(),
+            (20.0 * systems.scale as f32).floor(),
+        );
+        let text = create_label(
+            systems,
+            label_pos,
+            label_size,
+            Bounds::new(
+                label_pos.x,
+                label_pos.y,
+                label_pos.x + label_size.x,
+                label_pos.y + label_size.y,
+            ),
+            Color::rgb(255, 255, 255),
+            1,
+            16.0,
+            16.0,
+            true,
+        );
+        let label = systems.gfx.add_text(
+            text,
+            RENDER_GUI_TEXT,
+            "History Label",
+            false,
+            CameraView::SubView1,
+        );
+        systems.gfx.set_text(&label, "Map History");
+
+        let (list_pos, list_size, max_visible_list) =
+            get_history_list_area(systems, start_pos, area_size);
+
+        let history_list = TextList::new(
+            systems,
+            list_pos,
+            Vec2::new(0.0, 0.0),
+            ORDER_WINDOW_CONTENT,
+            list_size,
+            false,
+            TextListBG::Rect(TextListBGRect {
+                color: Color::rgb(85, 85, 85),
+                buffer_layer: RENDER_GUI,
+                order_layer: 2,
+                got_border: false,
+                border_color: Color::rgb(0, 0, 0),
+                radius: 0.0,
+            }),
+            ScrollbarRect {
+                color: Color::rgb(150, 150, 150),
+                buffer_layer: RENDER_GUI,
+                order_layer: 3,
+                got_border: false,
+                border_color: Color::rgb(0, 0, 0),
+                hover_color: Color::rgb(180, 180, 180),
+                hold_color: Color::rgb(120, 120, 120),
+                radius: 0.0,
+            },
+            Some(ScrollbarBackground {
+                color: Color::rgb(90, 90, 90),
+                buffer_layer: RENDER_GUI,
+                order_layer: 2,
+                got_border: false,
+                border_color: Color::rgb(0, 0, 0),
+                radius: 0.0,
+            }),
+            vec!["Initial State".to_string()],
+            TextListData {
+                selection_bufferlayer: RENDER_GUI,
+                text_bufferlayer: RENDER_GUI_TEXT,
+                selection_orderlayer: 4,
+                text_orderlayer: 5,
+                selection_color: SelectionColor {
+                    normal: Color::rgb(85, 85, 85),
+                    hover: Color::rgb(120, 120, 120),
+                    selected: Color::rgb(60, 60, 60),
+                },
+                text_color: SelectionColor {
+                    normal: Color::rgb(255, 255, 255),
+                    hover: Color::rgb(255, 255, 255),
+                    selected: Color::rgb(255, 255, 255),
+                },
+                max_list: max_visible_list,
+            },
+        );
+
+        HistoryWindow {
+            visible: false,
+            label,
+            history_list,
+        }
+    }
+
+    pub fn screen_resize(&mut self, systems: &mut SystemHolder, start_pos: Vec2, area_size: Vec2) {
+        let label_pos = Vec3::new(
+            start_pos.x + (10.0 * systems.scale as f32).floor(),
+            start_pos.y + (area_size.y - (30.0 * systems.scale as f32).floor()),
+            ORDER_WINDOW_CONTENT,
+        );
+        let label_size = Vec2::new(
+            area_size.x - (20.0 * systems.scale as f32).floor(),
+            (20.0 * systems.scale as f32).floor(),
+        );
+        systems.gfx.set_pos(&self.label, label_pos);
+        systems.gfx.set_bound(
+            &self.label,
+            Some(Bounds::new(
+                label_pos.x,
+                label_pos.y,
+                label_pos.x + label_size.x,
+                label_pos.y + label_size.y,
+            )),
+        );
+
+        let (list_pos, list_size, max_visible_list) =
+            get_history_list_area(systems, start_pos, area_size);
+
+        self.history_list.set_pos(systems, list_pos);
+        self.history_list
+            .set_size(systems, list_size, max_visible_list);
+    }
+
+    pub fn set_visible(&mut self, systems: &mut SystemHolder, visible: bool) {
+        if self.visible == visible {
+            return;
+        }
+
+        self.visible = visible;
+        systems.gfx.set_visible(&self.label, visible);
+        self.history_list.set_visible(systems, visible, false);
+    }
+}
+
+fn get_history_list_area(
+    systems: &SystemHolder,
+    start_pos: Vec2,
+    area_size: Vec2,
+) -> (Vec2, Vec2, usize) {
+    let mut list_size = Vec2::new(
+        (area_size.x / systems.scale as f32).floor() - 20.0,
+        area_size.y - (45.0 * systems.scale as f32).floor(),
+    );
+    let list_pos = Vec2::new(
+        start_pos.x + (10.0 * systems.scale as f32).floor(),
+        start_pos.y + (10.0 * systems.scale as f32).floor(),
+    );
+    let max_visible_list = ((list_size.y - (10.0 * systems.scale as f32).floor())
+        / (20.0 * systems.scale as f32).floor())
+    .floor() as usize;
+    list_size.y = (max_visible_list as f32 * 20.0) + 10.0;
+
+    (list_pos, list_size, max_visible_list)
+}
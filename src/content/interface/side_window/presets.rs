@@ -1,9 +1,10 @@
 use ascending_graphics::*;
+use indexmap::IndexSet;
 
 use crate::{
-    content::widget::*,
+    content::{get_autotile_unused_cell, widget::*},
     data_types::*,
-    database::{PresetFrames, PresetPos, PresetTypeList},
+    database::{DEFAULT_ANIM_TIME, PresetFrames, PresetPos, PresetTypeList},
     gfx_collection::GfxType,
     renderer::SystemHolder,
     resource::GuiTexture,
@@ -23,6 +24,16 @@ pub struct PresetTileSelection {
     pub in_hold: bool,
 }
 
+// Text of the buttons under the preset list, in rows of 3
+const PRESET_BUTTON: [&str; 6] = [
+    "New",
+    "Rename",
+    "Category",
+    "Delete",
+    "Move Up",
+    "Move Down",
+];
+
 pub struct PresetBase {
     seperator: GfxType,
     pub preview_name: GfxType,
@@ -34,6 +45,18 @@ pub struct PresetBase {
     pub preset_type: PresetTypeList,
     pub frames: [PresetFrames; 4],
     pub cur_frame: usize,
+    // Frame duration in milliseconds the preview plays at
+    pub anim_time: u32,
+
+    pub button: Vec<Button>,
+    pub category_list: OptionList,
+    // None lists every category, an empty one lists the presets without a category
+    pub category: Option<String>,
+    search_label: GfxType,
+    pub search: Textbox,
+    search_bg: GfxType,
+    // Preset index of each row of the preset list
+    pub list_index: Vec<usize>,
 }
 
 pub struct PresetEditor {
@@ -47,6 +70,10 @@ pub struct PresetEditor {
     pub frame_scroll: Scrollbar,
     pub frame_label: GfxType,
     pub frames: [PresetFrames; 4],
+    anim_time_label: GfxType,
+    // Frame duration in milliseconds of the animated types
+    pub anim_time: Textbox,
+    anim_time_bg: GfxType,
 
     content_y_size: f32,
     pub cur_tileset: usize,
@@ -64,6 +91,10 @@ pub struct PresetWindow {
     pub base: PresetBase,
     pub editor: PresetEditor,
     pub selected_index: usize,
+    // The editor was opened with New, saving adds a preset instead of replacing the selected one
+    pub edit_new: bool,
+    // Presets picked with ctrl click for the bundle export
+    pub export_select: IndexSet<usize>,
 }
 
 impl PresetWindow {
@@ -218,26 +249,11 @@ impl PresetWindow {
             CameraView::SubView1,
         );
 
-        let mut list_size = Vec2::new(
-            (area_size.x / systems.scale as f32).floor() - 20.0,
-            area_size.y - preset_preview_size.y - (20.0 * systems.scale as f32).floor(),
-        );
-        let max_visible_list = ((list_size.y - (10.0 * systems.scale as f32).floor())
-            / (20.0 * systems.scale as f32).floor())
-        .floor() as usize;
-        list_size.y = (max_visible_list as f32 * 20.0) + 10.0;
-
-        let mut preset_text = Vec::with_capacity(MAX_PRESETS);
-        for i in 0..MAX_PRESETS {
-            preset_text.push(format!("{}: -", i + 1));
-        }
+        let (list_size, max_visible_list) = get_preset_list_size(systems, area_size);
 
         let mut preset_list = TextList::new(
             systems,
-            Vec2::new(
-                start_pos.x + (10.0 * systems.scale as f32).floor(),
-                start_pos.y + (10.0 * systems.scale as f32).floor(),
-            ),
+            get_preset_list_pos(systems, start_pos),
             Vec2::new(0.0, 0.0),
             ORDER_WINDOW_CONTENT,
             list_size,
@@ -268,7 +284,7 @@ impl PresetWindow {
                 border_color: Color::rgb(0, 0, 0),
                 radius: 0.0,
             }),
-            preset_text,
+            vec![],
             TextListData {
                 selection_bufferlayer: RENDER_GUI,
                 text_bufferlayer: RENDER_GUI_TEXT,
@@ -289,6 +305,151 @@ impl PresetWindow {
         );
         preset_list.set_select(systems, Some(0), true);
 
+        let button_size = ((list_size.x - 6.0) / 3.0).floor();
+        let mut button = Vec::with_capacity(PRESET_BUTTON.len());
+        for (index, text) in PRESET_BUTTON.iter().enumerate() {
+            button.push(Button::new(
+                systems,
+                ButtonType::Rect(ButtonRect {
+                    rect_color: Color::rgb(80, 80, 80),
+                    got_border: true,
+                    border_color: Color::rgb(0, 0, 0),
+                    border_radius: 0.0,
+                    hover_change: ButtonChangeType::ColorChange(Color::rgb(50, 50, 50)),
+                    click_change: ButtonChangeType::ColorChange(Color::rgb(80, 80, 80)),
+                    alert_change: ButtonChangeType::None,
+                    disable_change: ButtonChangeType::None,
+                }),
+                ButtonContentType::Text(ButtonContentText {
+                    text: text.to_string(),
+                    pos: Vec2::new(0.0, 2.0),
+                    color: Color::rgb(255, 255, 255),
+                    order_layer: 2,
+                    buffer_layer: RENDER_GUI_TEXT,
+                    hover_change: ButtonChangeType::None,
+                    click_change: ButtonChangeType::None,
+                    alert_change: ButtonChangeType::None,
+                    disable_change: ButtonChangeType::None,
+                }),
+                get_preset_button_pos(systems, start_pos, index),
+                Vec2::new((button_size + 3.0) * (index % 3) as f32, 0.0),
+                ORDER_WINDOW_CONTENT,
+                Vec2::new(button_size, 24.0),
+                1,
+                RENDER_GUI,
+                false,
+                None,
+                false,
+            ));
+        }
+
+        let category_list = OptionList::new(
+            systems,
+            get_category_pos(systems, start_pos, bg_pos),
+            Vec2::new(0.0, 0.0),
+            Vec2::new(list_size.x, 24.0),
+            OptionListColor {
+                bar: [
+                    Color::rgb(85, 85, 85),
+                    Color::rgb(120, 120, 120),
+                    Color::rgb(60, 60, 60),
+                    Color::rgb(40, 40, 40),
+                ],
+                list_data_text: Color::rgb(255, 255, 255),
+                list_scroll: [
+                    Color::rgb(150, 150, 150),
+                    Color::rgb(180, 180, 180),
+                    Color::rgb(120, 120, 120),
+                    Color::rgb(90, 90, 90),
+                ],
+                list_selection: [
+                    Color::rgb(85, 85, 85),
+                    Color::rgb(120, 120, 120),
+                    Color::rgb(60, 60, 60),
+                ],
+                list_text: [
+                    Color::rgb(255, 255, 255),
+                    Color::rgb(255, 255, 255),
+                    Color::rgb(255, 255, 255),
+                ],
+            },
+            vec!["All Categories".to_string()],
+            6,
+            Some(0),
+            [ORDER_WINDOW_CONTENT, ORDER_ABOVE_WINDOW],
+            [
+                RENDER_GUI,
+                RENDER_GUI_TEXT,
+                RENDER_GUI2,
+                RENDER_GUI3,
+                RENDER_GUI_TEXT3,
+            ],
+            [2, 3, 4, 10, 11, 12],
+            false,
+        );
+
+        let text_pos = get_search_pos(systems, start_pos, bg_pos);
+        let text_size = (Vec2::new(70.0, 20.0) * systems.scale as f32).floor();
+        let text = create_label(
+            systems,
+            text_pos,
+            text_size,
+            Bounds::new(
+                text_pos.x,
+                text_pos.y,
+                text_pos.x + text_size.x,
+                text_pos.y + text_size.y,
+            ),
+            Color::rgb(255, 255, 255),
+            1,
+            16.0,
+            16.0,
+            true,
+        );
+        let search_label = systems.gfx.add_text(
+            text,
+            RENDER_GUI_TEXT,
+            "Preset Search",
+            false,
+            CameraView::SubView1,
+        );
+        systems.gfx.set_text(&search_label, "Search:");
+
+        let search_pos = text_pos + Vec3::new(text_size.x, 0.0, 0.0);
+        let search_size = Vec2::new(list_size.x - 72.0, 20.0);
+        let mut search = Textbox::new(
+            systems,
+            search_pos,
+            Vec2::new(0.0, 0.0),
+            search_size,
+            Color::rgb(255, 255, 255),
+            RENDER_GUI,
+            RENDER_GUI_TEXT,
+            [2, 3, 4],
+            255,
+            Color::rgb(110, 110, 110),
+            Color::rgb(150, 150, 150),
+            false,
+            false,
+            None,
+            vec![],
+            true,
+        );
+        search.set_select(systems, false);
+        search.set_hold(false);
+
+        let search_bg_pos = get_search_bg_pos(systems, search_pos);
+        let rect = Rect::new(
+            &mut systems.renderer,
+            search_bg_pos,
+            ((search_size + Vec2::new(2.0, 2.0)) * systems.scale as f32).floor(),
+            Color::rgb(70, 70, 70),
+            1,
+        );
+        let search_bg = systems
+            .gfx
+            .add_rect(rect, RENDER_GUI, "BG", false, CameraView::SubView1);
+
         let base = PresetBase {
             edit_button,
             seperator,
@@ -300,6 +461,14 @@ impl PresetWindow {
             preset_type: PresetTypeList::Normal,
             frames: [PresetFrames::default(); 4],
             cur_frame: 0,
+            anim_time: DEFAULT_ANIM_TIME,
+            button,
+            category_list,
+            category: None,
+            search_label,
+            search,
+            search_bg,
+            list_index: vec![],
         };
 
         let tileset_size = Vec2::new(
@@ -323,7 +492,7 @@ impl PresetWindow {
             .gfx
             .add_rect(rect, RENDER_GUI, "Tileset BG", false, CameraView::SubView1);
 
-        let content_y_size = tileset_size.y + (165.0 * systems.scale as f32).floor();
+        let content_y_size = tileset_size.y + (190.0 * systems.scale as f32).floor();
         let scroll_value = (content_y_size - area_size.y).max(0.0) as usize;
 
         let scrollbar_rect = ScrollbarRect {
@@ -439,7 +608,7 @@ impl PresetWindow {
             .resource
             .tilesheet
             .iter()
-            .map(|data| data.name.clone())
+            .map(|data| data.get_name())
             .collect();
         let list_size = tileset_name_list.len();
 
@@ -559,8 +728,10 @@ impl PresetWindow {
                 "Animated".to_string(),
                 "Auto Tile".to_string(),
                 "Auto Tile Animated".to_string(),
+                "Blob Tile".to_string(),
+                "Blob Tile Animated".to_string(),
             ],
-            4,
+            6,
             Some(0),
             [ORDER_WINDOW_CONTENT, ORDER_ABOVE_WINDOW],
             [
@@ -574,6 +745,71 @@ impl PresetWindow {
             false,
         );
 
+        let text_pos = get_anim_time_pos(systems, tileset_pos);
+        let text_size = (Vec2::new(80.0, 20.0) * systems.scale as f32).floor();
+        let text = create_label(
+            systems,
+            text_pos,
+            text_size,
+            Bounds::new(
+                text_pos.x,
+                text_pos.y,
+                text_pos.x + text_size.x,
+                text_pos.y + text_size.y,
+            ),
+            Color::rgb(255, 255, 255),
+            1,
+            16.0,
+            16.0,
+            true,
+        );
+        let anim_time_label = systems.gfx.add_text(
+            text,
+            RENDER_GUI_TEXT,
+            "Preset Frame Time",
+            false,
+            CameraView::SubView1,
+        );
+        systems.gfx.set_text(&anim_time_label, "Frame (ms):");
+
+        let anim_time_pos = text_pos + Vec3::new(text_size.x, 0.0, 0.0);
+        let anim_time_size =
+            Vec2::new((tileset_size.x / systems.scale as f32).floor() - 82.0, 20.0);
+        let mut anim_time = Textbox::new(
+            systems,
+            anim_time_pos,
+            Vec2::new(0.0, 0.0),
+            anim_time_size,
+            Color::rgb(255, 255, 255),
+            RENDER_GUI,
+            RENDER_GUI_TEXT,
+            [2, 3, 4],
+            5,
+            Color::rgb(110, 110, 110),
+            Color::rgb(150, 150, 150),
+            false,
+            false,
+            None,
+            vec![],
+            true,
+        );
+        anim_time.set_select(systems, false);
+        anim_time.set_hold(false);
+        anim_time.set_text(systems, DEFAULT_ANIM_TIME.to_string());
+
+        let anim_time_bg_pos = get_search_bg_pos(systems, anim_time_pos);
+        let rect = Rect::new(
+            &mut systems.renderer,
+            anim_time_bg_pos,
+            ((anim_time_size + Vec2::new(2.0, 2.0)) * systems.scale as f32).floor(),
+            Color::rgb(70, 70, 70),
+            1,
+        );
+        let anim_time_bg =
+            systems
+                .gfx
+                .add_rect(rect, RENDER_GUI, "BG", false, CameraView::SubView1);
+
         let button_rect = ButtonRect {
             rect_color: Color::rgb(80, 80, 80),
             got_border: true,
@@ -601,7 +837,7 @@ impl PresetWindow {
             }),
             Vec2::new(
                 tileset_pos.x,
-                tileset_pos.y - (85.0 * systems.scale as f32).floor(),
+                tileset_pos.y - (110.0 * systems.scale as f32).floor(),
             ),
             Vec2::new(0.0, 0.0),
             ORDER_WINDOW_CONTENT,
@@ -629,7 +865,7 @@ impl PresetWindow {
             }),
             Vec2::new(
                 tileset_pos.x,
-                tileset_pos.y - (110.0 * systems.scale as f32).floor(),
+                tileset_pos.y - (135.0 * systems.scale as f32).floor(),
             ),
             Vec2::new(0.0, 0.0),
             ORDER_WINDOW_CONTENT,
@@ -724,6 +960,9 @@ impl PresetWindow {
             frame_scroll,
             frame_label,
             frames: [PresetFrames::default(); 4],
+            anim_time_label,
+            anim_time,
+            anim_time_bg,
         };
 
         PresetWindow {
@@ -732,6 +971,8 @@ impl PresetWindow {
             base,
             editor,
             selected_index: 0,
+            edit_new: false,
+            export_select: IndexSet::default(),
         }
     }
 
@@ -819,19 +1060,12 @@ impl PresetWindow {
             let p_type = self.base.preset_type;
             let frame = self.base.frames[i];
 
-            if i > 0
-                && matches!(
-                    p_type,
-                    PresetTypeList::Animated | PresetTypeList::AutotileAnimated
-                )
-            {
+            if i > 0 && p_type.is_animated() {
                 continue;
             }
 
-            let size = if matches!(
-                p_type,
-                PresetTypeList::AutoTile | PresetTypeList::AutotileAnimated
-            ) {
+            // The preview got room for 5x3 tiles, larger autotiles show their upper left part
+            let size = if p_type.is_autotile() {
                 Vec2::new(5.0, 3.0)
             } else {
                 Vec2::new(
@@ -861,28 +1095,49 @@ impl PresetWindow {
             );
         }
 
-        let mut list_size = Vec2::new(
-            (area_size.x / systems.scale as f32).floor() - 20.0,
-            area_size.y - preset_preview_size.y - (20.0 * systems.scale as f32).floor(),
-        );
-        let max_visible_list = ((list_size.y - (10.0 * systems.scale as f32).floor())
-            / (20.0 * systems.scale as f32).floor())
-        .floor() as usize;
-        list_size.y = (max_visible_list as f32 * 20.0) + 10.0;
+        let (list_size, max_visible_list) = get_preset_list_size(systems, area_size);
 
-        self.base.preset_list.set_pos(
-            systems,
-            Vec2::new(
-                start_pos.x + (10.0 * systems.scale as f32).floor(),
-                start_pos.y + (10.0 * systems.scale as f32).floor(),
-            ),
-        );
+        self.base
+            .preset_list
+            .set_pos(systems, get_preset_list_pos(systems, start_pos));
         self.base
             .preset_list
             .set_size(systems, list_size, max_visible_list);
+        let selected_row = self
+            .base
+            .list_index
+            .iter()
+            .position(|index| *index == self.selected_index);
         self.base
             .preset_list
-            .set_select(systems, Some(self.selected_index), true);
+            .set_select(systems, selected_row, true);
+
+        for (index, button) in self.base.button.iter_mut().enumerate() {
+            button.set_pos(systems, get_preset_button_pos(systems, start_pos, index));
+        }
+        self.base.category_list.move_window(
+            systems,
+            get_category_pos(systems, start_pos, bg_pos),
+            ORDER_WINDOW_CONTENT,
+        );
+
+        let text_pos = get_search_pos(systems, start_pos, bg_pos);
+        let text_size = (Vec2::new(70.0, 20.0) * systems.scale as f32).floor();
+        systems.gfx.set_pos(&self.base.search_label, text_pos);
+        systems.gfx.set_bound(
+            &self.base.search_label,
+            Some(Bounds::new(
+                text_pos.x,
+                text_pos.y,
+                text_pos.x + text_size.x,
+                text_pos.y + text_size.y,
+            )),
+        );
+        let search_pos = text_pos + Vec3::new(text_size.x, 0.0, 0.0);
+        self.base.search.set_pos(systems, search_pos.truncate());
+        systems
+            .gfx
+            .set_pos(&self.base.search_bg, get_search_bg_pos(systems, search_pos));
 
         let tileset_size = Vec2::new(
             ((TILESET_COUNT_X * 20) as f32 * systems.scale as f32).floor(),
@@ -897,7 +1152,7 @@ impl PresetWindow {
         systems.gfx.set_pos(&self.editor.bg, tileset_pos);
         systems.gfx.set_size(&self.editor.bg, tileset_size);
 
-        self.editor.content_y_size = tileset_size.y + (165.0 * systems.scale as f32).floor();
+        self.editor.content_y_size = tileset_size.y + (190.0 * systems.scale as f32).floor();
         let scroll_value = (self.editor.content_y_size - area_size.y).max(0.0) as usize;
 
         let bar_size = (area_size.y / systems.scale as f32).floor() - 20.0;
@@ -946,6 +1201,8 @@ impl PresetWindow {
             )),
         );
 
+        self.set_anim_time_pos(systems, tileset_pos);
+
         systems.gfx.set_pos(&self.editor.tileset, tileset_pos);
         self.editor.tile_list.move_window(
             systems,
@@ -964,14 +1221,14 @@ impl PresetWindow {
             systems,
             Vec2::new(
                 tileset_pos.x,
-                tileset_pos.y - (85.0 * systems.scale as f32).floor(),
+                tileset_pos.y - (110.0 * systems.scale as f32).floor(),
             ),
         );
         self.editor.cancel_button.set_pos(
             systems,
             Vec2::new(
                 tileset_pos.x,
-                tileset_pos.y - (110.0 * systems.scale as f32).floor(),
+                tileset_pos.y - (135.0 * systems.scale as f32).floor(),
             ),
         );
 
@@ -986,11 +1243,8 @@ impl PresetWindow {
         self.editor.start_pos = start_pos;
         self.editor.area_size = area_size;
 
-        let end_pos = if matches!(
-            self.editor.cur_type,
-            PresetTypeList::AutotileAnimated | PresetTypeList::AutoTile
-        ) {
-            self.editor.selection.start_pos + Vec2::new(4.0, 2.0)
+        let end_pos = if let Some((x, y)) = self.editor.cur_type.autotile_size() {
+            self.editor.selection.start_pos + Vec2::new(x as f32 - 1.0, y as f32 - 1.0)
         } else {
             self.editor.selection.start_pos
         };
@@ -1009,6 +1263,7 @@ impl PresetWindow {
         systems.gfx.set_visible(&self.base.preview_info, visible);
         systems.gfx.set_visible(&self.base.preview_bg, visible);
         self.base.preset_list.set_visible(systems, visible, false);
+        self.set_base_widget_visible(systems, visible);
         self.window_type = PresetWindowType::Base;
 
         systems.gfx.set_visible(&self.editor.bg, false);
@@ -1026,6 +1281,7 @@ impl PresetWindow {
             .gfx
             .set_visible(&self.editor.selection.blocker, false);
         self.editor.type_list.set_visible(systems, false);
+        self.set_anim_time_visible(systems, false);
         for gfx in self.base.preview.iter() {
             systems.gfx.set_visible(gfx, false);
         }
@@ -1048,6 +1304,7 @@ impl PresetWindow {
                 self.base
                     .preset_list
                     .set_visible(systems, self.visible, false);
+                self.set_base_widget_visible(systems, self.visible);
                 for gfx in self.base.preview.iter() {
                     systems.gfx.set_visible(gfx, self.visible);
                 }
@@ -1067,6 +1324,7 @@ impl PresetWindow {
                 systems
                     .gfx
                     .set_visible(&self.editor.selection.blocker, false);
+                self.set_anim_time_visible(systems, false);
             }
             PresetWindowType::Editor => {
                 systems.gfx.set_visible(&self.editor.bg, self.visible);
@@ -1086,6 +1344,7 @@ impl PresetWindow {
                 systems
                     .gfx
                     .set_visible(&self.editor.frame_label, self.visible);
+                self.set_anim_time_visible(systems, self.visible);
 
                 for gfx in self.base.preview.iter() {
                     systems.gfx.set_visible(gfx, false);
@@ -1096,10 +1355,29 @@ impl PresetWindow {
                 systems.gfx.set_visible(&self.base.preview_info, false);
                 systems.gfx.set_visible(&self.base.preview_bg, false);
                 self.base.preset_list.set_visible(systems, false, false);
+                self.set_base_widget_visible(systems, false);
             }
         }
     }
 
+    // The search box on the preset list, the frame time in the editor
+    pub fn get_cur_textbox(&mut self) -> &mut Textbox {
+        match self.window_type {
+            PresetWindowType::Base => &mut self.base.search,
+            PresetWindowType::Editor => &mut self.editor.anim_time,
+        }
+    }
+
+    fn set_base_widget_visible(&mut self, systems: &mut SystemHolder, visible: bool) {
+        for button in self.base.button.iter_mut() {
+            button.set_visible(systems, visible);
+        }
+        self.base.category_list.set_visible(systems, visible);
+        systems.gfx.set_visible(&self.base.search_label, visible);
+        self.base.search.set_visible(systems, visible);
+        systems.gfx.set_visible(&self.base.search_bg, visible);
+    }
+
     pub fn update_editor_content(&mut self, systems: &mut SystemHolder) {
         let tileset_size = Vec2::new(
             ((TILESET_COUNT_X * 20) as f32 * systems.scale as f32).floor(),
@@ -1140,14 +1418,14 @@ impl PresetWindow {
             systems,
             Vec2::new(
                 tileset_pos.x,
-                tileset_pos.y - (85.0 * systems.scale as f32).floor(),
+                tileset_pos.y - (110.0 * systems.scale as f32).floor(),
             ),
         );
         self.editor.cancel_button.set_pos(
             systems,
             Vec2::new(
                 tileset_pos.x,
-                tileset_pos.y - (110.0 * systems.scale as f32).floor(),
+                tileset_pos.y - (135.0 * systems.scale as f32).floor(),
             ),
         );
 
@@ -1170,17 +1448,47 @@ impl PresetWindow {
             )),
         );
 
-        let end_pos = if matches!(
-            self.editor.cur_type,
-            PresetTypeList::AutotileAnimated | PresetTypeList::AutoTile
-        ) {
-            self.editor.selection.start_pos + Vec2::new(4.0, 2.0)
+        self.set_anim_time_pos(systems, tileset_pos);
+
+        let end_pos = if let Some((x, y)) = self.editor.cur_type.autotile_size() {
+            self.editor.selection.start_pos + Vec2::new(x as f32 - 1.0, y as f32 - 1.0)
         } else {
             self.editor.selection.start_pos
         };
         self.select_tile(systems, self.editor.selection.start_pos, end_pos);
     }
 
+    fn set_anim_time_pos(&mut self, systems: &mut SystemHolder, tileset_pos: Vec3) {
+        let text_pos = get_anim_time_pos(systems, tileset_pos);
+        let text_size = (Vec2::new(80.0, 20.0) * systems.scale as f32).floor();
+        systems.gfx.set_pos(&self.editor.anim_time_label, text_pos);
+        systems.gfx.set_bound(
+            &self.editor.anim_time_label,
+            Some(Bounds::new(
+                text_pos.x,
+                text_pos.y,
+                text_pos.x + text_size.x,
+                text_pos.y + text_size.y,
+            )),
+        );
+        let anim_time_pos = text_pos + Vec3::new(text_size.x, 0.0, 0.0);
+        self.editor
+            .anim_time
+            .set_pos(systems, anim_time_pos.truncate());
+        systems.gfx.set_pos(
+            &self.editor.anim_time_bg,
+            get_search_bg_pos(systems, anim_time_pos),
+        );
+    }
+
+    fn set_anim_time_visible(&mut self, systems: &mut SystemHolder, visible: bool) {
+        systems
+            .gfx
+            .set_visible(&self.editor.anim_time_label, visible);
+        self.editor.anim_time.set_visible(systems, visible);
+        systems.gfx.set_visible(&self.editor.anim_time_bg, visible);
+    }
+
     pub fn change_tileset(&mut self, systems: &mut SystemHolder, tileset: usize) {
         if self.editor.cur_tileset == tileset {
             return;
@@ -1268,21 +1576,26 @@ impl PresetWindow {
 
         let is_visible = systems.gfx.get_visible(&self.editor.selection.gfx[0]);
 
-        if matches!(
-            self.editor.cur_type,
-            PresetTypeList::AutoTile | PresetTypeList::AutotileAnimated
-        ) {
+        if let Some((start, count)) = get_autotile_unused_cell(self.editor.cur_type) {
             systems
                 .gfx
                 .set_visible(&self.editor.selection.blocker, is_visible);
             systems.gfx.set_pos(
                 &self.editor.selection.blocker,
                 Vec3::new(
-                    base_pos.x + ((3.0 * tile_size) * systems.scale as f32).floor(),
+                    base_pos.x + (start as f32 * tile_size),
                     base_pos.y,
                     tileset_pos.z,
                 ),
             );
+            systems.gfx.set_size(
+                &self.editor.selection.blocker,
+                Vec2::new(count as f32 * tile_size, tile_size),
+            );
+            systems.gfx.set_uv(
+                &self.editor.selection.blocker,
+                Vec4::new(0.0, 0.0, count as f32 * 20.0, 20.0),
+            );
         } else {
             systems
                 .gfx
@@ -1314,10 +1627,7 @@ impl PresetWindow {
             return;
         }
 
-        if !matches!(
-            self.base.preset_type,
-            PresetTypeList::Animated | PresetTypeList::AutotileAnimated
-        ) {
+        if !self.base.preset_type.is_animated() {
             return;
         }
 
@@ -1335,3 +1645,62 @@ impl PresetWindow {
             .set_visible(&self.base.preview[self.base.cur_frame], true);
     }
 }
+
+// The preset list fills the space between the buttons under it and the search above it
+fn get_preset_list_size(systems: &SystemHolder, area_size: Vec2) -> (Vec2, usize) {
+    let mut list_size = Vec2::new(
+        (area_size.x / systems.scale as f32).floor() - 20.0,
+        area_size.y - ((200.0 + 20.0 + 112.0) * systems.scale as f32).floor(),
+    );
+    let max_visible_list = ((list_size.y - (10.0 * systems.scale as f32).floor())
+        / (20.0 * systems.scale as f32).floor())
+    .floor() as usize;
+    list_size.y = (max_visible_list as f32 * 20.0) + 10.0;
+    (list_size, max_visible_list)
+}
+
+fn get_preset_list_pos(systems: &SystemHolder, start_pos: Vec2) -> Vec2 {
+    Vec2::new(
+        start_pos.x + (10.0 * systems.scale as f32).floor(),
+        start_pos.y + (66.0 * systems.scale as f32).floor(),
+    )
+}
+
+fn get_preset_button_pos(systems: &SystemHolder, start_pos: Vec2, index: usize) -> Vec2 {
+    Vec2::new(
+        start_pos.x + (10.0 * systems.scale as f32).floor(),
+        start_pos.y + (if index < 3 { 36.0 } else { 8.0 } * systems.scale as f32).floor(),
+    )
+}
+
+fn get_category_pos(systems: &SystemHolder, start_pos: Vec2, bg_pos: Vec3) -> Vec2 {
+    Vec2::new(
+        start_pos.x + (10.0 * systems.scale as f32).floor(),
+        bg_pos.y - (33.0 * systems.scale as f32).floor(),
+    )
+}
+
+fn get_search_pos(systems: &SystemHolder, start_pos: Vec2, bg_pos: Vec3) -> Vec3 {
+    Vec3::new(
+        start_pos.x + (10.0 * systems.scale as f32).floor(),
+        bg_pos.y - (59.0 * systems.scale as f32).floor(),
+        ORDER_WINDOW_CONTENT,
+    )
+}
+
+// The frame time row sits between the type list and the save button
+fn get_anim_time_pos(systems: &SystemHolder, tileset_pos: Vec3) -> Vec3 {
+    Vec3::new(
+        tileset_pos.x,
+        tileset_pos.y - (83.0 * systems.scale as f32).floor(),
+        ORDER_WINDOW_CONTENT,
+    )
+}
+
+fn get_search_bg_pos(systems: &SystemHolder, search_pos: Vec3) -> Vec3 {
+    Vec3::new(
+        search_pos.x - (1.0 * systems.scale as f32).floor(),
+        search_pos.y - (1.0 * systems.scale as f32).floor(),
+        ORDER_WINDOW_CONTENT,
+    )
+}
@@ -1,13 +1,33 @@
 use ascending_graphics::*;
 
 use crate::{
-    content::widget::{option_list::*, scrollbar::*},
+    content::widget::{
+        Textbox, button::*, checkbox::*, create_label, option_list::*, scrollbar::*,
+    },
     data_types::*,
+    database::TilesheetMeta,
     gfx_collection::GfxType,
     renderer::SystemHolder,
     resource::GuiTexture,
 };
 
+// Text and row of each label, the tag filter is only shown outside of the properties
+const TILESET_LABEL: [(&str, usize); 4] = [
+    ("Filter Tag:", 0),
+    ("Name:", 0),
+    ("Tags:", 1),
+    ("Frames:", 2),
+];
+
+const COLLISION_LABEL: [&str; 6] = [
+    "Blocked",
+    "NPC Blocked",
+    "Block Up",
+    "Block Down",
+    "Block Left",
+    "Block Right",
+];
+
 pub struct TileSelection {
     gfx: [GfxType; 4],
     pub start_pos: Vec2,
@@ -24,6 +44,21 @@ pub struct TilesetWindow {
     pub scrollbar: Scrollbar,
     pub selection: TileSelection,
 
+    // Editing the metadata of the current sheet instead of picking tiles to paint
+    pub in_properties: bool,
+    label: Vec<GfxType>,
+    // Tag filter, then the sheet name, tags and anim frames of the properties
+    pub textbox: Vec<Textbox>,
+    textbox_bg: Vec<GfxType>,
+    pub cur_textbox: Option<usize>,
+    // Blocked, NPC Blocked, Block Up, Block Down, Block Left, Block Right
+    pub collision: Vec<Checkbox>,
+    // Properties, Save, Close
+    pub button: Vec<Button>,
+    // Covers the tiles that don't have the filtered tag
+    filter: Vec<GfxType>,
+    pub edit_meta: TilesheetMeta,
+
     content_y_size: f32,
     pub cur_tileset: usize,
     pub start_pos: Vec2,
@@ -53,7 +88,7 @@ impl TilesetWindow {
             .gfx
             .add_rect(rect, RENDER_GUI, "Tileset BG", true, CameraView::SubView1);
 
-        let content_y_size = tileset_size.y + (54.0 * systems.scale as f32).floor();
+        let content_y_size = get_content_y_size(systems, false);
         let scroll_value = (content_y_size - area_size.y).max(0.0) as usize;
 
         let bar_size = (area_size.y / systems.scale as f32).floor() - 20.0;
@@ -110,7 +145,7 @@ impl TilesetWindow {
             .resource
             .tilesheet
             .iter()
-            .map(|data| data.name.clone())
+            .map(|data| data.get_name())
             .collect();
         let list_size = tileset_name_list.len();
 
@@ -221,6 +256,188 @@ impl TilesetWindow {
             in_hold: false,
         };
 
+        let mut label = Vec::with_capacity(TILESET_LABEL.len());
+        let mut textbox = Vec::with_capacity(TILESET_LABEL.len());
+        let mut textbox_bg = Vec::with_capacity(TILESET_LABEL.len());
+        for (index, (text, row)) in TILESET_LABEL.iter().enumerate() {
+            let visible = index == 0;
+            let text_pos = get_row_pos(systems, tileset_pos, *row);
+            let text_size = (Vec2::new(85.0, 20.0) * systems.scale as f32).floor();
+            let label_text = create_label(
+                systems,
+                text_pos,
+                text_size,
+                Bounds::new(
+                    text_pos.x,
+                    text_pos.y,
+                    text_pos.x + text_size.x,
+                    text_pos.y + text_size.y,
+                ),
+                Color::rgb(255, 255, 255),
+                1,
+                16.0,
+                16.0,
+                true,
+            );
+            let text_gfx = systems.gfx.add_text(
+                label_text,
+                RENDER_GUI_TEXT,
+                "Tileset Labels",
+                visible,
+                CameraView::SubView1,
+            );
+            systems.gfx.set_text(&text_gfx, text);
+            label.push(text_gfx);
+
+            let textbox_pos = get_textbox_pos(systems, text_pos);
+            let textbox_size = Vec2::new(110.0, 20.0);
+            let mut textbox_data = Textbox::new(
+                systems,
+                textbox_pos,
+                Vec2::new(0.0, 0.0),
+                textbox_size,
+                Color::rgb(255, 255, 255),
+                RENDER_GUI,
+                RENDER_GUI_TEXT,
+                [2, 3, 4],
+                if index == 3 { 1 } else { 255 },
+                Color::rgb(110, 110, 110),
+                Color::rgb(150, 150, 150),
+                false,
+                visible,
+                None,
+                vec![],
+                true,
+            );
+            textbox_data.set_select(systems, false);
+            textbox_data.set_hold(false);
+            textbox.push(textbox_data);
+
+            let rect = Rect::new(
+                &mut systems.renderer,
+                Vec3::new(
+                    textbox_pos.x - (1.0 * systems.scale as f32).floor(),
+                    textbox_pos.y - (1.0 * systems.scale as f32).floor(),
+                    ORDER_WINDOW_CONTENT,
+                ),
+                ((textbox_size + Vec2::new(2.0, 2.0)) * systems.scale as f32).floor(),
+                Color::rgb(70, 70, 70),
+                1,
+            );
+            textbox_bg.push(systems.gfx.add_rect(
+                rect,
+                RENDER_GUI,
+                "BG",
+                visible,
+                CameraView::SubView1,
+            ));
+        }
+
+        let mut collision = Vec::with_capacity(COLLISION_LABEL.len());
+        for (index, text) in COLLISION_LABEL.iter().enumerate() {
+            collision.push(Checkbox::new(
+                systems,
+                CheckboxType::Rect(CheckboxRect {
+                    rect_color: Color::rgb(150, 150, 150),
+                    got_border: true,
+                    border_color: Color::rgb(0, 0, 0),
+                    border_radius: 0.0,
+                    hover_change: CheckboxChangeType::ColorChange(Color::rgb(180, 180, 180)),
+                    click_change: CheckboxChangeType::ColorChange(Color::rgb(120, 120, 120)),
+                    disable_change: CheckboxChangeType::None,
+                }),
+                CheckType::SetRect(CheckRect {
+                    rect_color: Color::rgb(90, 90, 90),
+                    got_border: false,
+                    border_color: Color::rgb(0, 0, 0),
+                    border_radius: 0.0,
+                    pos: Vec2::new(3.0, 3.0),
+                    size: Vec2::new(14.0, 14.0),
+                }),
+                get_row_pos(systems, tileset_pos, 3 + index / 2).truncate(),
+                Vec2::new(100.0 * (index % 2) as f32, 0.0),
+                ORDER_WINDOW_CONTENT,
+                Vec2::new(20.0, 20.0),
+                RENDER_GUI,
+                1,
+                RENDER_GUI,
+                2,
+                Some(CheckboxText {
+                    text: text.to_string(),
+                    offset_pos: Vec2::new(3.0, 0.0),
+                    buffer_layer: RENDER_GUI_TEXT,
+                    order_layer: 2,
+                    label_size: Vec2::new(75.0, 20.0),
+                    color: Color::rgb(255, 255, 255),
+                    hover_change: CheckboxChangeType::None,
+                    click_change: CheckboxChangeType::None,
+                    disable_change: CheckboxChangeType::None,
+                }),
+                false,
+                None,
+            ));
+        }
+
+        let button_rect = ButtonRect {
+            rect_color: Color::rgb(80, 80, 80),
+            got_border: true,
+            border_color: Color::rgb(0, 0, 0),
+            border_radius: 0.0,
+            hover_change: ButtonChangeType::ColorChange(Color::rgb(50, 50, 50)),
+            click_change: ButtonChangeType::ColorChange(Color::rgb(80, 80, 80)),
+            alert_change: ButtonChangeType::None,
+            disable_change: ButtonChangeType::None,
+        };
+        let full_size = (tileset_size.x / systems.scale as f32).floor();
+        let half_size = ((full_size - 6.0) * 0.5).floor();
+        let mut button = Vec::with_capacity(3);
+        for (index, text) in ["Properties", "Save", "Close"].iter().enumerate() {
+            button.push(Button::new(
+                systems,
+                ButtonType::Rect(button_rect),
+                ButtonContentType::Text(ButtonContentText {
+                    text: text.to_string(),
+                    pos: Vec2::new(0.0, 2.0),
+                    color: Color::rgb(255, 255, 255),
+                    order_layer: 2,
+                    buffer_layer: RENDER_GUI_TEXT,
+                    hover_change: ButtonChangeType::None,
+                    click_change: ButtonChangeType::None,
+                    alert_change: ButtonChangeType::None,
+                    disable_change: ButtonChangeType::None,
+                }),
+                get_button_pos(systems, tileset_pos, index),
+                Vec2::new(if index == 2 { half_size + 6.0 } else { 0.0 }, 0.0),
+                ORDER_WINDOW_CONTENT,
+                Vec2::new(if index == 0 { full_size } else { half_size }, 24.0),
+                1,
+                RENDER_GUI,
+                index == 0,
+                None,
+                false,
+            ));
+        }
+
+        let tile_size = (20.0 * systems.scale as f32).floor();
+        let tile_count = (TILESET_COUNT_X * TILESET_COUNT_Y) as usize;
+        let mut filter = Vec::with_capacity(tile_count);
+        for index in 0..tile_count {
+            let rect = Rect::new(
+                &mut systems.renderer,
+                get_filter_pos(tileset_pos, tile_size, index),
+                Vec2::new(tile_size, tile_size),
+                Color::rgba(0, 0, 0, 170),
+                2,
+            );
+            filter.push(systems.gfx.add_rect(
+                rect,
+                RENDER_GUI2,
+                "Tileset Filter",
+                false,
+                CameraView::SubView1,
+            ));
+        }
+
         TilesetWindow {
             visible: true,
             bg,
@@ -233,6 +450,15 @@ impl TilesetWindow {
             area_size,
             content_y_size,
             selection,
+            in_properties: false,
+            label,
+            textbox,
+            textbox_bg,
+            cur_textbox: None,
+            collision,
+            button,
+            filter,
+            edit_meta: TilesheetMeta::default(),
         }
     }
 
@@ -250,7 +476,7 @@ impl TilesetWindow {
         systems.gfx.set_pos(&self.bg, tileset_pos);
         systems.gfx.set_size(&self.bg, tileset_size);
 
-        self.content_y_size = tileset_size.y + (54.0 * systems.scale as f32).floor();
+        self.content_y_size = get_content_y_size(systems, self.in_properties);
         let scroll_value = (self.content_y_size - area_size.y).max(0.0) as usize;
 
         let bar_size = (area_size.y / systems.scale as f32).floor() - 20.0;
@@ -281,6 +507,7 @@ impl TilesetWindow {
         self.start_pos = start_pos;
         self.area_size = area_size;
 
+        self.update_widget_pos(systems, tileset_pos);
         self.select_tile(systems, self.selection.start_pos, self.selection.end_pos);
     }
 
@@ -297,6 +524,178 @@ impl TilesetWindow {
         for gfx in self.selection.gfx.iter() {
             systems.gfx.set_visible(gfx, visible);
         }
+        self.update_widget_visible(systems);
+    }
+
+    // Switching keeps the tile selection, so the properties open on the tiles that were picked
+    pub fn set_properties(&mut self, systems: &mut SystemHolder, in_properties: bool) {
+        if self.in_properties == in_properties {
+            return;
+        }
+        self.in_properties = in_properties;
+
+        if let Some(index) = self.cur_textbox.take() {
+            self.textbox[index].set_select(systems, false);
+        }
+
+        self.content_y_size = get_content_y_size(systems, in_properties);
+        let scroll_value = (self.content_y_size - self.area_size.y).max(0.0) as usize;
+        self.scrollbar.set_value(systems, 0);
+        self.scrollbar.set_max_value(systems, scroll_value);
+        self.update_content(systems);
+        self.update_widget_visible(systems);
+    }
+
+    fn update_widget_visible(&mut self, systems: &mut SystemHolder) {
+        let (base, properties) = (
+            self.visible && !self.in_properties,
+            self.visible && self.in_properties,
+        );
+
+        for (index, gfx) in self.label.iter().enumerate() {
+            systems
+                .gfx
+                .set_visible(gfx, if index == 0 { base } else { properties });
+        }
+        for (index, textbox) in self.textbox.iter_mut().enumerate() {
+            textbox.set_visible(systems, if index == 0 { base } else { properties });
+        }
+        for (index, gfx) in self.textbox_bg.iter().enumerate() {
+            systems
+                .gfx
+                .set_visible(gfx, if index == 0 { base } else { properties });
+        }
+        for checkbox in self.collision.iter_mut() {
+            checkbox.set_visible(systems, properties);
+        }
+        for (index, button) in self.button.iter_mut().enumerate() {
+            button.set_visible(systems, if index == 0 { base } else { properties });
+        }
+        self.update_filter(systems);
+    }
+
+    fn update_widget_pos(&mut self, systems: &mut SystemHolder, tileset_pos: Vec3) {
+        for (index, (_, row)) in TILESET_LABEL.iter().enumerate() {
+            let text_pos = get_row_pos(systems, tileset_pos, *row);
+            let text_size = (Vec2::new(85.0, 20.0) * systems.scale as f32).floor();
+            systems.gfx.set_pos(&self.label[index], text_pos);
+            systems.gfx.set_bound(
+                &self.label[index],
+                Some(Bounds::new(
+                    text_pos.x,
+                    text_pos.y,
+                    text_pos.x + text_size.x,
+                    text_pos.y + text_size.y,
+                )),
+            );
+
+            let textbox_pos = get_textbox_pos(systems, text_pos);
+            self.textbox[index].set_pos(systems, textbox_pos.truncate());
+            systems.gfx.set_pos(
+                &self.textbox_bg[index],
+                Vec3::new(
+                    textbox_pos.x - (1.0 * systems.scale as f32).floor(),
+                    textbox_pos.y - (1.0 * systems.scale as f32).floor(),
+                    ORDER_WINDOW_CONTENT,
+                ),
+            );
+        }
+
+        for (index, checkbox) in self.collision.iter_mut().enumerate() {
+            checkbox.set_pos(
+                systems,
+                get_row_pos(systems, tileset_pos, 3 + index / 2).truncate(),
+            );
+        }
+        for (index, button) in self.button.iter_mut().enumerate() {
+            button.set_pos(systems, get_button_pos(systems, tileset_pos, index));
+        }
+
+        let tile_size = (20.0 * systems.scale as f32).floor();
+        for (index, gfx) in self.filter.iter().enumerate() {
+            systems
+                .gfx
+                .set_pos(gfx, get_filter_pos(tileset_pos, tile_size, index));
+        }
+    }
+
+    // Darkens the tiles of the current sheet that are missing the tag typed into the filter
+    pub fn update_filter(&mut self, systems: &mut SystemHolder) {
+        let tag = self.textbox[0].text.trim().to_string();
+        let show = self.visible && !self.in_properties && !tag.is_empty();
+
+        let hidden: Vec<bool> = {
+            let meta = systems
+                .resource
+                .tilesheet
+                .get(self.cur_tileset)
+                .map(|data| &data.meta);
+
+            (0..self.filter.len())
+                .map(|index| {
+                    let (x, y) = (
+                        index as u32 % TILESET_COUNT_X,
+                        index as u32 / TILESET_COUNT_X,
+                    );
+                    show && !meta
+                        .and_then(|meta| meta.get_tile(x, y))
+                        .is_some_and(|tile| tile.has_tag(&tag))
+                })
+                .collect()
+        };
+
+        for (gfx, hide) in self.filter.iter().zip(hidden) {
+            systems.gfx.set_visible(gfx, hide);
+        }
+    }
+
+    // Tile sheet position of the selected tiles, counted from the top left like the metadata
+    pub fn get_selected_tiles(&self) -> Vec<(u32, u32)> {
+        let (start_pos, end_pos) = (self.selection.start_pos, self.selection.end_pos);
+        let (min, max) = (start_pos.min(end_pos), start_pos.max(end_pos));
+
+        let mut tiles = Vec::new();
+        for y in (min.y as u32..=max.y as u32).rev() {
+            for x in min.x as u32..=max.x as u32 {
+                tiles.push((x, TILESET_COUNT_Y.saturating_sub(1) - y));
+            }
+        }
+        tiles
+    }
+
+    // Fills the properties with the sheet name and the first selected tile
+    pub fn update_properties(&mut self, systems: &mut SystemHolder) {
+        let tile = self
+            .get_selected_tiles()
+            .first()
+            .and_then(|(x, y)| self.edit_meta.get_tile(*x, *y))
+            .cloned()
+            .unwrap_or_default();
+
+        let name = self.edit_meta.name.clone();
+        self.textbox[1].set_text(systems, name);
+        self.textbox[2].set_text(systems, tile.tags.join(", "));
+        self.textbox[3].set_text(
+            systems,
+            if tile.anim_frames > 1 {
+                format!("{}", tile.anim_frames)
+            } else {
+                String::new()
+            },
+        );
+
+        let collision = tile.collision;
+        let value = [
+            collision.blocked,
+            collision.npc_blocked,
+            collision.dir_block & 0b0010 != 0,
+            collision.dir_block & 0b0001 != 0,
+            collision.dir_block & 0b0100 != 0,
+            collision.dir_block & 0b1000 != 0,
+        ];
+        for (checkbox, value) in self.collision.iter_mut().zip(value) {
+            checkbox.set_value(systems, value);
+        }
     }
 
     pub fn change_tileset(&mut self, systems: &mut SystemHolder, tileset: usize) {
@@ -334,6 +733,7 @@ impl TilesetWindow {
             self.visible,
             CameraView::SubView1,
         );
+        self.update_filter(systems);
     }
 
     pub fn update_content(&mut self, systems: &mut SystemHolder) {
@@ -357,6 +757,7 @@ impl TilesetWindow {
             ORDER_WINDOW_CONTENT,
         );
 
+        self.update_widget_pos(systems, tileset_pos);
         self.select_tile(systems, self.selection.start_pos, self.selection.end_pos);
     }
 
@@ -424,3 +825,46 @@ impl TilesetWindow {
         }
     }
 }
+
+// The properties need room for their rows under the tile sheet
+fn get_content_y_size(systems: &SystemHolder, in_properties: bool) -> f32 {
+    let last_row = if in_properties { 6.0 } else { 1.0 };
+    ((TILESET_COUNT_Y * 20) as f32 * systems.scale as f32).floor()
+        + ((92.0 + (26.0 * last_row)) * systems.scale as f32).floor()
+}
+
+// Rows are counted down from the bottom of the tile sheet
+fn get_row_pos(systems: &SystemHolder, tileset_pos: Vec3, row: usize) -> Vec3 {
+    Vec3::new(
+        tileset_pos.x,
+        tileset_pos.y - ((30.0 + (26.0 * row as f32)) * systems.scale as f32).floor(),
+        ORDER_WINDOW_CONTENT,
+    )
+}
+
+fn get_textbox_pos(systems: &SystemHolder, text_pos: Vec3) -> Vec3 {
+    Vec3::new(
+        text_pos.x + (85.0 * systems.scale as f32).floor(),
+        text_pos.y,
+        ORDER_WINDOW_CONTENT,
+    )
+}
+
+// Properties sits under the tag filter, Save and Close under the collision
+fn get_button_pos(systems: &SystemHolder, tileset_pos: Vec3, index: usize) -> Vec2 {
+    let row = if index == 0 { 1 } else { 6 };
+    get_row_pos(systems, tileset_pos, row).truncate()
+        - Vec2::new(0.0, (2.0 * systems.scale as f32).floor())
+}
+
+fn get_filter_pos(tileset_pos: Vec3, tile_size: f32, index: usize) -> Vec3 {
+    let (x, y) = (
+        index as u32 % TILESET_COUNT_X,
+        index as u32 / TILESET_COUNT_X,
+    );
+    Vec3::new(
+        tileset_pos.x + (x as f32 * tile_size),
+        tileset_pos.y + ((TILESET_COUNT_Y - 1 - y) as f32 * tile_size),
+        ORDER_WINDOW_CONTENT,
+    )
+}
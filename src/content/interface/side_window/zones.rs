@@ -1,26 +1,50 @@
 use ascending_graphics::*;
 
 use crate::{
-    content::{
-        interface::side_window::zones,
-        widget::{Textbox, checkbox::*, create_label, scrollbar::*},
-    },
+    content::widget::{Textbox, button::*, checkbox::*, create_label, scrollbar::*, text_list::*},
     data_types::*,
+    database::ZoneData,
     gfx_collection::GfxType,
     renderer::SystemHolder,
 };
 
+// Text and row of each label, the ones without a textbox are centered headers
+const ZONE_LABEL: [(&str, usize, bool); 9] = [
+    ("Properties", 0, false),
+    ("Name:", 1, true),
+    ("Max NPC:", 2, true),
+    ("NPC List", 3, false),
+    ("NPC ID:", 9, true),
+    ("Weight:", 10, true),
+    ("Min Level:", 11, true),
+    ("Max Level:", 12, true),
+    ("Respawn:", 13, true),
+];
+
+const ZONE_LIST_BOTTOM: f32 = 100.0;
+const NPC_LIST_BOTTOM: f32 = 330.0;
+const MAX_VISIBLE_LIST: usize = 4;
+
 pub struct ZoneWindow {
     pub visible: bool,
-    pub zones: Vec<Checkbox>,
     lower_bg: GfxType,
     seperator: GfxType,
     pub scrollbar: Scrollbar,
+    // The zone list doubles as the legend of the zone colours
+    pub zone_list: TextList,
+    zone_color: Vec<GfxType>,
+    // Add Zone, Remove Zone, Add NPC, Remove NPC
+    pub button: Vec<Button>,
     label: Vec<GfxType>,
+    // Name, Max NPC, then the selected npc entry
     pub textbox: Vec<Textbox>,
     textbox_bg: Vec<GfxType>,
+    pub npc_list: TextList,
+    // Show All Zones, Rectangle Paint
+    pub option: Vec<Checkbox>,
 
     pub cur_zone: usize,
+    pub cur_npc: Option<usize>,
     pub cur_textbox: Option<usize>,
     content_y_size: f32,
     start_pos: Vec2,
@@ -29,63 +53,6 @@ pub struct ZoneWindow {
 
 impl ZoneWindow {
     pub fn new(systems: &mut SystemHolder, start_pos: Vec2, area_size: Vec2) -> Self {
-        let mut zones = Vec::with_capacity(5);
-
-        let checkbox_rect = CheckboxRect {
-            rect_color: Color::rgb(150, 150, 150),
-            got_border: true,
-            border_color: Color::rgb(0, 0, 0),
-            border_radius: 10.0,
-            hover_change: CheckboxChangeType::ColorChange(Color::rgb(180, 180, 180)),
-            click_change: CheckboxChangeType::ColorChange(Color::rgb(120, 120, 120)),
-            disable_change: CheckboxChangeType::None,
-        };
-
-        let check_rect = CheckRect {
-            rect_color: Color::rgb(90, 90, 90),
-            got_border: false,
-            border_color: Color::rgb(0, 0, 0),
-            border_radius: 8.0,
-            pos: Vec2::new(3.0, 3.0),
-            size: Vec2::new(14.0, 14.0),
-        };
-
-        let zone_pos = Vec2::new(
-            start_pos.x,
-            start_pos.y + (area_size.y - (120.0 * systems.scale as f32).floor()),
-        );
-
-        for i in 0..5 {
-            zones.push(Checkbox::new(
-                systems,
-                CheckboxType::Rect(checkbox_rect),
-                CheckType::SetRect(check_rect),
-                zone_pos,
-                Vec2::new(5.0, 22.0 * (4 - i) as f32),
-                ORDER_WINDOW_CONTENT,
-                Vec2::new(20.0, 20.0),
-                RENDER_GUI,
-                1,
-                RENDER_GUI,
-                2,
-                Some(CheckboxText {
-                    text: format!("Zone {}", i + 1),
-                    offset_pos: Vec2::new(3.0, 0.0),
-                    buffer_layer: RENDER_GUI_TEXT,
-                    order_layer: 2,
-                    label_size: Vec2::new(100.0, 20.0),
-                    color: Color::rgb(255, 255, 255),
-                    hover_change: CheckboxChangeType::None,
-                    click_change: CheckboxChangeType::None,
-                    disable_change: CheckboxChangeType::None,
-                }),
-                false,
-                None,
-            ));
-        }
-
-        zones[0].set_value(systems, true);
-
         let rect = Rect::new(
             &mut systems.renderer,
             Vec3::new(start_pos.x, 0.0, ORDER_WINDOW_CONTENT2),
@@ -97,13 +64,10 @@ impl ZoneWindow {
             .gfx
             .add_rect(rect, RENDER_GUI2, "BG", false, CameraView::SubView1);
 
+        let separator_pos = get_zone_pos(systems, start_pos, area_size, 140.0, 0.0);
         let separator_rect = Rect::new(
             &mut systems.renderer,
-            Vec3::new(
-                zone_pos.x + (10.0 * systems.scale as f32).floor(),
-                zone_pos.y - (10.0 * systems.scale as f32).floor(),
-                ORDER_WINDOW_CONTENT2,
-            ),
+            separator_pos.extend(ORDER_WINDOW_CONTENT2),
             Vec2::new(
                 area_size.x - (40.0 * systems.scale as f32).floor(),
                 (2.0 * systems.scale as f32).floor(),
@@ -119,7 +83,7 @@ impl ZoneWindow {
             CameraView::SubView1,
         );
 
-        let content_y_size = (368.0 * systems.scale as f32).floor();
+        let content_y_size = (560.0 * systems.scale as f32).floor();
         let scroll_value = (content_y_size - area_size.y).max(0.0) as usize;
 
         let bar_size = (area_size.y / systems.scale as f32).floor() - 20.0;
@@ -159,21 +123,91 @@ impl ZoneWindow {
             None,
         );
 
-        let mut label = Vec::with_capacity(8);
-        let mut textbox = Vec::with_capacity(6);
-        let mut textbox_bg = Vec::with_capacity(6);
-        for i in 0..8 {
-            let text_pos = Vec3::new(
-                start_pos.x + (10.0 * systems.scale as f32).floor(),
-                start_pos.y
-                    + (area_size.y - ((156.0 + (26.0 * i as f32)) * systems.scale as f32).floor()),
-                ORDER_WINDOW_CONTENT,
+        let list_width = (area_size.x / systems.scale as f32).floor() - 40.0;
+        let zone_list = create_zone_list(
+            systems,
+            get_zone_pos(systems, start_pos, area_size, ZONE_LIST_BOTTOM, 0.0)
+                + Vec2::new((20.0 * systems.scale as f32).floor(), 0.0),
+            Vec2::new(list_width - 20.0, (MAX_VISIBLE_LIST as f32 * 20.0) + 10.0),
+        );
+        let npc_list = create_zone_list(
+            systems,
+            get_zone_pos(systems, start_pos, area_size, NPC_LIST_BOTTOM, 0.0),
+            Vec2::new(list_width, (MAX_VISIBLE_LIST as f32 * 20.0) + 10.0),
+        );
+
+        let mut zone_color = Vec::with_capacity(MAX_VISIBLE_LIST);
+        for i in 0..MAX_VISIBLE_LIST {
+            let color_pos = get_zone_color_pos(systems, &zone_list, i);
+            let rect = Rect::new(
+                &mut systems.renderer,
+                color_pos,
+                (Vec2::new(14.0, 14.0) * systems.scale as f32).floor(),
+                ZONE_COLOR[i % ZONE_COLOR.len()],
+                1,
             );
+            zone_color.push(systems.gfx.add_rect(
+                rect,
+                RENDER_GUI,
+                "Zone Color",
+                false,
+                CameraView::SubView1,
+            ));
+        }
+
+        let buttonrect = ButtonRect {
+            rect_color: Color::rgb(80, 80, 80),
+            got_border: true,
+            border_color: Color::rgb(0, 0, 0),
+            border_radius: 0.0,
+            hover_change: ButtonChangeType::ColorChange(Color::rgb(50, 50, 50)),
+            click_change: ButtonChangeType::ColorChange(Color::rgb(80, 80, 80)),
+            alert_change: ButtonChangeType::None,
+            disable_change: ButtonChangeType::None,
+        };
+        let button_size = ((list_width - 6.0) * 0.5).floor();
+
+        let mut button = Vec::with_capacity(4);
+        for (i, text) in ["Add Zone", "Remove Zone", "Add NPC", "Remove NPC"]
+            .iter()
+            .enumerate()
+        {
+            button.push(Button::new(
+                systems,
+                ButtonType::Rect(buttonrect),
+                ButtonContentType::Text(ButtonContentText {
+                    text: text.to_string(),
+                    pos: Vec2::new(0.0, 2.0),
+                    color: Color::rgb(255, 255, 255),
+                    order_layer: 2,
+                    buffer_layer: RENDER_GUI_TEXT,
+                    hover_change: ButtonChangeType::None,
+                    click_change: ButtonChangeType::None,
+                    alert_change: ButtonChangeType::None,
+                    disable_change: ButtonChangeType::None,
+                }),
+                get_button_pos(systems, start_pos, area_size, i, 0.0),
+                Vec2::new((button_size + 6.0) * (i % 2) as f32, 0.0),
+                ORDER_WINDOW_CONTENT,
+                Vec2::new(button_size, 24.0),
+                1,
+                RENDER_GUI,
+                false,
+                None,
+                false,
+            ));
+        }
+
+        let mut label = Vec::with_capacity(ZONE_LABEL.len());
+        let mut textbox = Vec::with_capacity(7);
+        let mut textbox_bg = Vec::with_capacity(7);
+        for (text, row, got_textbox) in ZONE_LABEL {
+            let text_pos = get_zone_row_pos(systems, start_pos, area_size, row, 0.0);
             let text_size = Vec2::new(
                 area_size.x - (40.0 * systems.scale as f32).floor(),
                 (20.0 * systems.scale as f32).floor(),
             );
-            let text = create_label(
+            let label_text = create_label(
                 systems,
                 text_pos,
                 text_size,
@@ -190,23 +224,15 @@ impl ZoneWindow {
                 true,
             );
             let text_gfx = systems.gfx.add_text(
-                text,
+                label_text,
                 RENDER_GUI_TEXT,
                 "Zone Labels",
                 false,
                 CameraView::SubView1,
             );
-            systems.gfx.set_text(
-                &text_gfx,
-                &match i {
-                    0 => "Properties".to_string(),
-                    1 => "Max NPC:".to_string(),
-                    2 => "NPC ID".to_string(),
-                    _ => format!("{}:", i - 2),
-                },
-            );
+            systems.gfx.set_text(&text_gfx, text);
 
-            if matches!(i, 0 | 2) {
+            if !got_textbox {
                 systems.gfx.center_text(&mut systems.renderer, &text_gfx);
             } else {
                 let textbox_pos = Vec3::new(
@@ -225,7 +251,7 @@ impl ZoneWindow {
                     RENDER_GUI,
                     RENDER_GUI_TEXT,
                     [2, 3, 4],
-                    255,
+                    if textbox.is_empty() { 32 } else { 255 },
                     Color::rgb(110, 110, 110),
                     Color::rgb(150, 150, 150),
                     false,
@@ -234,7 +260,6 @@ impl ZoneWindow {
                     vec![],
                     true,
                 );
-                textbox_data.set_text(systems, "0".to_string());
                 textbox_data.set_select(systems, false);
                 textbox_data.set_hold(false);
                 textbox.push(textbox_data);
@@ -259,16 +284,70 @@ impl ZoneWindow {
             label.push(text_gfx);
         }
 
+        let mut option = Vec::with_capacity(2);
+        for i in 0..2 {
+            option.push(Checkbox::new(
+                systems,
+                CheckboxType::Rect(CheckboxRect {
+                    rect_color: Color::rgb(150, 150, 150),
+                    got_border: true,
+                    border_color: Color::rgb(0, 0, 0),
+                    border_radius: 0.0,
+                    hover_change: CheckboxChangeType::ColorChange(Color::rgb(180, 180, 180)),
+                    click_change: CheckboxChangeType::ColorChange(Color::rgb(120, 120, 120)),
+                    disable_change: CheckboxChangeType::None,
+                }),
+                CheckType::SetRect(CheckRect {
+                    rect_color: Color::rgb(90, 90, 90),
+                    got_border: false,
+                    border_color: Color::rgb(0, 0, 0),
+                    border_radius: 0.0,
+                    pos: Vec2::new(3.0, 3.0),
+                    size: Vec2::new(14.0, 14.0),
+                }),
+                get_zone_row_pos(systems, start_pos, area_size, 14 + i, 0.0).truncate(),
+                Vec2::new(0.0, 0.0),
+                ORDER_WINDOW_CONTENT,
+                Vec2::new(20.0, 20.0),
+                RENDER_GUI,
+                1,
+                RENDER_GUI,
+                2,
+                Some(CheckboxText {
+                    text: if i == 0 {
+                        "Show All Zones".to_string()
+                    } else {
+                        "Rectangle Paint".to_string()
+                    },
+                    offset_pos: Vec2::new(3.0, 0.0),
+                    buffer_layer: RENDER_GUI_TEXT,
+                    order_layer: 2,
+                    label_size: Vec2::new(140.0, 20.0),
+                    color: Color::rgb(255, 255, 255),
+                    hover_change: CheckboxChangeType::None,
+                    click_change: CheckboxChangeType::None,
+                    disable_change: CheckboxChangeType::None,
+                }),
+                false,
+                None,
+            ));
+        }
+
         ZoneWindow {
             visible: false,
-            zones,
-            cur_zone: 0,
             lower_bg,
             seperator,
             scrollbar,
+            zone_list,
+            zone_color,
+            button,
             label,
             textbox,
             textbox_bg,
+            npc_list,
+            option,
+            cur_zone: 0,
+            cur_npc: None,
             cur_textbox: None,
             content_y_size,
             start_pos,
@@ -280,15 +359,6 @@ impl ZoneWindow {
         self.start_pos = start_pos;
         self.area_size = area_size;
 
-        let zone_pos = Vec2::new(
-            start_pos.x,
-            start_pos.y + (area_size.y - (120.0 * systems.scale as f32).floor()),
-        );
-
-        for zones in self.zones.iter_mut() {
-            zones.set_pos(systems, zone_pos);
-        }
-
         systems
             .gfx
             .set_size(&self.lower_bg, Vec2::new(area_size.x, start_pos.y));
@@ -297,15 +367,6 @@ impl ZoneWindow {
             Vec3::new(start_pos.x, 0.0, ORDER_WINDOW_CONTENT2),
         );
 
-        systems.gfx.set_pos(
-            &self.seperator,
-            Vec3::new(
-                zone_pos.x + (10.0 * systems.scale as f32).floor(),
-                zone_pos.y - (10.0 * systems.scale as f32).floor(),
-                ORDER_WINDOW_CONTENT2,
-            ),
-        );
-
         let scroll_value = (self.content_y_size - area_size.y).max(0.0) as usize;
         let bar_size = (area_size.y / systems.scale as f32).floor() - 20.0;
         let min_bar_size = (bar_size * 0.4).floor();
@@ -318,51 +379,7 @@ impl ZoneWindow {
         self.scrollbar.set_value(systems, 0);
         self.scrollbar.set_max_value(systems, scroll_value);
 
-        let mut loop_count = 0;
-        for (i, label) in self.label.iter().enumerate() {
-            let text_pos = Vec3::new(
-                start_pos.x + (10.0 * systems.scale as f32).floor(),
-                start_pos.y
-                    + (area_size.y - ((156.0 + (26.0 * i as f32)) * systems.scale as f32).floor()),
-                ORDER_WINDOW_CONTENT,
-            );
-            let text_size = Vec2::new(
-                area_size.x - (40.0 * systems.scale as f32).floor(),
-                (20.0 * systems.scale as f32).floor(),
-            );
-
-            systems.gfx.set_pos(label, text_pos);
-            systems.gfx.set_bound(
-                label,
-                Some(Bounds::new(
-                    text_pos.x,
-                    text_pos.y,
-                    text_pos.x + text_size.x,
-                    text_pos.y + text_size.y,
-                )),
-            );
-
-            if matches!(i, 0 | 2) {
-                systems.gfx.center_text(&mut systems.renderer, label);
-            } else {
-                let textbox_pos = Vec2::new(
-                    text_pos.x + (85.0 * systems.scale as f32).floor(),
-                    text_pos.y,
-                );
-
-                self.textbox[loop_count].set_pos(systems, textbox_pos);
-                systems.gfx.set_pos(
-                    &self.textbox_bg[loop_count],
-                    Vec3::new(
-                        textbox_pos.x - (1.0 * systems.scale as f32).floor(),
-                        textbox_pos.y - (1.0 * systems.scale as f32).floor(),
-                        ORDER_WINDOW_CONTENT,
-                    ),
-                );
-
-                loop_count += 1;
-            }
-        }
+        self.update_pos(systems, 0.0);
     }
 
     pub fn set_visible(&mut self, systems: &mut SystemHolder, visible: bool) {
@@ -371,12 +388,14 @@ impl ZoneWindow {
         }
 
         self.visible = visible;
-        for checkbox in self.zones.iter_mut() {
-            checkbox.set_visible(systems, visible);
-        }
         systems.gfx.set_visible(&self.lower_bg, visible);
         systems.gfx.set_visible(&self.seperator, visible);
         self.scrollbar.set_visible(systems, visible);
+        self.zone_list.set_visible(systems, visible, false);
+        self.npc_list.set_visible(systems, visible, false);
+        for button in self.button.iter_mut() {
+            button.set_visible(systems, visible);
+        }
         for gfx in self.label.iter() {
             systems.gfx.set_visible(gfx, visible);
         }
@@ -386,41 +405,149 @@ impl ZoneWindow {
         for gfx in self.textbox_bg.iter() {
             systems.gfx.set_visible(gfx, visible);
         }
+        for checkbox in self.option.iter_mut() {
+            checkbox.set_visible(systems, visible);
+        }
+        self.update_zone_color(systems);
     }
 
-    pub fn update_content(&mut self, systems: &mut SystemHolder) {
-        let zone_pos = Vec2::new(
-            self.start_pos.x,
-            self.start_pos.y
-                + (self.area_size.y - (120.0 * systems.scale as f32).floor())
-                + self.scrollbar.value as f32,
+    pub fn is_overview(&self) -> bool {
+        self.option[0].value
+    }
+
+    pub fn is_rect_paint(&self) -> bool {
+        self.option[1].value
+    }
+
+    // Zone lines show the max npc count and the npc ids of each zone
+    pub fn update_zone_list(&mut self, systems: &mut SystemHolder, zones: &[ZoneData]) {
+        self.cur_zone = self.cur_zone.min(zones.len().saturating_sub(1));
+
+        let list = zones
+            .iter()
+            .map(|zone| {
+                let npc_ids: Vec<String> = zone
+                    .npcs
+                    .iter()
+                    .map(|npc| format!("{}", npc.npc_id))
+                    .collect();
+                format!(
+                    "{}: Max {} NPC {}",
+                    zone.name,
+                    zone.max_npc,
+                    if npc_ids.is_empty() {
+                        "-".to_string()
+                    } else {
+                        npc_ids.join(",")
+                    }
+                )
+            })
+            .collect();
+        let selected = (!zones.is_empty()).then_some(self.cur_zone);
+        set_list_keep_scroll(systems, &mut self.zone_list, list, selected);
+        self.update_zone_color(systems);
+    }
+
+    // Fills the textboxes with the current zone and its selected npc entry
+    pub fn update_zone_data(&mut self, systems: &mut SystemHolder, zones: &[ZoneData]) {
+        let zone = zones.get(self.cur_zone);
+        self.update_npc_list(systems, zone);
+
+        self.textbox[0].set_text(
+            systems,
+            zone.map(|zone| zone.name.clone()).unwrap_or_default(),
+        );
+        self.textbox[1].set_text(
+            systems,
+            zone.map(|zone| format!("{}", zone.max_npc))
+                .unwrap_or_default(),
         );
 
-        for zones in self.zones.iter_mut() {
-            zones.set_pos(systems, zone_pos);
+        let npc = zone.and_then(|zone| self.cur_npc.and_then(|index| zone.npcs.get(index)));
+        let npc_text: [String; 5] = npc.map_or_else(Default::default, |npc| {
+            [
+                format!("{}", npc.npc_id),
+                format!("{}", npc.weight),
+                format!("{}", npc.min_level),
+                format!("{}", npc.max_level),
+                format!("{}", npc.respawn),
+            ]
+        });
+        for (textbox, text) in self.textbox[2..].iter_mut().zip(npc_text) {
+            textbox.set_text(systems, text);
         }
+    }
+
+    pub fn update_npc_list(&mut self, systems: &mut SystemHolder, zone: Option<&ZoneData>) {
+        let list: Vec<String> = zone
+            .map(|zone| {
+                zone.npcs
+                    .iter()
+                    .map(|npc| {
+                        format!(
+                            "#{} W{} Lv{}-{} {}s",
+                            npc.npc_id, npc.weight, npc.min_level, npc.max_level, npc.respawn
+                        )
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        self.cur_npc = self.cur_npc.filter(|index| *index < list.len());
+        let selected = self.cur_npc;
+        set_list_keep_scroll(systems, &mut self.npc_list, list, selected);
+    }
+
+    // Each visible row of the zone list gets the colour its zone is drawn with
+    pub fn update_zone_color(&mut self, systems: &mut SystemHolder) {
+        let scroll = self.zone_list.scrollbar.value;
+        for (i, gfx) in self.zone_color.iter().enumerate() {
+            let zone = scroll + i;
+            systems
+                .gfx
+                .set_color(gfx, ZONE_COLOR[zone % ZONE_COLOR.len()]);
+            systems
+                .gfx
+                .set_visible(gfx, self.visible && zone < self.zone_list.max_data);
+        }
+    }
+
+    fn update_pos(&mut self, systems: &mut SystemHolder, scroll: f32) {
+        let (start_pos, area_size) = (self.start_pos, self.area_size);
 
         systems.gfx.set_pos(
             &self.seperator,
-            Vec3::new(
-                zone_pos.x + (10.0 * systems.scale as f32).floor(),
-                zone_pos.y - (10.0 * systems.scale as f32).floor(),
-                ORDER_WINDOW_CONTENT2,
-            ),
+            get_zone_pos(systems, start_pos, area_size, 140.0, scroll)
+                .extend(ORDER_WINDOW_CONTENT2),
         );
 
-        let mut loop_count = 0;
-        for (i, label) in self.label.iter().enumerate() {
-            let text_pos = Vec3::new(
-                self.start_pos.x + (10.0 * systems.scale as f32).floor(),
-                self.start_pos.y
-                    + (self.area_size.y
-                        - ((156.0 + (26.0 * i as f32)) * systems.scale as f32).floor())
-                    + self.scrollbar.value as f32,
-                ORDER_WINDOW_CONTENT,
+        self.zone_list.set_pos(
+            systems,
+            get_zone_pos(systems, start_pos, area_size, ZONE_LIST_BOTTOM, scroll)
+                + Vec2::new((20.0 * systems.scale as f32).floor(), 0.0),
+        );
+        self.npc_list.set_pos(
+            systems,
+            get_zone_pos(systems, start_pos, area_size, NPC_LIST_BOTTOM, scroll),
+        );
+        for (i, gfx) in self.zone_color.iter().enumerate() {
+            systems
+                .gfx
+                .set_pos(gfx, get_zone_color_pos(systems, &self.zone_list, i));
+        }
+
+        for (i, button) in self.button.iter_mut().enumerate() {
+            button.set_pos(
+                systems,
+                get_button_pos(systems, start_pos, area_size, i, scroll),
             );
+        }
+
+        let mut textbox_index = 0;
+        for (label, (_, row, got_textbox)) in self.label.iter().zip(ZONE_LABEL) {
+            let text_pos = get_zone_row_pos(systems, start_pos, area_size, row, scroll);
             let text_size = Vec2::new(
-                self.area_size.x - (40.0 * systems.scale as f32).floor(),
+                area_size.x - (40.0 * systems.scale as f32).floor(),
                 (20.0 * systems.scale as f32).floor(),
             );
 
@@ -435,7 +562,7 @@ impl ZoneWindow {
                 )),
             );
 
-            if matches!(i, 0 | 2) {
+            if !got_textbox {
                 systems.gfx.center_text(&mut systems.renderer, label);
             } else {
                 let textbox_pos = Vec2::new(
@@ -443,9 +570,9 @@ impl ZoneWindow {
                     text_pos.y,
                 );
 
-                self.textbox[loop_count].set_pos(systems, textbox_pos);
+                self.textbox[textbox_index].set_pos(systems, textbox_pos);
                 systems.gfx.set_pos(
-                    &self.textbox_bg[loop_count],
+                    &self.textbox_bg[textbox_index],
                     Vec3::new(
                         textbox_pos.x - (1.0 * systems.scale as f32).floor(),
                         textbox_pos.y - (1.0 * systems.scale as f32).floor(),
@@ -453,8 +580,145 @@ impl ZoneWindow {
                     ),
                 );
 
-                loop_count += 1;
+                textbox_index += 1;
             }
         }
+
+        for (i, checkbox) in self.option.iter_mut().enumerate() {
+            let row_pos = get_zone_row_pos(systems, start_pos, area_size, 14 + i, scroll);
+            checkbox.set_pos(systems, row_pos.truncate());
+        }
+    }
+
+    pub fn update_content(&mut self, systems: &mut SystemHolder) {
+        self.update_pos(systems, self.scrollbar.value as f32);
+    }
+}
+
+// Rebuilding the list would scroll it back to the top while typing into the textboxes
+fn set_list_keep_scroll(
+    systems: &mut SystemHolder,
+    list: &mut TextList,
+    text: Vec<String>,
+    selected: Option<usize>,
+) {
+    let scroll = list.scrollbar.value;
+    list.update_list(systems, text, None);
+    list.scrollbar
+        .set_value(systems, scroll.min(list.scrollbar.max_value));
+    list.update_list_scroll(systems);
+    list.set_select(systems, selected, true);
+}
+
+fn create_zone_list(systems: &mut SystemHolder, pos: Vec2, size: Vec2) -> TextList {
+    TextList::new(
+        systems,
+        pos,
+        Vec2::new(0.0, 0.0),
+        ORDER_WINDOW_CONTENT,
+        size,
+        false,
+        TextListBG::Rect(TextListBGRect {
+            color: Color::rgb(85, 85, 85),
+            buffer_layer: RENDER_GUI,
+            order_layer: 2,
+            got_border: false,
+            border_color: Color::rgb(0, 0, 0),
+            radius: 0.0,
+        }),
+        ScrollbarRect {
+            color: Color::rgb(150, 150, 150),
+            buffer_layer: RENDER_GUI,
+            order_layer: 3,
+            got_border: false,
+            border_color: Color::rgb(0, 0, 0),
+            hover_color: Color::rgb(180, 180, 180),
+            hold_color: Color::rgb(120, 120, 120),
+            radius: 0.0,
+        },
+        Some(ScrollbarBackground {
+            color: Color::rgb(90, 90, 90),
+            buffer_layer: RENDER_GUI,
+            order_layer: 2,
+            got_border: false,
+            border_color: Color::rgb(0, 0, 0),
+            radius: 0.0,
+        }),
+        Vec::new(),
+        TextListData {
+            selection_bufferlayer: RENDER_GUI,
+            text_bufferlayer: RENDER_GUI_TEXT,
+            selection_orderlayer: 4,
+            text_orderlayer: 5,
+            selection_color: SelectionColor {
+                normal: Color::rgb(85, 85, 85),
+                hover: Color::rgb(120, 120, 120),
+                selected: Color::rgb(60, 60, 60),
+            },
+            text_color: SelectionColor {
+                normal: Color::rgb(255, 255, 255),
+                hover: Color::rgb(255, 255, 255),
+                selected: Color::rgb(255, 255, 255),
+            },
+            max_list: MAX_VISIBLE_LIST,
+        },
+    )
+}
+
+// Positions are measured from the top of the window, the scroll moves the content up
+fn get_zone_pos(
+    systems: &SystemHolder,
+    start_pos: Vec2,
+    area_size: Vec2,
+    from_top: f32,
+    scroll: f32,
+) -> Vec2 {
+    Vec2::new(
+        start_pos.x + (10.0 * systems.scale as f32).floor(),
+        start_pos.y + (area_size.y - (from_top * systems.scale as f32).floor()) + scroll,
+    )
+}
+
+fn get_zone_row_pos(
+    systems: &SystemHolder,
+    start_pos: Vec2,
+    area_size: Vec2,
+    row: usize,
+    scroll: f32,
+) -> Vec3 {
+    get_zone_pos(
+        systems,
+        start_pos,
+        area_size,
+        156.0 + (26.0 * row as f32),
+        scroll,
+    )
+    .extend(ORDER_WINDOW_CONTENT)
+}
+
+// The zone buttons sit under the zone list, the npc buttons under the npc list
+fn get_button_pos(
+    systems: &SystemHolder,
+    start_pos: Vec2,
+    area_size: Vec2,
+    index: usize,
+    scroll: f32,
+) -> Vec2 {
+    if index < 2 {
+        get_zone_pos(systems, start_pos, area_size, 128.0, scroll)
+    } else {
+        get_zone_row_pos(systems, start_pos, area_size, 8, scroll).truncate()
+            - Vec2::new(0.0, (2.0 * systems.scale as f32).floor())
     }
 }
+
+fn get_zone_color_pos(systems: &SystemHolder, zone_list: &TextList, index: usize) -> Vec3 {
+    Vec3::new(
+        zone_list.base_pos.x - (17.0 * systems.scale as f32).floor(),
+        zone_list.base_pos.y
+            + ((zone_list.size.y - 5.0 - ((index as f32 + 1.0) * 20.0) + 3.0)
+                * systems.scale as f32)
+                .floor(),
+        ORDER_WINDOW_CONTENT,
+    )
+}
@@ -44,7 +44,7 @@ pub fn in_view_screen(systems: &SystemHolder, screen_pos: Vec2) -> bool {
 }
 
 pub fn in_layer_area(systems: &SystemHolder, screen_pos: Vec2) -> bool {
-    let size = Vec2::new(48.0, 137.0);
+    let size = Vec2::new(84.0, 160.0);
     let pos = Vec2::new(systems.size.width - size.x, 0.0);
     is_within_area(screen_pos, pos, size)
 }
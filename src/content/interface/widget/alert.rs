@@ -3,22 +3,26 @@ mod alert_builder;
 use crate::{
     Content, MouseInputType, SystemHolder,
     content::{
-        apply_link_map, apply_map_data, exit_editor,
+        MapTransform, apply_link_map, apply_map_data, delete_map, delete_preset, duplicate_map,
+        exit_editor, export_presets, import_presets,
         interface::widget::{
             button::*, checkbox::*, create_empty_label, get_screen_center, is_within_area,
             measure_string,
         },
-        save_preset,
+        move_map, parse_shift_input, rename_preset, save_link_map_temp, save_preset,
+        set_preset_category, switch_tileset, transform_map,
         widget::{Textbox, Tooltip},
     },
     data_types::*,
     database::{
-        delete_recovery_map_file, delete_temp_map_file, load_recovery_map_file, load_temp_map_file,
-        save_and_clear_map,
+        delete_recovery_map_file, delete_temp_map_file, is_valid_template_name,
+        load_recovery_map_file, load_temp_map_file, save_and_clear_map, save_map_template,
     },
     gfx_collection::GfxType,
+    renderer::Graphics,
 };
 pub use alert_builder::*;
+use ascending_camera::controls::FlatControls;
 use ascending_graphics::{cosmic_text::Attrs, *};
 use ascending_input::{Key, Named};
 use winit::event_loop::ActiveEventLoop;
@@ -606,6 +610,7 @@ impl Alert {
     pub fn alert_mouse_input(
         &mut self,
         systems: &mut SystemHolder,
+        graphics: &mut Graphics<FlatControls>,
         content: &mut Content,
         elwt: &ActiveEventLoop,
         input_type: MouseInputType,
@@ -625,7 +630,7 @@ impl Alert {
                 let button_index = self.click_buttons(systems, screen_pos);
                 if let Some(index) = button_index {
                     self.did_button_click = true;
-                    self.select_option(systems, content, elwt, index, seconds)?;
+                    self.select_option(systems, graphics, content, elwt, index, seconds)?;
                 }
                 self.click_textbox(systems, screen_pos);
 
@@ -642,10 +647,12 @@ impl Alert {
         Ok(())
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn alert_key_input(
         &mut self,
         content: &mut Content,
         systems: &mut SystemHolder,
+        graphics: &mut Graphics<FlatControls>,
         elwt: &ActiveEventLoop,
         seconds: f32,
         key: &Key,
@@ -662,18 +669,18 @@ impl Alert {
         match self.alert_type {
             AlertType::Confirm | AlertType::Input => match key {
                 Key::Named(Named::Enter) => {
-                    self.select_option(systems, content, elwt, 0, seconds)?;
+                    self.select_option(systems, graphics, content, elwt, 0, seconds)?;
                     return Ok(true);
                 }
                 Key::Named(Named::Escape) => {
-                    self.select_option(systems, content, elwt, 1, seconds)?;
+                    self.select_option(systems, graphics, content, elwt, 1, seconds)?;
                     return Ok(true);
                 }
                 _ => {}
             },
             AlertType::Inform => {
                 if let Key::Named(Named::Enter) = key {
-                    self.select_option(systems, content, elwt, 0, seconds)?;
+                    self.select_option(systems, graphics, content, elwt, 0, seconds)?;
                     return Ok(true);
                 }
             }
@@ -685,6 +692,7 @@ impl Alert {
     pub fn select_option(
         &mut self,
         systems: &mut SystemHolder,
+        graphics: &mut Graphics<FlatControls>,
         content: &mut Content,
         elwt: &ActiveEventLoop,
         index: usize,
@@ -705,17 +713,24 @@ impl Alert {
                     #[allow(clippy::match_single_binding)]
                     0 => match self.custom_index {
                         AlertIndex::ExitEditor => exit_editor(content, systems, self, elwt, None)?,
-                        AlertIndex::LoadTempFile(mappos) => {
+                        AlertIndex::LoadTempFile(mappos, center) => {
                             if let Ok(mapdata) =
                                 load_temp_map_file(mappos.x, mappos.y, mappos.group as u64)
                             {
                                 apply_map_data(content, systems, &mapdata);
-                                apply_link_map(content, mappos);
+                                apply_link_map(content, systems, mappos)?;
+                                content.data.switch_history(Some(mappos));
                                 content.data.mapdata = mapdata;
                                 content.data.pos = Some(mappos);
+                                if systems.config.save_undo_history {
+                                    content.data.load_history()?;
+                                }
                                 content.data.changed = true;
                                 content.data.temp_saved = true;
                                 content.interface.footer.set_map_pos(systems, mappos, true);
+                                if let Some(tile) = center {
+                                    content.map_view.center_on_tile(systems, graphics, tile);
+                                }
 
                                 content.interface.notification.add_msg(
                                     systems,
@@ -741,9 +756,17 @@ impl Alert {
                         }
                         AlertIndex::LoadRecoveryFile => {
                             if let Ok(mapdata) = load_recovery_map_file() {
+                                // The recovery map has no position to link with
+                                save_link_map_temp(content)?;
+                                content.data.link_map.clear();
+
                                 apply_map_data(content, systems, &mapdata);
+                                content.data.switch_history(None);
                                 content.data.mapdata = mapdata;
                                 content.data.pos = None;
+                                if systems.config.save_undo_history {
+                                    content.data.load_history()?;
+                                }
                                 content.data.changed = true;
                                 content.data.temp_saved = true;
                                 content.interface.footer.remove_map_pos(systems);
@@ -757,12 +780,54 @@ impl Alert {
 
                             self.hide_alert(systems)
                         }
+                        AlertIndex::DuplicateMap(mappos) => {
+                            duplicate_map(content, systems, mappos)?;
+                            content.interface.notification.add_msg(
+                                systems,
+                                format!(
+                                    "Map duplicated to [X: {} Y: {} Group: {}]!",
+                                    mappos.x, mappos.y, mappos.group
+                                ),
+                                seconds,
+                            );
+                            self.hide_alert(systems)
+                        }
+                        AlertIndex::MoveMap(mappos) => {
+                            move_map(content, systems, mappos, checkbox_value)?;
+                            content.interface.notification.add_msg(
+                                systems,
+                                format!(
+                                    "Map moved to [X: {} Y: {} Group: {}]!",
+                                    mappos.x, mappos.y, mappos.group
+                                ),
+                                seconds,
+                            );
+                            self.hide_alert(systems)
+                        }
+                        AlertIndex::DeleteMap => {
+                            delete_map(content, systems)?;
+                            content.interface.notification.add_msg(
+                                systems,
+                                "Map deleted!".to_string(),
+                                seconds,
+                            );
+                            self.hide_alert(systems)
+                        }
+                        AlertIndex::DeletePreset => {
+                            delete_preset(content, systems)?;
+                            self.hide_alert(systems)
+                        }
+                        AlertIndex::SwitchTileset(tileset) => {
+                            switch_tileset(content, systems, tileset);
+                            self.hide_alert(systems)
+                        }
                         _ => self.hide_alert(systems),
                     }, // Yes
                     #[allow(clippy::match_single_binding)]
                     _ => match self.custom_index {
-                        AlertIndex::LoadTempFile(mappos) => {
+                        AlertIndex::LoadTempFile(mappos, _) => {
                             delete_temp_map_file(mappos.x, mappos.y, mappos.group as u64)?;
+                            let _ = content.data.map_history.remove(&mappos);
                             self.hide_alert(systems)
                         }
                         AlertIndex::ExitSaveMap(_) => exit_editor(
@@ -790,6 +855,117 @@ impl Alert {
                                 save_preset(content, systems, input_text)?;
                                 self.hide_alert(systems)
                             }
+                            AlertIndex::RenamePreset => {
+                                rename_preset(content, systems, &input_text)?;
+                                self.hide_alert(systems)
+                            }
+                            AlertIndex::SetPresetCategory => {
+                                set_preset_category(content, systems, &input_text)?;
+                                self.hide_alert(systems)
+                            }
+                            AlertIndex::ShiftMap(wrap) => {
+                                if let Some((x, y)) = parse_shift_input(&input_text) {
+                                    transform_map(
+                                        content,
+                                        systems,
+                                        MapTransform::Shift { x, y, wrap },
+                                    );
+                                    self.hide_alert(systems)
+                                } else {
+                                    self.show_alert(
+                                        systems,
+                                        &AlertBuilder::new_info(
+                                            "Error",
+                                            "Shift must be written as X,Y",
+                                        ),
+                                    );
+                                }
+                            }
+                            AlertIndex::SaveTemplate => {
+                                if is_valid_template_name(&input_text) {
+                                    save_map_template(&input_text, &content.data.mapdata)?;
+                                    content.interface.notification.add_msg(
+                                        systems,
+                                        format!("Template {} Saved!", input_text.trim()),
+                                        seconds,
+                                    );
+                                    self.hide_alert(systems)
+                                } else {
+                                    self.show_alert(
+                                        systems,
+                                        &AlertBuilder::new_info(
+                                            "Error",
+                                            "Template names can only use letters, numbers, spaces, - and _",
+                                        ),
+                                    );
+                                }
+                            }
+                            AlertIndex::ExportPresets | AlertIndex::ImportPresets
+                                if !is_valid_template_name(&input_text) =>
+                            {
+                                self.show_alert(
+                                    systems,
+                                    &AlertBuilder::new_info(
+                                        "Error",
+                                        "Bundle names can only use letters, numbers, spaces, - and _",
+                                    ),
+                                );
+                            }
+                            AlertIndex::ExportPresets => {
+                                match export_presets(content, systems, &input_text) {
+                                    Ok(count) => {
+                                        content.interface.notification.add_msg(
+                                            systems,
+                                            format!(
+                                                "{count} Presets exported to {}!",
+                                                input_text.trim()
+                                            ),
+                                            seconds,
+                                        );
+                                        self.hide_alert(systems)
+                                    }
+                                    Err(e) => self.show_alert(
+                                        systems,
+                                        &AlertBuilder::new_info(
+                                            "Error",
+                                            &format!("Failed to export presets: {e}"),
+                                        ),
+                                    ),
+                                }
+                            }
+                            AlertIndex::ImportPresets => {
+                                match import_presets(content, systems, &input_text) {
+                                    Ok((count, missing)) => {
+                                        content.interface.notification.add_msg(
+                                            systems,
+                                            format!("{count} Presets imported!"),
+                                            seconds,
+                                        );
+                                        if missing.is_empty() {
+                                            self.hide_alert(systems)
+                                        } else {
+                                            self.show_alert(
+                                                systems,
+                                                AlertBuilder::new_info(
+                                                    "Warning",
+                                                    &format!(
+                                                        "Presets using missing tile sheets were skipped: {}",
+                                                        missing.join(", ")
+                                                    ),
+                                                )
+                                                .with_width(400),
+                                            );
+                                        }
+                                    }
+                                    Err(e) => self.show_alert(
+                                        systems,
+                                        &AlertBuilder::new_info(
+                                            "Error",
+                                            &format!("Failed to import presets: {e}"),
+                                        ),
+                                    ),
+                                }
+                            }
                             _ => self.hide_alert(systems),
                         }, // Yes
                         #[allow(clippy::match_single_binding)]
@@ -1,3 +1,5 @@
+use ascending_graphics::Vec2;
+
 use crate::{
     content::widget::Textbox, data_types::*, database::MapPosition, gfx_collection::GfxType,
 };
@@ -16,9 +18,21 @@ pub enum AlertIndex {
     None,
     ExitEditor,
     ExitSaveMap(MapPosition),
-    LoadTempFile(MapPosition),
+    // Tile to center on once the map got loaded
+    LoadTempFile(MapPosition, Option<Vec2>),
     LoadRecoveryFile,
     SavePreset,
+    RenamePreset,
+    SetPresetCategory,
+    DeletePreset,
+    ShiftMap(bool),
+    DuplicateMap(MapPosition),
+    MoveMap(MapPosition),
+    DeleteMap,
+    SaveTemplate,
+    ExportPresets,
+    ImportPresets,
+    SwitchTileset(usize),
 }
 
 pub struct AlertTextbox {
@@ -13,10 +13,22 @@ use crate::{
 mod attr_preview;
 mod autotile;
 mod editor;
+mod manage;
+mod measure;
+mod terrain;
+mod transform;
+mod warp;
+mod zone;
 
 pub use attr_preview::*;
 pub use autotile::*;
 pub use editor::*;
+pub use manage::*;
+pub use measure::*;
+pub use terrain::*;
+pub use transform::*;
+pub use warp::*;
+pub use zone::*;
 
 #[derive(Default)]
 pub struct MapDrag {
@@ -32,6 +44,16 @@ pub struct TileSelect {
     pub cur_pos: Vec2,
 }
 
+pub struct MapSelection {
+    pub gfx: GfxType,
+    pub in_hold: bool,
+    pub active: bool,
+    pub start_pos: Vec2,
+    pub end_pos: Vec2,
+    // Set while dragging a painted rectangle, true when it sets and false when it erases
+    pub rect_paint: Option<bool>,
+}
+
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub struct ViewAttribute {
     pub bg: GfxType,
@@ -50,12 +72,17 @@ pub struct MapView {
     pub zones: Vec<GfxType>,
     pub dir_block: Vec<GfxType>,
     pub map_border: [GfxType; 4],
+    pub warp_marker: Vec<GfxType>,
+    pub grid: Vec<GfxType>,
+    pub ruler: Vec<GfxType>,
+    pub measure: MapMeasure,
     pub attr_preview: AttrPreview,
 
     pub attr_visible: bool,
     pub zone_visible: bool,
     pub dirblock_visible: bool,
     pub tile: TileSelect,
+    pub selection: MapSelection,
     pub drag: MapDrag,
     pub hover_linked_map: Option<usize>,
     pub camera_pos: Vec2,
@@ -173,6 +200,30 @@ impl MapView {
             cur_pos: Vec2::new(0.0, 0.0),
         };
 
+        let mut rect = Rect::new(
+            &mut systems.renderer,
+            Vec3::new(map_pos.x, map_pos.y, ORDER_TILE_SELECT),
+            Vec2::new(20.0, 20.0),
+            Color::rgba(80, 160, 255, 60),
+            3,
+        );
+        rect.set_border_width(1.0)
+            .set_border_color(Color::rgba(80, 160, 255, 220));
+        let selection = MapSelection {
+            gfx: systems.gfx.add_rect(
+                rect,
+                RENDER_TOP_MAP,
+                "Map Selection",
+                false,
+                CameraView::MainView,
+            ),
+            in_hold: false,
+            active: false,
+            start_pos: Vec2::new(0.0, 0.0),
+            end_pos: Vec2::new(0.0, 0.0),
+            rect_paint: None,
+        };
+
         let mut attribute = Vec::with_capacity(MAX_TILE);
         let mut zones = Vec::with_capacity(MAX_TILE);
         let mut dir_block = Vec::with_capacity(MAX_TILE);
@@ -292,10 +343,116 @@ impl MapView {
                 .add_rect(rect, RENDER_TOP_MAP, "Border", true, CameraView::MainView);
         }
 
+        // One line for every tile edge, the outer lines overlap with the map border
+        let mut grid = Vec::with_capacity(66);
+        for i in 0..33 {
+            let line_pos = map_pos.x + (i * TEXTURE_SIZE) as f32;
+            for vertical in [true, false] {
+                let (set_pos, set_size) = if vertical {
+                    (Vec2::new(line_pos, map_pos.y), Vec2::new(1.0, map_size))
+                } else {
+                    (
+                        Vec2::new(map_pos.x, map_pos.y + (i * TEXTURE_SIZE) as f32),
+                        Vec2::new(map_size, 1.0),
+                    )
+                };
+
+                let rect = Rect::new(
+                    &mut systems.renderer,
+                    Vec3::new(set_pos.x, set_pos.y, ORDER_TILE_SELECT),
+                    set_size,
+                    Color::rgba(0, 0, 0, 80),
+                    2,
+                );
+                grid.push(systems.gfx.add_rect(
+                    rect,
+                    RENDER_TOP_MAP,
+                    "Map Grid",
+                    systems.config.show_grid,
+                    CameraView::MainView,
+                ));
+            }
+        }
+
+        // Tile numbers below and on the left side of the map
+        let mut ruler = Vec::with_capacity(66);
+        for vertical in [false, true] {
+            let (set_pos, set_size) = if vertical {
+                (
+                    Vec2::new(map_pos.x - 22.0, map_pos.y),
+                    Vec2::new(22.0, map_size),
+                )
+            } else {
+                (
+                    Vec2::new(map_pos.x, map_pos.y - 16.0),
+                    Vec2::new(map_size, 16.0),
+                )
+            };
+            let rect = Rect::new(
+                &mut systems.renderer,
+                Vec3::new(set_pos.x, set_pos.y, ORDER_TILE_SELECT),
+                set_size,
+                Color::rgba(0, 0, 0, 180),
+                2,
+            );
+            ruler.push(systems.gfx.add_rect(
+                rect,
+                RENDER_TOP_MAP,
+                "Map Ruler BG",
+                systems.config.show_ruler,
+                CameraView::MainView,
+            ));
+
+            for i in 0..32 {
+                let (text_pos, text_size) = if vertical {
+                    (
+                        Vec2::new(
+                            map_pos.x - 22.0,
+                            map_pos.y + (i * TEXTURE_SIZE) as f32 + 2.0,
+                        ),
+                        Vec2::new(22.0, 16.0),
+                    )
+                } else {
+                    (
+                        Vec2::new(map_pos.x + (i * TEXTURE_SIZE) as f32, map_pos.y - 16.0),
+                        Vec2::new(TEXTURE_SIZE as f32, 16.0),
+                    )
+                };
+
+                let label = create_label(
+                    systems,
+                    Vec3::new(text_pos.x, text_pos.y, ORDER_TILE_SELECT),
+                    text_size,
+                    Bounds::new(
+                        text_pos.x,
+                        text_pos.y,
+                        text_pos.x + text_size.x,
+                        text_pos.y + text_size.y,
+                    ),
+                    Color::rgb(255, 255, 255),
+                    3,
+                    12.0,
+                    12.0,
+                    false,
+                );
+                let text = systems.gfx.add_text(
+                    label,
+                    RENDER_GUI_TEXT,
+                    "Map Ruler Text",
+                    systems.config.show_ruler,
+                    CameraView::MainView,
+                );
+                systems.gfx.set_text(&text, &format!("{i}"));
+                systems.gfx.center_text(&mut systems.renderer, &text);
+                ruler.push(text);
+            }
+        }
+
         Ok(MapView {
             map,
             drag: MapDrag::default(),
             tile,
+            selection,
             attribute,
             attr_visible: false,
             zones,
@@ -305,6 +462,10 @@ impl MapView {
             linked_map,
             hover_linked_map: None,
             map_border,
+            warp_marker: Vec::new(),
+            grid,
+            ruler,
+            measure: MapMeasure::default(),
             attr_preview: AttrPreview::default(),
             camera_pos: Vec2::new(0.0, 0.0),
             last_camera_pos: Vec2::new(0.0, 0.0),
@@ -338,6 +499,18 @@ impl MapView {
         }
     }
 
+    pub fn set_grid_visible(&mut self, systems: &mut SystemHolder, visible: bool) {
+        for gfx in self.grid.iter() {
+            systems.gfx.set_visible(gfx, visible);
+        }
+    }
+
+    pub fn set_ruler_visible(&mut self, systems: &mut SystemHolder, visible: bool) {
+        for gfx in self.ruler.iter() {
+            systems.gfx.set_visible(gfx, visible);
+        }
+    }
+
     pub fn set_dirblock_visible(&mut self, systems: &mut SystemHolder, visible: bool) {
         self.dirblock_visible = visible;
         for zone in self.dir_block.iter() {
@@ -345,6 +518,60 @@ impl MapView {
         }
     }
 
+    // Marks the tiles where the warps leading into this map arrive
+    pub fn set_warp_marker(
+        &mut self,
+        systems: &mut SystemHolder,
+        tiles: &[(u32, u32)],
+        visible: bool,
+    ) {
+        for gfx in self.warp_marker.drain(..) {
+            systems.gfx.remove_gfx(&mut systems.renderer, &gfx);
+        }
+
+        for &(x, y) in tiles {
+            let mut rect = Rect::new(
+                &mut systems.renderer,
+                Vec3::new(
+                    self.map.pos.x + (x * TEXTURE_SIZE) as f32,
+                    self.map.pos.y + (y * TEXTURE_SIZE) as f32,
+                    ORDER_TILE_SELECT,
+                ),
+                Vec2::new(TEXTURE_SIZE as f32, TEXTURE_SIZE as f32),
+                Color::rgba(255, 160, 40, 60),
+                3,
+            );
+            rect.set_border_width(1.0)
+                .set_border_color(Color::rgba(255, 160, 40, 220));
+            self.warp_marker.push(systems.gfx.add_rect(
+                rect,
+                RENDER_TOP_MAP,
+                "Warp Marker",
+                visible,
+                CameraView::MainView,
+            ));
+        }
+    }
+
+    pub fn select_warp_marker(&mut self, systems: &mut SystemHolder, index: Option<usize>) {
+        for (i, gfx) in self.warp_marker.iter().enumerate() {
+            systems.gfx.set_color(
+                gfx,
+                if index == Some(i) {
+                    Color::rgba(255, 160, 40, 160)
+                } else {
+                    Color::rgba(255, 160, 40, 60)
+                },
+            );
+        }
+    }
+
+    pub fn set_warp_marker_visible(&mut self, systems: &mut SystemHolder, visible: bool) {
+        for gfx in self.warp_marker.iter() {
+            systems.gfx.set_visible(gfx, visible);
+        }
+    }
+
     pub fn hover_tile(&mut self, systems: &mut SystemHolder, mouse_pos: Vec2) {
         if self.drag.in_hold {
             return;
@@ -380,6 +607,67 @@ impl MapView {
         }
     }
 
+    pub fn start_selection(&mut self, systems: &mut SystemHolder) {
+        self.selection.in_hold = true;
+        self.selection.active = true;
+        self.selection.start_pos = self.tile.cur_pos;
+        self.selection.end_pos = self.tile.cur_pos;
+        self.update_selection_visual(systems);
+        systems.gfx.set_visible(&self.selection.gfx, true);
+    }
+
+    pub fn update_selection(&mut self, systems: &mut SystemHolder) {
+        if !self.selection.in_hold || self.selection.end_pos == self.tile.cur_pos {
+            return;
+        }
+
+        self.selection.end_pos = self.tile.cur_pos;
+        self.update_selection_visual(systems);
+    }
+
+    pub fn clear_selection(&mut self, systems: &mut SystemHolder) {
+        self.selection.in_hold = false;
+        self.selection.active = false;
+        systems.gfx.set_visible(&self.selection.gfx, false);
+    }
+
+    // Returns the lowest and highest tile of the selection
+    pub fn get_selection_area(&self) -> Option<(Vec2, Vec2)> {
+        if !self.selection.active {
+            return None;
+        }
+
+        Some((
+            self.selection.start_pos.min(self.selection.end_pos),
+            self.selection.start_pos.max(self.selection.end_pos),
+        ))
+    }
+
+    pub fn in_selection(&self, tile_pos: Vec2) -> bool {
+        if let Some((min, max)) = self.get_selection_area() {
+            tile_pos.x >= min.x && tile_pos.x <= max.x && tile_pos.y >= min.y && tile_pos.y <= max.y
+        } else {
+            false
+        }
+    }
+
+    fn update_selection_visual(&mut self, systems: &mut SystemHolder) {
+        if let Some((min, max)) = self.get_selection_area() {
+            systems.gfx.set_pos(
+                &self.selection.gfx,
+                Vec3::new(
+                    self.map.pos.x + (min.x * TEXTURE_SIZE as f32),
+                    self.map.pos.y + (min.y * TEXTURE_SIZE as f32),
+                    ORDER_TILE_SELECT,
+                ),
+            );
+            systems.gfx.set_size(
+                &self.selection.gfx,
+                (max - min + Vec2::new(1.0, 1.0)) * TEXTURE_SIZE as f32,
+            );
+        }
+    }
+
     pub fn update_tile_frame(&mut self, systems: &mut SystemHolder) {
         self.tile.frame += 1;
         if self.tile.frame > 3 {
@@ -410,6 +698,33 @@ impl MapView {
         difference
     }
 
+    pub fn center_on_tile(
+        &mut self,
+        systems: &mut SystemHolder,
+        graphics: &mut Graphics<FlatControls>,
+        tile_pos: Vec2,
+    ) {
+        let view_pos = (Vec2::new(254.0, 0.0) * systems.scale as f32).floor();
+        let view_size = Vec2::new(
+            systems.size.width - (254.0 * systems.scale as f32).floor(),
+            systems.size.height - (20.0 * systems.scale as f32).floor(),
+        );
+        let tile_size = (TEXTURE_SIZE as f32 * systems.config.zoom).round();
+        let start_pos = (self.map.pos * systems.config.zoom).round();
+
+        let difference = (view_pos + (view_size * 0.5)
+            - start_pos
+            - ((tile_pos + Vec2::new(0.5, 0.5)) * tile_size))
+            .floor();
+
+        let input = graphics.system.controls_mut().inputs_mut();
+        input.translation.x = difference.x;
+        input.translation.y = difference.y;
+
+        self.camera_pos = difference;
+        self.last_camera_pos = difference;
+    }
+
     pub fn adjust_map_by_zoom(
         &mut self,
         systems: &mut SystemHolder,
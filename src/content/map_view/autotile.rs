@@ -1,9 +1,9 @@
 use ascending_graphics::*;
 
 use crate::{
-    content::{Content, get_tile_pos},
+    content::{Content, get_map_tile, is_layer_locked, set_map_tile},
     data_types::{TEXTURE_SIZE, TILESET_COUNT_Y},
-    database::{PresetFrames, PresetTypeList},
+    database::{AutotileLookup, PresetData, PresetFrames, PresetTypeList},
     renderer::SystemHolder,
     resource::TilePos,
 };
@@ -22,104 +22,293 @@ pub const AUTO_CUR: (u32, u32) = (4, 2); // Corner Up Right
 pub const AUTO_CDL: (u32, u32) = (3, 1); // Corner Down Left
 pub const AUTO_CDR: (u32, u32) = (4, 1); // Corner Down Right
 
-pub fn check_match_direction(content: &Content, map_pos: Vec2, layer: usize) -> [bool; 8] {
-    let pos = (map_pos.x as u32, map_pos.y as u32);
+// Neighbour offsets in the order TL, T, TR, L, R, DL, D, DR
+pub const AUTO_DIR: [(i32, i32); 8] = [
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+    (-1, 0),
+    (1, 0),
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+];
 
-    let top = if pos.1 < 31 {
-        is_tile_same(content, Vec2::new(pos.0 as f32, (pos.1 + 1) as f32), layer)
-    } else {
-        true
-    };
-    let left = if pos.0 > 0 {
-        is_tile_same(content, Vec2::new((pos.0 - 1) as f32, pos.1 as f32), layer)
-    } else {
-        true
-    };
-    let right = if pos.0 < 31 {
-        is_tile_same(content, Vec2::new((pos.0 + 1) as f32, pos.1 as f32), layer)
-    } else {
-        true
-    };
-    let down = if pos.1 > 0 {
-        is_tile_same(content, Vec2::new(pos.0 as f32, (pos.1 - 1) as f32), layer)
-    } else {
-        true
-    };
-    let top_right = if pos.0 < 31 && pos.1 < 31 {
-        is_tile_same(
-            content,
-            Vec2::new((pos.0 + 1) as f32, (pos.1 + 1) as f32),
-            layer,
-        )
-    } else {
-        true
-    };
-    let top_left = if pos.0 > 0 && pos.1 < 31 {
-        is_tile_same(
-            content,
-            Vec2::new((pos.0 - 1) as f32, (pos.1 + 1) as f32),
-            layer,
-        )
-    } else {
-        true
-    };
-    let down_left = if pos.0 > 0 && pos.1 > 0 {
-        is_tile_same(
-            content,
-            Vec2::new((pos.0 - 1) as f32, (pos.1 - 1) as f32),
-            layer,
-        )
-    } else {
-        true
-    };
-    let down_right = if pos.0 < 31 && pos.1 > 0 {
-        is_tile_same(
-            content,
-            Vec2::new((pos.0 + 1) as f32, (pos.1 - 1) as f32),
-            layer,
-        )
+// The 47 blob pieces as read from the tile sheet, left to right and top row
+// first with 8 pieces on each row, the last cell of the bottom row stays empty.
+// Each piece is the neighbour mask it connects to, bits in the order
+// TL, T, TR, L, R, DL, D, DR from the lowest bit. Pieces are grouped by the
+// sides they connect to, then by their inner corners.
+// images/blob_autotile_template.png shows the layout for artists to follow.
+pub const BLOB_COLUMN: u32 = 8;
+pub const BLOB_ROW: u32 = 6;
+pub const BLOB_LAYOUT: [u8; 47] = [
+    0b00000000, 0b00000010, 0b00010000, 0b00010010, 0b00010110, 0b01000000, 0b01000010, 0b01010000,
+    0b11010000, 0b01010010, 0b01010110, 0b11010010, 0b11010110, 0b00001000, 0b00001010, 0b00001011,
+    0b00011000, 0b00011010, 0b00011110, 0b00011011, 0b00011111, 0b01001000, 0b01101000, 0b01001010,
+    0b01101010, 0b01001011, 0b01101011, 0b01011000, 0b11011000, 0b01111000, 0b11111000, 0b01011010,
+    0b01011110, 0b11011010, 0b11011110, 0b01111010, 0b01111110, 0b11111010, 0b11111110, 0b01011011,
+    0b01011111, 0b11011011, 0b11011111, 0b01111011, 0b01111111, 0b11111011, 0b11111111,
+];
+
+// First cell and count of the empty cells on the bottom row of an autotile sheet area
+pub fn get_autotile_unused_cell(draw_type: PresetTypeList) -> Option<(u32, u32)> {
+    match draw_type {
+        PresetTypeList::AutoTile | PresetTypeList::AutotileAnimated => Some((3, 2)),
+        PresetTypeList::BlobTile | PresetTypeList::BlobTileAnimated => {
+            Some((BLOB_LAYOUT.len() as u32 % BLOB_COLUMN, 1))
+        }
+        _ => None,
+    }
+}
+
+pub fn is_autotile_unused_cell(draw_type: PresetTypeList, x: u32, y: u32) -> bool {
+    get_autotile_unused_cell(draw_type).is_some_and(|(start, _)| y == 0 && x >= start)
+}
+
+fn get_autotile_sheet_pos(key: [bool; 8]) -> (u32, u32) {
+    // TL, T, TR, L, R, DL, D, DR
+    match key {
+        [_, false, _, false, true, _, true, _] => AUTO_UL,
+        [_, false, _, true, true, _, true, _] => AUTO_U,
+        [_, false, _, true, false, _, true, _] => AUTO_UR,
+        [_, true, _, false, true, _, true, _] => AUTO_L,
+        [_, true, _, true, false, _, true, _] => AUTO_R,
+        [_, true, _, false, true, _, false, _] => AUTO_DL,
+        [_, true, _, true, true, _, false, _] => AUTO_D,
+        [_, true, _, true, false, _, false, _] => AUTO_DR,
+        [true, true, _, true, true, _, true, false] => AUTO_CUL,
+        [_, true, true, true, true, false, true, _] => AUTO_CUR,
+        [_, true, false, true, true, true, true, _] => AUTO_CDL,
+        [false, true, _, true, true, _, true, true] => AUTO_CDR,
+        _ => AUTO_C,
+    }
+}
+
+fn get_blob_sheet_pos(key: [bool; 8]) -> (u32, u32) {
+    let mut mask = key.iter().enumerate().fold(
+        0u8,
+        |mask, (i, &same)| if same { mask | (1 << i) } else { mask },
+    );
+
+    // A corner only counts when both of the sides next to it connect
+    for (corner, side_a, side_b) in [(0, 1, 3), (2, 1, 4), (5, 6, 3), (7, 6, 4)] {
+        if !(key[side_a] && key[side_b]) {
+            mask &= !(1 << corner);
+        }
+    }
+
+    let index = BLOB_LAYOUT
+        .iter()
+        .position(|&piece| piece == mask)
+        .unwrap_or(BLOB_LAYOUT.len() - 1) as u32;
+
+    // Sheet rows count upward while the layout is read from the top row
+    (index % BLOB_COLUMN, BLOB_ROW - 1 - (index / BLOB_COLUMN))
+}
+
+pub fn check_match_direction(
+    content: &Content,
+    map_pos: Vec2,
+    layer: usize,
+    tiles: &[usize],
+) -> [bool; 8] {
+    // Tiles that can't be reached count as the same tile so the edge stays open
+    AUTO_DIR.map(|(x, y)| {
+        let pos = Vec2::new(map_pos.x + x as f32, map_pos.y + y as f32);
+        get_map_tile(content, pos.x as i32, pos.y as i32, layer).is_none()
+            || is_tile_same(content, pos, layer, tiles)
+    })
+}
+
+pub fn is_tile_same(content: &Content, map_pos: Vec2, layer: usize, tiles: &[usize]) -> bool {
+    get_map_tile(content, map_pos.x as i32, map_pos.y as i32, layer)
+        .is_some_and(|tile| tiles.contains(&tile))
+}
+
+// Every tile a preset frame is made of, autotiles leave out their empty cells
+pub fn get_preset_frame_tiles(
+    systems: &SystemHolder,
+    draw_type: PresetTypeList,
+    frame: &PresetFrames,
+) -> Vec<usize> {
+    let (size_x, size_y) = draw_type.autotile_size().unwrap_or((
+        frame.start.x.abs_diff(frame.end.x) as u32 + 1,
+        frame.start.y.abs_diff(frame.end.y) as u32 + 1,
+    ));
+    let tilesheet_pos = (
+        frame.start.x.min(frame.end.x) as u32,
+        TILESET_COUNT_Y.saturating_sub(1) - frame.start.y.min(frame.end.y) as u32,
+    );
+
+    let mut tiles = Vec::with_capacity((size_x * size_y) as usize);
+    for x in 0..size_x {
+        for y in 0..size_y {
+            if is_autotile_unused_cell(draw_type, x, y) {
+                continue;
+            }
+
+            if let Some(id) = systems
+                .resource
+                .tile_pos_loc
+                .get(&TilePos {
+                    x: (tilesheet_pos.0 + x) * TEXTURE_SIZE,
+                    y: (tilesheet_pos.1 - y) * TEXTURE_SIZE,
+                    file: frame.tileset as u32,
+                })
+                .copied()
+            {
+                tiles.push(id);
+            }
+        }
+    }
+    tiles
+}
+
+pub fn get_preset_tiles(systems: &SystemHolder, preset: &PresetData) -> Vec<usize> {
+    let frame_count = if preset.draw_type.is_animated() { 4 } else { 1 };
+    preset.frames[..frame_count]
+        .iter()
+        .flat_map(|frame| get_preset_frame_tiles(systems, preset.draw_type, frame))
+        .collect()
+}
+
+fn build_autotile_lookup(content: &Content, systems: &SystemHolder) -> AutotileLookup {
+    let mut lookup = AutotileLookup::default();
+
+    for (index, preset) in content.preset.data.iter().enumerate() {
+        if !preset.draw_type.is_autotile() {
+            lookup.preset_tiles.push(Vec::new());
+            continue;
+        }
+
+        let frame_count = if preset.draw_type.is_animated() { 4 } else { 1 };
+        for (frame_index, frame) in preset.frames[..frame_count].iter().enumerate() {
+            for tile in get_preset_frame_tiles(systems, preset.draw_type, frame) {
+                // The first preset holding the tile wins, like the search it replaces
+                lookup.tiles.entry(tile).or_insert((index, frame_index));
+            }
+        }
+        lookup.preset_tiles.push(get_preset_tiles(systems, preset));
+    }
+    lookup
+}
+
+// Finds the autotile preset a placed tile was taken from, along with its frame
+fn find_autotile_preset(
+    content: &mut Content,
+    systems: &SystemHolder,
+    tile: usize,
+) -> Option<(PresetTypeList, PresetFrames, Vec<usize>)> {
+    if content.preset.autotile_lookup.is_none() {
+        content.preset.autotile_lookup = Some(build_autotile_lookup(content, systems));
+    }
+
+    let lookup = content.preset.autotile_lookup.as_ref()?;
+    let (index, frame) = *lookup.tiles.get(&tile)?;
+    let preset = content.preset.data.get(index)?;
+    Some((
+        preset.draw_type,
+        preset.frames[frame],
+        lookup.preset_tiles[index].clone(),
+    ))
+}
+
+// Tile of the frame that fits the surrounding tiles
+pub fn get_autotile_piece(
+    systems: &SystemHolder,
+    draw_type: PresetTypeList,
+    frame: &PresetFrames,
+    key: [bool; 8],
+) -> Option<usize> {
+    let start_pos = Vec2::new(frame.start.x as f32, frame.start.y as f32);
+    let end_pos = Vec2::new(frame.end.x as f32, frame.end.y as f32);
+    let tilesheet_pos = Vec2::new(
+        start_pos.x.min(end_pos.x),
+        TILESET_COUNT_Y.saturating_sub(1) as f32 - start_pos.y.min(end_pos.y),
+    );
+
+    let sheet_pos = if matches!(
+        draw_type,
+        PresetTypeList::BlobTile | PresetTypeList::BlobTileAnimated
+    ) {
+        get_blob_sheet_pos(key)
     } else {
-        true
+        get_autotile_sheet_pos(key)
     };
 
-    [
-        top_left, top, top_right, left, right, down_left, down, down_right,
-    ]
+    systems
+        .resource
+        .tile_pos_loc
+        .get(&TilePos {
+            x: (tilesheet_pos.x as u32 + sheet_pos.0) * TEXTURE_SIZE,
+            y: (tilesheet_pos.y as u32 - sheet_pos.1) * TEXTURE_SIZE,
+            file: frame.tileset as u32,
+        })
+        .copied()
 }
 
-pub fn is_tile_same(content: &Content, map_pos: Vec2, layer: usize) -> bool {
-    let tile = content
-        .map_view
-        .map
-        .get_tile(UVec3::new(map_pos.x as u32, map_pos.y as u32, layer as u32))
-        .id;
-    content.preset.selected_preset_tiles.contains(&tile)
+fn set_autotile_piece(
+    content: &mut Content,
+    systems: &SystemHolder,
+    pos: Vec2,
+    layer: usize,
+    draw_type: PresetTypeList,
+    frame: &PresetFrames,
+    tiles: &[usize],
+) {
+    let key = check_match_direction(content, pos, layer, tiles);
+
+    if let Some(id) = get_autotile_piece(systems, draw_type, frame, key)
+        && get_map_tile(content, pos.x as i32, pos.y as i32, layer) != Some(id)
+    {
+        set_map_tile(content, systems, pos.x as i32, pos.y as i32, layer, id);
+    }
 }
 
-pub fn place_autotile(
+// Autotiles around a changed tile get their piece picked again, this also
+// reaches into the linked maps so the borders between maps stay seamless
+pub fn refresh_autotile_around(
     content: &mut Content,
-    systems: &mut SystemHolder,
-    animated: bool,
+    systems: &SystemHolder,
     map_pos: Vec2,
-    cur_layer: u32,
-    frames: [PresetFrames; 4],
+    layer: usize,
 ) {
-    let mut check_pos = Vec::with_capacity(9);
-    check_pos.push(map_pos);
+    if is_layer_locked(systems, layer) {
+        return;
+    }
+
     for x in -1..=1 {
         for y in -1..=1 {
-            let newpos = map_pos + Vec2::new(x as f32, y as f32);
-            if newpos == map_pos
-                || newpos.x < 0.0
-                || newpos.y < 0.0
-                || newpos.x > 31.0
-                || newpos.y > 31.0
-            {
+            let pos = map_pos + Vec2::new(x as f32, y as f32);
+            if pos == map_pos {
                 continue;
             }
-            check_pos.push(newpos);
+
+            let Some(tile) = get_map_tile(content, pos.x as i32, pos.y as i32, layer) else {
+                continue;
+            };
+            if tile == 0 {
+                continue;
+            }
+
+            if let Some((draw_type, frame, tiles)) = find_autotile_preset(content, systems, tile) {
+                set_autotile_piece(content, systems, pos, layer, draw_type, &frame, &tiles);
+            }
         }
     }
+}
+
+pub fn place_autotile(
+    content: &mut Content,
+    systems: &mut SystemHolder,
+    draw_type: PresetTypeList,
+    map_pos: Vec2,
+    cur_layer: u32,
+    frames: [PresetFrames; 4],
+) {
+    let animated = draw_type.is_animated();
+    let tiles = content.preset.selected_preset_tiles.clone();
 
     for (i, frame) in frames.iter().enumerate() {
         let set_layer = if animated {
@@ -131,67 +320,14 @@ pub fn place_autotile(
             cur_layer as usize
         };
 
-        for &pos in check_pos.iter() {
-            if pos != map_pos && !is_tile_same(content, pos, set_layer) {
-                continue;
-            }
-
-            let start_pos = Vec2::new(frame.start.x as f32, frame.start.y as f32);
-            let end_pos = Vec2::new(frame.end.x as f32, frame.end.y as f32);
-            let tilesheet_pos = Vec2::new(
-                start_pos.x.min(end_pos.x),
-                TILESET_COUNT_Y.saturating_sub(1) as f32 - start_pos.y.min(end_pos.y),
-            );
-
-            let key = check_match_direction(content, pos, set_layer);
-            // TL, T, TR, L, R, DL, D, DR
-            let sheet_pos = match key {
-                [_, false, _, false, true, _, true, _] => AUTO_UL,
-                [_, false, _, true, true, _, true, _] => AUTO_U,
-                [_, false, _, true, false, _, true, _] => AUTO_UR,
-                [_, true, _, false, true, _, true, _] => AUTO_L,
-                [_, true, _, true, false, _, true, _] => AUTO_R,
-                [_, true, _, false, true, _, false, _] => AUTO_DL,
-                [_, true, _, true, true, _, false, _] => AUTO_D,
-                [_, true, _, true, false, _, false, _] => AUTO_DR,
-                [true, true, _, true, true, _, true, false] => AUTO_CUL,
-                [_, true, true, true, true, false, true, _] => AUTO_CUR,
-                [_, true, false, true, true, true, true, _] => AUTO_CDL,
-                [false, true, _, true, true, _, true, true] => AUTO_CDR,
-                _ => AUTO_C,
-            };
-
-            let tile_id = systems
-                .resource
-                .tile_pos_loc
-                .get(&TilePos {
-                    x: (tilesheet_pos.x as u32 + sheet_pos.0) * TEXTURE_SIZE,
-                    y: (tilesheet_pos.y as u32 - sheet_pos.1) * TEXTURE_SIZE,
-                    file: frame.tileset as u32,
-                })
-                .copied();
-
-            if let Some(id) = tile_id {
-                let t_pos = UVec3::new(pos.x as u32, pos.y as u32, set_layer as u32);
-
-                let cur_id = content.map_view.map.get_tile(t_pos);
-                content
-                    .data
-                    .record_tile(t_pos.x as u16, t_pos.y as u16, set_layer, cur_id.id, id);
-
-                content.map_view.map.set_tile(
-                    t_pos,
-                    TileData {
-                        id,
-                        color: Color::rgba(255, 255, 255, 255),
-                        anim_time: 250,
-                    },
-                );
-
-                let tile_pos = get_tile_pos(t_pos.x as i32, t_pos.y as i32);
-                content.data.mapdata.tile[set_layer].id[tile_pos] = id as u32;
-            }
+        if is_layer_locked(systems, set_layer) {
+            continue;
         }
+
+        set_autotile_piece(
+            content, systems, map_pos, set_layer, draw_type, frame, &tiles,
+        );
+        refresh_autotile_around(content, systems, map_pos, set_layer);
     }
 
     content.data.changed = true;
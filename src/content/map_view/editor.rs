@@ -1,17 +1,24 @@
+use std::collections::HashSet;
+
+use ascending_camera::controls::FlatControls;
 use ascending_graphics::*;
 use bit_op::{BitOp, bit_u8::*};
+use indexmap::IndexSet;
 
 use crate::{
     content::{
-        Content, place_autotile, switch_attributes, switch_tab,
-        widget::{in_layer_area, in_view_screen},
+        AUTO_DIR, AnimTimeChangeData, CollisionChangeData, CollisionChanges, Content, EditorChange,
+        LinkEdit, LinkMapData, LinkedMap, clear_measure, place_autotile, refresh_autotile_around,
+        switch_attributes, switch_tab,
+        widget::{Alert, AlertBuilder, AlertIndex, in_layer_area, in_view_screen},
     },
     data_types::*,
     database::{
         EditorMapAttribute, ItemSpawnData, MapAttribute, MapData, MapPosition, PresetTypeList,
-        WarpData, delete_temp_map_file, is_temp_map_exist, load_map_file, save_temp_file,
+        TileCollision, WarpData, delete_temp_map_file, is_map_exist, is_temp_map_exist,
+        load_map_file, load_temp_map_file, save_temp_file,
     },
-    renderer::SystemHolder,
+    renderer::{Graphics, SystemHolder},
     resource::TilePos,
 };
 
@@ -64,12 +71,258 @@ pub fn get_link_map_pos(map_pos: MapPosition, id: usize) -> MapPosition {
     }
 }
 
-pub fn apply_link_map(content: &mut Content, map_pos: MapPosition) {
+// Tile offset of the linked map from the current map
+pub fn get_link_offset(link: usize) -> (i32, i32) {
+    let pos = get_link_map_pos(
+        MapPosition {
+            x: 0,
+            y: 0,
+            group: 0,
+        },
+        link,
+    );
+    (pos.x * 32, pos.y * 32)
+}
+
+// Converts a tile position that can go past the map border into
+// the linked map index and the tile position inside of that map
+pub fn get_link_tile_pos(x: i32, y: i32) -> Option<(Option<usize>, u32, u32)> {
+    let link = match (x.div_euclid(32), y.div_euclid(32)) {
+        (0, 0) => None,
+        (-1, 1) => Some(0),
+        (0, 1) => Some(1),
+        (1, 1) => Some(2),
+        (-1, 0) => Some(3),
+        (1, 0) => Some(4),
+        (-1, -1) => Some(5),
+        (0, -1) => Some(6),
+        (1, -1) => Some(7),
+        _ => return None,
+    };
+
+    Some((link, x.rem_euclid(32) as u32, y.rem_euclid(32) as u32))
+}
+
+pub fn get_map_tile(content: &Content, x: i32, y: i32, layer: usize) -> Option<usize> {
+    let (link, tile_x, tile_y) = get_link_tile_pos(x, y)?;
+    let tile_pos = get_tile_pos(tile_x as i32, tile_y as i32);
+
+    let mapdata = match link {
+        Some(link) => &content.data.link_map.get(link)?.as_ref()?.mapdata,
+        None => &content.data.mapdata,
+    };
+
+    Some(mapdata.tile[layer].id[tile_pos] as usize)
+}
+
+// Sets the tile on the current map or on the linked map it belongs to,
+// returns false if the position can't be edited
+pub fn set_map_tile(
+    content: &mut Content,
+    systems: &SystemHolder,
+    x: i32,
+    y: i32,
+    layer: usize,
+    id: usize,
+) -> bool {
+    let Some(from_id) = get_map_tile(content, x, y, layer) else {
+        return false;
+    };
+    let Some((link, tile_x, tile_y)) = get_link_tile_pos(x, y) else {
+        return false;
+    };
+
+    if let Some(link) = link {
+        content
+            .data
+            .record_link_tile(link, tile_x as u16, tile_y as u16, layer, from_id, id);
+        set_link_tile(content, systems, link, (tile_x, tile_y), layer, id);
+        return true;
+    }
+
+    content
+        .data
+        .record_tile(tile_x as u16, tile_y as u16, layer, from_id, id);
+
+    let cur_layer = convert_layer_button_to_index(content.interface.tool.cur_layer);
+    let tile_pos = get_tile_pos(tile_x as i32, tile_y as i32);
+    content.map_view.map.set_tile(
+        UVec3::new(tile_x, tile_y, layer as u32),
+        if id > 0 {
+            TileData {
+                id,
+                color: get_layer_color(systems, layer, cur_layer),
+                anim_time: content.data.mapdata.anim_time[tile_pos],
+            }
+        } else {
+            TileData::default()
+        },
+    );
+
+    content.data.mapdata.tile[layer].id[tile_pos] = id as u32;
+    true
+}
+
+pub fn set_link_tile(
+    content: &mut Content,
+    systems: &SystemHolder,
+    link: usize,
+    pos: (u32, u32),
+    layer: usize,
+    id: usize,
+) {
+    let Some(Some(link_data)) = content.data.link_map.get_mut(link) else {
+        return;
+    };
+
+    let tile_pos = get_tile_pos(pos.0 as i32, pos.1 as i32);
+    link_data.mapdata.tile[layer].id[tile_pos] = id as u32;
+    link_data.changed = true;
+    let _ = link_data.edits.insert(LinkEdit::Tile(layer, tile_pos));
+
+    content.map_view.linked_map[link].map.set_tile(
+        UVec3::new(pos.0, pos.1, layer as u32),
+        if id > 0 {
+            TileData {
+                id,
+                color: get_layer_color(systems, layer, layer),
+                anim_time: link_data.mapdata.anim_time[tile_pos],
+            }
+        } else {
+            TileData::default()
+        },
+    );
+}
+
+// Keeps the edits done on the linked maps in their temp files until they get saved
+pub fn save_link_map_temp(content: &mut Content) -> Result<()> {
+    for link_data in content.data.link_map.iter_mut().flatten() {
+        if !link_data.changed {
+            continue;
+        }
+
+        let pos = link_data.pos;
+        save_temp_file(pos.x, pos.y, pos.group as u64, &link_data.mapdata, true)?;
+        link_data.changed = false;
+
+        let _ = content.data.unsaved_map.insert(pos);
+    }
+    Ok(())
+}
+
+// Only the edits made from the current map get saved, other unsaved edits of the
+// linked map stay in its temp file
+fn save_link_map(content: &mut Content) -> Result<()> {
+    for link_data in content.data.link_map.iter_mut().flatten() {
+        if link_data.edits.is_empty() {
+            continue;
+        }
+
+        let pos = link_data.pos;
+        let mut mapdata = load_map_file(pos.x, pos.y, pos.group as u64, false)?;
+        for edit in link_data.edits.iter() {
+            match *edit {
+                LinkEdit::Tile(layer, tile_pos) => {
+                    mapdata.tile[layer].id[tile_pos] = link_data.mapdata.tile[layer].id[tile_pos];
+                }
+                LinkEdit::Attribute(tile_pos) => {
+                    mapdata.attribute[tile_pos].clone_from(&link_data.mapdata.attribute[tile_pos]);
+                }
+            }
+        }
+        mapdata.position = pos;
+        mapdata.save_file()?;
+        link_data.edits.clear();
+
+        if !link_data.from_temp {
+            if is_temp_map_exist(pos.x, pos.y, pos.group as u64) {
+                delete_temp_map_file(pos.x, pos.y, pos.group as u64)?;
+            }
+            link_data.changed = false;
+            let _ = content.data.unsaved_map.swap_remove(&pos);
+        }
+    }
+    Ok(())
+}
+
+pub fn get_layer_color(systems: &SystemHolder, layer: usize, cur_layer: usize) -> Color {
+    if !systems.config.layer_visible[layer] {
+        Color::rgba(255, 255, 255, 0)
+    } else if systems.config.dim_layers && layer != cur_layer {
+        Color::rgba(255, 255, 255, systems.config.dim_opacity)
+    } else {
+        Color::rgba(255, 255, 255, 255)
+    }
+}
+
+pub fn is_layer_locked(systems: &SystemHolder, layer: usize) -> bool {
+    systems.config.layer_locked[layer]
+}
+
+pub fn update_layer_visual(content: &mut Content, systems: &mut SystemHolder) {
+    let cur_layer = convert_layer_button_to_index(content.interface.tool.cur_layer);
+
+    for layer in 0..MapLayers::Count as usize {
+        let color = get_layer_color(systems, layer, cur_layer);
+        // Linked maps are already shaded, so they only follow the visibility
+        let link_color = get_layer_color(systems, layer, layer);
+
+        (0..32).for_each(|x| {
+            (0..32).for_each(|y| {
+                let pos = UVec3::new(x, y, layer as u32);
+
+                let tile = content.map_view.map.get_tile(pos);
+                if tile.id > 0 {
+                    content
+                        .map_view
+                        .map
+                        .set_tile(pos, TileData { color, ..tile });
+                }
+
+                for map in content.map_view.linked_map.iter_mut() {
+                    let tile = map.map.get_tile(pos);
+                    if tile.id > 0 {
+                        map.map.set_tile(
+                            pos,
+                            TileData {
+                                color: link_color,
+                                ..tile
+                            },
+                        );
+                    }
+                }
+            })
+        });
+    }
+}
+
+pub fn apply_link_map(
+    content: &mut Content,
+    systems: &SystemHolder,
+    map_pos: MapPosition,
+) -> Result<()> {
+    save_link_map_temp(content)?;
+    content.data.link_map.clear();
+
     for (id, map) in content.map_view.linked_map.iter_mut().enumerate() {
         let check_pos = get_link_map_pos(map_pos, id);
+        let from_temp = is_temp_map_exist(check_pos.x, check_pos.y, check_pos.group as u64);
 
-        if let Ok(mapdata) = load_map_file(check_pos.x, check_pos.y, check_pos.group as u64, false)
-        {
+        // Missing maps are left empty and can't be painted into
+        if !from_temp && !is_map_exist(check_pos.x, check_pos.y, check_pos.group as u64) {
+            clear_link_map_view(map);
+            content.data.link_map.push(None);
+            continue;
+        }
+
+        // Unsaved edits of the linked map are kept in its temp file
+        let mapdata = if from_temp {
+            load_temp_map_file(check_pos.x, check_pos.y, check_pos.group as u64)
+        } else {
+            load_map_file(check_pos.x, check_pos.y, check_pos.group as u64, false)
+        };
+
+        if let Ok(mapdata) = mapdata {
             (0..32).for_each(|x| {
                 (0..32).for_each(|y| {
                     let tile_num = get_tile_pos(x, y);
@@ -82,8 +335,8 @@ pub fn apply_link_map(content: &mut Content, map_pos: MapPosition) {
                             if id > 0 {
                                 TileData {
                                     id,
-                                    color: Color::rgba(255, 255, 255, 255),
-                                    anim_time: 250,
+                                    color: get_layer_color(systems, i, i),
+                                    anim_time: mapdata.anim_time[tile_num],
                                 }
                             } else {
                                 TileData::default()
@@ -91,14 +344,38 @@ pub fn apply_link_map(content: &mut Content, map_pos: MapPosition) {
                         );
                     });
                 })
-            })
+            });
+
+            content.data.link_map.push(Some(LinkMapData {
+                pos: check_pos,
+                mapdata,
+                changed: false,
+                edits: IndexSet::default(),
+                from_temp,
+            }));
+        } else {
+            clear_link_map_view(map);
+            content.data.link_map.push(None);
         }
     }
+
+    Ok(())
+}
+
+pub fn clear_link_map_view(map: &mut LinkedMap) {
+    (0..32).for_each(|x| {
+        (0..32).for_each(|y| {
+            (0..9).for_each(|i| {
+                map.map.set_tile(UVec3::new(x, y, i), TileData::default());
+            });
+        })
+    });
 }
 
 pub fn apply_map_data(content: &mut Content, systems: &mut SystemHolder, mapdata: &MapData) {
     let tile_size = Vec2::new(TEXTURE_SIZE as f32, TEXTURE_SIZE as f32);
     let attr_zoom_pos = Vec2::new(content.map_view.map.pos.x, content.map_view.map.pos.y);
+    let cur_layer = convert_layer_button_to_index(content.interface.tool.cur_layer);
 
     (0..32).for_each(|x| {
         (0..32).for_each(|y| {
@@ -112,8 +389,8 @@ pub fn apply_map_data(content: &mut Content, systems: &mut SystemHolder, mapdata
                     if id > 0 {
                         TileData {
                             id,
-                            color: Color::rgba(255, 255, 255, 255),
-                            anim_time: 250,
+                            color: get_layer_color(systems, i, cur_layer),
+                            anim_time: mapdata.anim_time[tile_num],
                         }
                     } else {
                         TileData::default()
@@ -180,42 +457,17 @@ pub fn apply_map_data(content: &mut Content, systems: &mut SystemHolder, mapdata
         });
     });
 
-    let cur_zone = content.interface.side_window.zone.cur_zone;
-
-    let zone_data = mapdata.zones[cur_zone];
-
-    content.interface.side_window.zone.textbox[0].set_text(systems, format!("{}", zone_data.0));
-
-    for i in 0..5 {
-        content.interface.side_window.zone.textbox[i + 1].set_text(
-            systems,
-            if let Some(data) = zone_data.1[i] {
-                format!("{data}")
-            } else {
-                String::new()
-            },
-        );
-    }
-
-    for gfx in content.map_view.zones.iter() {
-        systems.gfx.set_color(gfx, Color::rgba(0, 0, 0, 0));
-    }
-
-    for zones in mapdata.zonespawns[cur_zone].iter() {
-        let tile_num = get_tile_pos(zones.0 as i32, zones.1 as i32);
-        let gfx = content.map_view.zones[tile_num];
-
-        let pos = Vec2::new((tile_num % 32) as f32, (tile_num / 32) as f32);
-        let tile_pos = Vec2::new(
-            attr_zoom_pos.x + (tile_size.x * pos.x),
-            attr_zoom_pos.y + (tile_size.y * pos.y),
-        );
-
-        systems
-            .gfx
-            .set_pos(&gfx, Vec3::new(tile_pos.x, tile_pos.y, ORDER_TILE_BG));
-        systems.gfx.set_color(&gfx, Color::rgba(0, 0, 100, 150));
-    }
+    let zone = &mut content.interface.side_window.zone;
+    zone.cur_npc = None;
+    zone.update_zone_list(systems, &mapdata.zones);
+    zone.update_zone_data(systems, &mapdata.zones);
+    set_zone_visual(
+        content,
+        systems,
+        &mapdata.zonespawns,
+        content.interface.side_window.zone.cur_zone,
+        content.interface.side_window.zone.is_overview(),
+    );
 
     content
         .interface
@@ -246,43 +498,237 @@ pub fn apply_map_data(content: &mut Content, systems: &mut SystemHolder, mapdata
         .set_select(systems, Some(music_index), true);
 }
 
+fn set_map_zone(
+    content: &mut Content,
+    systems: &mut SystemHolder,
+    data: (u16, u16),
+    zone: usize,
+    set: bool,
+) -> bool {
+    let Some(spawns) = content.data.mapdata.zonespawns.get_mut(zone) else {
+        return false;
+    };
+    if set {
+        if spawns.contains(&data) {
+            return false;
+        }
+        spawns.push(data);
+    } else if let Some(index) = spawns.iter().position(|check| *check == data) {
+        spawns.remove(index);
+    } else {
+        return false;
+    }
+    content.data.record_zonespawn(data.0, data.1, zone, set);
+
+    let tile_num = get_tile_pos(data.0 as i32, data.1 as i32);
+    let tile_pos =
+        content.map_view.map.pos + Vec2::new(data.0 as f32, data.1 as f32) * TEXTURE_SIZE as f32;
+
+    // Without the overview only the current zone is shown
+    let gui = &content.interface.side_window.zone;
+    let shown_zone = if content
+        .data
+        .mapdata
+        .zonespawns
+        .get(gui.cur_zone)
+        .is_some_and(|spawns| spawns.contains(&data))
+    {
+        Some(gui.cur_zone)
+    } else if gui.is_overview() {
+        content
+            .data
+            .mapdata
+            .zonespawns
+            .iter()
+            .rposition(|spawns| spawns.contains(&data))
+    } else {
+        None
+    };
+
+    let gfx = content.map_view.zones[tile_num];
+    systems
+        .gfx
+        .set_pos(&gfx, Vec3::new(tile_pos.x, tile_pos.y, ORDER_TILE_BG));
+    systems.gfx.set_color(
+        &gfx,
+        shown_zone.map_or(Color::rgba(0, 0, 0, 0), |zone| {
+            ZONE_COLOR[zone % ZONE_COLOR.len()]
+        }),
+    );
+    true
+}
+
+pub fn is_rect_paint(content: &Content) -> bool {
+    let gui = &content.interface.side_window;
+    match gui.cur_tab {
+        TabButton::Zones => gui.zone.is_rect_paint(),
+        TabButton::DirBlock => gui.dirblocks.is_rect_paint(),
+        _ => false,
+    }
+}
+
+pub fn start_rect_paint(content: &mut Content, systems: &mut SystemHolder, set: bool) {
+    content.map_view.selection.rect_paint = Some(set);
+    content.map_view.start_selection(systems);
+}
+
+// The dragged rectangle is painted on release with the tab it was started on
+pub fn apply_rect_paint(content: &mut Content, systems: &mut SystemHolder) {
+    let Some(set) = content.map_view.selection.rect_paint.take() else {
+        return;
+    };
+    let area = content.map_view.get_selection_area();
+    content.map_view.clear_selection(systems);
+
+    let Some((min, max)) = area else {
+        return;
+    };
+
+    let cur_zone = content.interface.side_window.zone.cur_zone;
+    let dirblock = if set { get_dirblock_value(content) } else { 0 };
+    let cur_tab = content.interface.side_window.cur_tab;
+
+    let mut did_change = false;
+    for x in min.x as u16..=max.x as u16 {
+        for y in min.y as u16..=max.y as u16 {
+            did_change |= match cur_tab {
+                TabButton::Zones => set_map_zone(content, systems, (x, y), cur_zone, set),
+                TabButton::DirBlock => set_map_dirblock(content, systems, x, y, dirblock),
+                _ => false,
+            };
+        }
+    }
+    content.data.record_placeholder();
+
+    if !did_change {
+        return;
+    }
+
+    content.data.changed = true;
+    content.data.temp_saved = false;
+    if let Some(map_pos) = content.data.pos {
+        content
+            .interface
+            .footer
+            .set_map_pos(systems, map_pos, false);
+    }
+}
+
+// Spawns are filled up to blocked tiles, removing follows the spawns of the zone
+pub fn update_zone_fill(content: &mut Content, systems: &mut SystemHolder, set: bool) {
+    let map_pos = content.map_view.tile.cur_pos;
+    let cur_zone = content.interface.side_window.zone.cur_zone;
+    let Some(spawns) = content.data.mapdata.zonespawns.get(cur_zone) else {
+        return;
+    };
+
+    let fill_area = get_fill_area(content, map_pos, false, |pos| {
+        let check_pos = get_tile_pos(pos.x as i32, pos.y as i32);
+        spawns.contains(&(pos.x as u16, pos.y as u16)) != set
+            && (!set || content.data.mapdata.attribute[check_pos] != MapAttribute::Blocked)
+    });
+
+    if fill_area.is_empty() {
+        return;
+    }
+
+    for pos in fill_area {
+        set_map_zone(
+            content,
+            systems,
+            (pos.x as u16, pos.y as u16),
+            cur_zone,
+            set,
+        );
+    }
+
+    content.data.changed = true;
+    content.data.temp_saved = false;
+    if let Some(map_pos) = content.data.pos {
+        content
+            .interface
+            .footer
+            .set_map_pos(systems, map_pos, false);
+    }
+}
+
 pub fn update_zone_visible(content: &mut Content, systems: &mut SystemHolder) {
+    let gui = &content.interface.side_window.zone;
+    set_zone_visual(
+        content,
+        systems,
+        &content.data.mapdata.zonespawns,
+        gui.cur_zone,
+        gui.is_overview(),
+    );
+}
+
+pub fn update_zone_list(content: &mut Content, systems: &mut SystemHolder) {
+    content
+        .interface
+        .side_window
+        .zone
+        .update_zone_list(systems, &content.data.mapdata.zones);
+}
+
+// The overview draws every zone in its own colour, the current zone stays on top
+fn set_zone_visual(
+    content: &Content,
+    systems: &mut SystemHolder,
+    zonespawns: &[Vec<(u16, u16)>],
+    cur_zone: usize,
+    overview: bool,
+) {
     let tile_size = Vec2::new(TEXTURE_SIZE as f32, TEXTURE_SIZE as f32);
     let attr_zoom_pos = Vec2::new(content.map_view.map.pos.x, content.map_view.map.pos.y);
 
-    let cur_zone = content.interface.side_window.zone.cur_zone;
-
     for gfx in content.map_view.zones.iter() {
         systems.gfx.set_color(gfx, Color::rgba(0, 0, 0, 0));
     }
 
-    for zones in content.data.mapdata.zonespawns[cur_zone].iter() {
-        let tile_num = get_tile_pos(zones.0 as i32, zones.1 as i32);
-        let gfx = content.map_view.zones[tile_num];
+    let zone_order = (0..zonespawns.len())
+        .filter(|zone| overview && *zone != cur_zone)
+        .chain(std::iter::once(cur_zone).filter(|zone| *zone < zonespawns.len()));
 
-        let pos = Vec2::new((tile_num % 32) as f32, (tile_num / 32) as f32);
-        let tile_pos = Vec2::new(
-            attr_zoom_pos.x + (tile_size.x * pos.x),
-            attr_zoom_pos.y + (tile_size.y * pos.y),
-        );
+    for zone in zone_order {
+        for zones in zonespawns[zone].iter() {
+            let tile_num = get_tile_pos(zones.0 as i32, zones.1 as i32);
+            let gfx = content.map_view.zones[tile_num];
 
-        systems
-            .gfx
-            .set_pos(&gfx, Vec3::new(tile_pos.x, tile_pos.y, ORDER_TILE_BG));
-        systems.gfx.set_color(&gfx, Color::rgba(0, 0, 100, 150));
+            let pos = Vec2::new((tile_num % 32) as f32, (tile_num / 32) as f32);
+            let tile_pos = Vec2::new(
+                attr_zoom_pos.x + (tile_size.x * pos.x),
+                attr_zoom_pos.y + (tile_size.y * pos.y),
+            );
+
+            systems
+                .gfx
+                .set_pos(&gfx, Vec3::new(tile_pos.x, tile_pos.y, ORDER_TILE_BG));
+            systems
+                .gfx
+                .set_color(&gfx, ZONE_COLOR[zone % ZONE_COLOR.len()]);
+        }
     }
 }
 
+pub fn update_zone_textbox(content: &mut Content, systems: &mut SystemHolder) {
+    content
+        .interface
+        .side_window
+        .zone
+        .update_zone_data(systems, &content.data.mapdata.zones);
+}
+
 pub fn update_map_tile(content: &mut Content, systems: &mut SystemHolder, set: bool) {
     let start_pos = content.interface.side_window.tilesets.selection.start_pos;
     let end_pos = content.interface.side_window.tilesets.selection.end_pos;
 
     let map_pos = content.map_view.tile.cur_pos;
-    let cur_layer = match content.interface.tool.cur_layer {
-        1..=4 => content.interface.tool.cur_layer + 2,
-        5 | 6 => content.interface.tool.cur_layer - 4,
-        _ => content.interface.tool.cur_layer,
-    } as u32;
+    let cur_layer = convert_layer_button_to_index(content.interface.tool.cur_layer) as u32;
+
+    if is_layer_locked(systems, cur_layer as usize) {
+        return;
+    }
 
     let pos = Vec2::new(start_pos.x.min(end_pos.x), start_pos.y.min(end_pos.y));
 
@@ -299,64 +745,38 @@ pub fn update_map_tile(content: &mut Content, systems: &mut SystemHolder, set: b
 
     for x in 0..tile_size_x {
         for y in 0..tile_size_y {
+            let tilepos = TilePos {
+                x: (tilesheet_pos.x as u32 + x as u32) * TEXTURE_SIZE,
+                y: (tilesheet_pos.y as u32 - y as u32) * TEXTURE_SIZE,
+                file: content.interface.side_window.tilesets.cur_tileset as u32,
+            };
             let tile_id = if set {
-                systems
-                    .resource
-                    .tile_pos_loc
-                    .get(&TilePos {
-                        x: (tilesheet_pos.x as u32 + x as u32) * TEXTURE_SIZE,
-                        y: (tilesheet_pos.y as u32 - y as u32) * TEXTURE_SIZE,
-                        file: content.interface.side_window.tilesets.cur_tileset as u32,
-                    })
-                    .copied()
+                systems.resource.tile_pos_loc.get(&tilepos).copied()
             } else {
                 None
             };
 
-            if let Some(id) = tile_id {
-                let pos = UVec3::new(
-                    map_pos.x as u32 + x as u32,
-                    map_pos.y as u32 + y as u32,
-                    cur_layer,
-                );
-
-                let cur_id = content.map_view.map.get_tile(pos);
-                content.data.record_tile(
-                    pos.x as u16,
-                    pos.y as u16,
-                    cur_layer as usize,
-                    cur_id.id,
-                    id,
-                );
-
-                content.map_view.map.set_tile(
-                    pos,
-                    TileData {
-                        id,
-                        color: Color::rgba(255, 255, 255, 255),
-                        anim_time: 250,
-                    },
-                );
-
-                let tile_pos = get_tile_pos(pos.x as i32, pos.y as i32);
-                content.data.mapdata.tile[cur_layer as usize].id[tile_pos] = id as u32;
+            // Tiles past the map border are placed on the linked maps
+            let set_pos = if tile_id.is_some() {
+                map_pos + Vec2::new(x as f32, y as f32)
             } else {
-                let pos = UVec3::new(map_pos.x as u32, map_pos.y as u32, cur_layer);
-
-                let cur_id = content.map_view.map.get_tile(pos);
-                content.data.record_tile(
-                    pos.x as u16,
-                    pos.y as u16,
-                    cur_layer as usize,
-                    cur_id.id,
-                    0,
-                );
-
-                content.map_view.map.set_tile(pos, TileData::default());
-
-                let tile_pos = get_tile_pos(map_pos.x as i32, map_pos.y as i32);
-                content.data.mapdata.tile[cur_layer as usize].id[tile_pos] = 0;
+                map_pos
+            };
+            let prev_collision = get_prev_collision(content, systems, set_pos);
+
+            set_map_tile(
+                content,
+                systems,
+                set_pos.x as i32,
+                set_pos.y as i32,
+                cur_layer as usize,
+                tile_id.unwrap_or(0),
+            );
+            if tile_id.is_some() {
+                set_tile_anim_frames(content, systems, set_pos, &tilepos);
             }
+            refresh_autotile_around(content, systems, set_pos, cur_layer as usize);
+            update_tile_collision(content, systems, set_pos, prev_collision);
         }
     }
 
@@ -370,9 +790,51 @@ pub fn update_map_tile(content: &mut Content, systems: &mut SystemHolder, set: b
     }
 }
 
+// Tiles with anim frames in their tile sheet metadata also fill the anim layers,
+// frame i is the tile i columns to the right and goes on Anim (i + 1)
+fn set_tile_anim_frames(
+    content: &mut Content,
+    systems: &mut SystemHolder,
+    set_pos: Vec2,
+    tilepos: &TilePos,
+) {
+    let frames = systems
+        .resource
+        .get_tile_meta(tilepos)
+        .map(|meta| meta.anim_frames.min(MAX_ANIM_FRAMES))
+        .unwrap_or_default();
+    if frames <= 1 {
+        return;
+    }
+
+    for i in 0..frames {
+        let layer = MapLayers::Anim1 as usize + i as usize;
+        if is_layer_locked(systems, layer) {
+            continue;
+        }
+
+        let frame_pos = TilePos {
+            x: tilepos.x + (i * TEXTURE_SIZE),
+            ..*tilepos
+        };
+        let Some(id) = systems.resource.tile_pos_loc.get(&frame_pos).copied() else {
+            continue;
+        };
+
+        set_map_tile(
+            content,
+            systems,
+            set_pos.x as i32,
+            set_pos.y as i32,
+            layer,
+            id,
+        );
+        refresh_autotile_around(content, systems, set_pos, layer);
+    }
+}
+
 pub fn update_map_attribute(content: &mut Content, systems: &mut SystemHolder, set: bool) {
     let map_pos = content.map_view.tile.cur_pos;
-    let tile_pos = get_tile_pos(map_pos.x as i32, map_pos.y as i32);
 
     let e_attribute = content.interface.side_window.attributes.cur_attribute;
     let attribute = {
@@ -406,27 +868,281 @@ pub fn update_map_attribute(content: &mut Content, systems: &mut SystemHolder, s
         }
     };
 
-    let (color, text) = get_attribute_visual(&attribute);
+    set_map_attribute(
+        content,
+        systems,
+        map_pos.x as u16,
+        map_pos.y as u16,
+        attribute,
+    );
+}
+
+pub fn set_map_attribute(
+    content: &mut Content,
+    systems: &mut SystemHolder,
+    x: u16,
+    y: u16,
+    attribute: MapAttribute,
+) {
+    let tile_pos = get_tile_pos(x as i32, y as i32);
+    let (color, text) = get_attribute_visual(&attribute);
+
+    let cur_attr = content.data.mapdata.attribute[tile_pos].clone();
+    content.data.record_attr(x, y, cur_attr, attribute.clone());
+
+    content.data.mapdata.attribute[tile_pos] = attribute;
+
+    {
+        let view_attr = content.map_view.attribute[tile_pos];
+        systems.gfx.set_text(&view_attr.text, &text);
+        systems
+            .gfx
+            .center_text(&mut systems.renderer, &view_attr.text);
+        systems.gfx.set_color(&view_attr.bg, color);
+    }
+
+    content.data.changed = true;
+    content.data.temp_saved = false;
+    if let Some(map_pos) = content.data.pos {
+        content
+            .interface
+            .footer
+            .set_map_pos(systems, map_pos, false);
+    }
+}
+
+// Sets the attribute and dir block without recording them in the history
+pub fn set_tile_collision(
+    content: &mut Content,
+    systems: &mut SystemHolder,
+    x: u16,
+    y: u16,
+    attribute: MapAttribute,
+    dirblock: u8,
+) {
+    let tile_pos = get_tile_pos(x as i32, y as i32);
+    let (color, text) = get_attribute_visual(&attribute);
+    content.data.mapdata.attribute[tile_pos] = attribute;
+    content.data.mapdata.dir_block[tile_pos] = dirblock;
+
+    let view_attr = content.map_view.attribute[tile_pos];
+    systems.gfx.set_text(&view_attr.text, &text);
+    systems
+        .gfx
+        .center_text(&mut systems.renderer, &view_attr.text);
+    systems.gfx.set_color(&view_attr.bg, color);
+
+    let dirblock_uv = get_dirblock_uv(dirblock);
+    systems.gfx.set_uv(
+        &content.map_view.dir_block[tile_pos],
+        Vec4::new(
+            20.0 * dirblock_uv.0 as f32,
+            20.0 * dirblock_uv.1 as f32,
+            20.0,
+            20.0,
+        ),
+    );
+}
+
+// Collision the tiles with metadata on every layer of the map tile add up to,
+// None when none of the tiles got metadata
+fn get_meta_collision(
+    content: &Content,
+    systems: &SystemHolder,
+    tile_pos: usize,
+) -> Option<TileCollision> {
+    let mut collision = None;
+    for layer in content.data.mapdata.tile.iter() {
+        let id = layer.id[tile_pos] as usize;
+        if id == 0 {
+            continue;
+        }
+
+        if let Some(tilepos) = systems.resource.tile_index_loc.get(&id)
+            && let Some(meta) = systems.resource.get_tile_meta(tilepos)
+        {
+            collision
+                .get_or_insert_with(TileCollision::default)
+                .merge(&meta.collision);
+        }
+    }
+    collision
+}
+
+fn get_collision_attr(collision: &TileCollision) -> MapAttribute {
+    if collision.blocked {
+        MapAttribute::Blocked
+    } else if collision.npc_blocked {
+        MapAttribute::NpcBlocked
+    } else {
+        MapAttribute::Walkable
+    }
+}
+
+// Collision the tiles on every layer of the map tile add up to. Attributes like warps or
+// signs are not collision, so the tiles never replace them. On map tiles without any tile
+// metadata the collision is left alone, unless it is still the one `prev_collision` brought
+// from the tiles that were there before
+fn get_tile_collision(
+    content: &Content,
+    systems: &SystemHolder,
+    x: u16,
+    y: u16,
+    prev_collision: Option<TileCollision>,
+) -> Option<CollisionChangeData> {
+    let tile_pos = get_tile_pos(x as i32, y as i32);
+    let mapdata = &content.data.mapdata;
+
+    let from_attr = mapdata.attribute[tile_pos].clone();
+    if !matches!(
+        from_attr,
+        MapAttribute::Walkable | MapAttribute::Blocked | MapAttribute::NpcBlocked
+    ) {
+        return None;
+    }
+    let from_dirblock = mapdata.dir_block[tile_pos];
+
+    let collision = match (
+        get_meta_collision(content, systems, tile_pos),
+        prev_collision,
+    ) {
+        (Some(collision), _) => collision,
+        (None, Some(prev))
+            if from_attr == get_collision_attr(&prev) && from_dirblock == prev.dir_block =>
+        {
+            TileCollision::default()
+        }
+        _ => return None,
+    };
+    let to_attr = get_collision_attr(&collision);
+
+    if from_attr == to_attr && from_dirblock == collision.dir_block {
+        return None;
+    }
+
+    Some(CollisionChangeData {
+        x,
+        y,
+        from_attr,
+        to_attr,
+        from_dirblock,
+        to_dirblock: collision.dir_block,
+    })
+}
+
+// Collision the tiles of the map tile brought along before it gets painted over
+fn get_prev_collision(
+    content: &Content,
+    systems: &SystemHolder,
+    pos: Vec2,
+) -> Option<TileCollision> {
+    let Some((None, tile_x, tile_y)) = get_link_tile_pos(pos.x as i32, pos.y as i32) else {
+        return None;
+    };
+    get_meta_collision(content, systems, get_tile_pos(tile_x as i32, tile_y as i32))
+}
+
+// With tile collision enabled the painted map tile follows the collision of its tiles,
+// the linked maps are left alone
+fn update_tile_collision(
+    content: &mut Content,
+    systems: &mut SystemHolder,
+    pos: Vec2,
+    prev_collision: Option<TileCollision>,
+) {
+    if !systems.config.auto_collision {
+        return;
+    }
+
+    let Some((None, tile_x, tile_y)) = get_link_tile_pos(pos.x as i32, pos.y as i32) else {
+        return;
+    };
+    let Some(change) = get_tile_collision(
+        content,
+        systems,
+        tile_x as u16,
+        tile_y as u16,
+        prev_collision,
+    ) else {
+        return;
+    };
+
+    set_tile_collision(
+        content,
+        systems,
+        change.x,
+        change.y,
+        change.to_attr.clone(),
+        change.to_dirblock,
+    );
+    content.data.record_tile_collision(change);
+}
+
+// Changes how long each frame of the animated tiles on the map tile is shown
+pub fn set_tile_anim_time(content: &mut Content, x: u16, y: u16, anim_time: u32) {
+    let tile_pos = get_tile_pos(x as i32, y as i32);
+    content.data.mapdata.anim_time[tile_pos] = anim_time;
+
+    for layer in 0..MapLayers::Count as u32 {
+        let pos = UVec3::new(x as u32, y as u32, layer);
+        let tile = content.map_view.map.get_tile(pos);
+        if tile.id > 0 {
+            content
+                .map_view
+                .map
+                .set_tile(pos, TileData { anim_time, ..tile });
+        }
+    }
+}
+
+// Animated presets bring their frame duration to the map tiles they paint,
+// the linked maps are left alone
+fn update_tile_anim_time(content: &mut Content, x: i32, y: i32, anim_time: u32) {
+    let Some((None, tile_x, tile_y)) = get_link_tile_pos(x, y) else {
+        return;
+    };
+    let from = content.data.mapdata.anim_time[get_tile_pos(tile_x as i32, tile_y as i32)];
+    if from == anim_time {
+        return;
+    }
 
-    let cur_attr = content.data.mapdata.attribute[tile_pos].clone();
-    content.data.record_attr(
-        map_pos.x as u16,
-        map_pos.y as u16,
-        cur_attr,
-        attribute.clone(),
-    );
+    set_tile_anim_time(content, tile_x as u16, tile_y as u16, anim_time);
+    content.data.record_tile_anim_time(AnimTimeChangeData {
+        x: tile_x as u16,
+        y: tile_y as u16,
+        from,
+        to: anim_time,
+    });
+}
 
-    content.data.mapdata.attribute[tile_pos] = attribute;
+// Rebuilds the collision of the whole map from the tiles, for maps painted before
+// the tile sheets got their collision
+pub fn recompute_tile_collision(content: &mut Content, systems: &mut SystemHolder) -> bool {
+    let mut change = Vec::new();
+    for x in 0..32 {
+        for y in 0..32 {
+            if let Some(data) = get_tile_collision(content, systems, x, y, None) {
+                set_tile_collision(
+                    content,
+                    systems,
+                    x,
+                    y,
+                    data.to_attr.clone(),
+                    data.to_dirblock,
+                );
+                change.push(data);
+            }
+        }
+    }
 
-    {
-        let view_attr = content.map_view.attribute[tile_pos];
-        systems.gfx.set_text(&view_attr.text, &text);
-        systems
-            .gfx
-            .center_text(&mut systems.renderer, &view_attr.text);
-        systems.gfx.set_color(&view_attr.bg, color);
+    if change.is_empty() {
+        return false;
     }
 
+    content
+        .data
+        .record_change(EditorChange::Collision(CollisionChanges { change }));
+
     content.data.changed = true;
     content.data.temp_saved = false;
     if let Some(map_pos) = content.data.pos {
@@ -435,47 +1151,23 @@ pub fn update_map_attribute(content: &mut Content, systems: &mut SystemHolder, s
             .footer
             .set_map_pos(systems, map_pos, false);
     }
+    true
 }
 
 pub fn update_map_zone(content: &mut Content, systems: &mut SystemHolder, set: bool) {
     let map_pos = content.map_view.tile.cur_pos;
     let cur_zone = content.interface.side_window.zone.cur_zone;
-    let tile_num = get_tile_pos(map_pos.x as i32, map_pos.y as i32);
-    let data = (map_pos.x as u16, map_pos.y as u16);
 
-    if set {
-        if content.data.mapdata.zonespawns[cur_zone].contains(&data) {
-            return;
-        }
-        content.data.mapdata.zonespawns[cur_zone].push(data);
-    } else if let Some(index) = content.data.mapdata.zonespawns[cur_zone]
-        .iter()
-        .position(|check| *check == data)
-    {
-        content.data.mapdata.zonespawns[cur_zone].remove(index);
-    } else {
+    if !set_map_zone(
+        content,
+        systems,
+        (map_pos.x as u16, map_pos.y as u16),
+        cur_zone,
+        set,
+    ) {
         return;
     }
 
-    let tile_size = Vec2::new(TEXTURE_SIZE as f32, TEXTURE_SIZE as f32);
-    let attr_zoom_pos = Vec2::new(content.map_view.map.pos.x, content.map_view.map.pos.y);
-
-    let pos = Vec2::new((tile_num % 32) as f32, (tile_num / 32) as f32);
-    let tile_pos = Vec2::new(
-        attr_zoom_pos.x + (tile_size.x * pos.x),
-        attr_zoom_pos.y + (tile_size.y * pos.y),
-    );
-
-    let gfx = content.map_view.zones[tile_num];
-
-    systems
-        .gfx
-        .set_pos(&gfx, Vec3::new(tile_pos.x, tile_pos.y, ORDER_TILE_BG));
-    systems.gfx.set_color(
-        &gfx,
-        Color::rgba(0, 0, if set { 100 } else { 0 }, if set { 150 } else { 0 }),
-    );
-
     content.data.changed = true;
     content.data.temp_saved = false;
     if let Some(map_pos) = content.data.pos {
@@ -486,26 +1178,40 @@ pub fn update_map_zone(content: &mut Content, systems: &mut SystemHolder, set: b
     }
 }
 
-pub fn update_map_dirblock(content: &mut Content, systems: &mut SystemHolder, set: bool) {
-    let map_pos = content.map_view.tile.cur_pos;
-    let tile_num = get_tile_pos(map_pos.x as i32, map_pos.y as i32);
+fn get_dirblock_value(content: &Content) -> u8 {
+    let blocks = &content.interface.side_window.dirblocks.blocks;
 
     let mut dirblock = 0;
-    if set {
-        if content.interface.side_window.dirblocks.blocks[1].value {
-            dirblock.set(B1);
-        }
-        if content.interface.side_window.dirblocks.blocks[2].value {
-            dirblock.set(B2);
-        }
-        if content.interface.side_window.dirblocks.blocks[0].value {
-            dirblock.set(B0);
-        }
-        if content.interface.side_window.dirblocks.blocks[3].value {
-            dirblock.set(B3);
-        }
+    if blocks[1].value {
+        dirblock.set(B1);
+    }
+    if blocks[2].value {
+        dirblock.set(B2);
+    }
+    if blocks[0].value {
+        dirblock.set(B0);
+    }
+    if blocks[3].value {
+        dirblock.set(B3);
+    }
+    dirblock
+}
+
+fn set_map_dirblock(
+    content: &mut Content,
+    systems: &mut SystemHolder,
+    x: u16,
+    y: u16,
+    dirblock: u8,
+) -> bool {
+    let tile_num = get_tile_pos(x as i32, y as i32);
+
+    let cur_dirblock = content.data.mapdata.dir_block[tile_num];
+    if cur_dirblock == dirblock {
+        return false;
     }
 
+    content.data.record_dirblock(x, y, cur_dirblock, dirblock);
     content.data.mapdata.dir_block[tile_num] = dirblock;
 
     let dirblock_uv = get_dirblock_uv(dirblock);
@@ -520,6 +1226,55 @@ pub fn update_map_dirblock(content: &mut Content, systems: &mut SystemHolder, se
             20.0,
         ),
     );
+    true
+}
+
+pub fn update_map_dirblock(content: &mut Content, systems: &mut SystemHolder, set: bool) {
+    let map_pos = content.map_view.tile.cur_pos;
+    let dirblock = if set { get_dirblock_value(content) } else { 0 };
+
+    if !set_map_dirblock(
+        content,
+        systems,
+        map_pos.x as u16,
+        map_pos.y as u16,
+        dirblock,
+    ) {
+        return;
+    }
+
+    content.data.changed = true;
+    content.data.temp_saved = false;
+    if let Some(map_pos) = content.data.pos {
+        content
+            .interface
+            .footer
+            .set_map_pos(systems, map_pos, false);
+    }
+}
+
+// Fills the connected tiles that share the dir block of the clicked tile
+pub fn update_dirblock_fill(content: &mut Content, systems: &mut SystemHolder, set: bool) {
+    let map_pos = content.map_view.tile.cur_pos;
+    let dirblock = if set { get_dirblock_value(content) } else { 0 };
+    let start_dirblock =
+        content.data.mapdata.dir_block[get_tile_pos(map_pos.x as i32, map_pos.y as i32)];
+
+    if start_dirblock == dirblock {
+        return;
+    }
+
+    let fill_area = get_fill_area(content, map_pos, false, |pos| {
+        content.data.mapdata.dir_block[get_tile_pos(pos.x as i32, pos.y as i32)] == start_dirblock
+    });
+
+    if fill_area.is_empty() {
+        return;
+    }
+
+    for pos in fill_area {
+        set_map_dirblock(content, systems, pos.x as u16, pos.y as u16, dirblock);
+    }
 
     content.data.changed = true;
     content.data.temp_saved = false;
@@ -531,20 +1286,53 @@ pub fn update_map_dirblock(content: &mut Content, systems: &mut SystemHolder, se
     }
 }
 
+// Border tiles of the selection get the block of each side that leads out of it,
+// the blocks they already had are kept
+pub fn auto_edge_dirblock(content: &mut Content, systems: &mut SystemHolder) -> bool {
+    let Some((min, max)) = content.map_view.get_selection_area() else {
+        return false;
+    };
+
+    let mut did_change = false;
+    for x in min.x as i32..=max.x as i32 {
+        for y in min.y as i32..=max.y as i32 {
+            let mut dirblock = content.data.mapdata.dir_block[get_tile_pos(x, y)];
+            for (bit, offset) in [(B1, (0, 1)), (B2, (-1, 0)), (B0, (0, -1)), (B3, (1, 0))] {
+                let side = Vec2::new((x + offset.0) as f32, (y + offset.1) as f32);
+                if !content.map_view.in_selection(side) {
+                    dirblock.set(bit);
+                }
+            }
+
+            did_change |= set_map_dirblock(content, systems, x as u16, y as u16, dirblock);
+        }
+    }
+    content.data.record_placeholder();
+
+    if did_change {
+        content.data.changed = true;
+        content.data.temp_saved = false;
+        if let Some(map_pos) = content.data.pos {
+            content
+                .interface
+                .footer
+                .set_map_pos(systems, map_pos, false);
+        }
+    }
+    true
+}
+
 pub fn set_preset(content: &mut Content, systems: &mut SystemHolder) {
     let cur_preset = content.interface.side_window.presets.selected_index;
-    let draw_type = content.preset.data[cur_preset].draw_type;
-    let animated = matches!(
-        draw_type,
-        PresetTypeList::Animated | PresetTypeList::AutotileAnimated
-    );
+    let Some(preset) = content.preset.data.get(cur_preset) else {
+        return;
+    };
+    let draw_type = preset.draw_type;
+    let frames = preset.frames;
+    let anim_time = preset.anim_time;
+    let animated = draw_type.is_animated();
     let map_pos = content.map_view.tile.cur_pos;
-    let cur_layer = match content.interface.tool.cur_layer {
-        1..=4 => content.interface.tool.cur_layer + 2,
-        5 | 6 => content.interface.tool.cur_layer - 4,
-        _ => content.interface.tool.cur_layer,
-    } as u32;
-    let frames = content.preset.data[cur_preset].frames;
+    let cur_layer = convert_layer_button_to_index(content.interface.tool.cur_layer) as u32;
 
     match draw_type {
         PresetTypeList::Normal | PresetTypeList::Animated => {
@@ -558,6 +1346,10 @@ pub fn set_preset(content: &mut Content, systems: &mut SystemHolder) {
                     cur_layer as usize
                 };
 
+                if is_layer_locked(systems, set_layer) {
+                    continue;
+                }
+
                 let start_pos = Vec2::new(frame.start.x as f32, frame.start.y as f32);
                 let end_pos = Vec2::new(frame.end.x as f32, frame.end.y as f32);
 
@@ -584,50 +1376,30 @@ pub fn set_preset(content: &mut Content, systems: &mut SystemHolder) {
                             })
                             .copied();
 
-                        if let Some(id) = tile_id {
-                            let pos = UVec3::new(
-                                map_pos.x as u32 + x as u32,
-                                map_pos.y as u32 + y as u32,
-                                set_layer as u32,
-                            );
-
-                            let cur_id = content.map_view.map.get_tile(pos);
-                            content.data.record_tile(
-                                pos.x as u16,
-                                pos.y as u16,
-                                set_layer,
-                                cur_id.id,
-                                id,
-                            );
-
-                            content.map_view.map.set_tile(
-                                pos,
-                                TileData {
-                                    id,
-                                    color: Color::rgba(255, 255, 255, 255),
-                                    anim_time: 250,
-                                },
-                            );
-
-                            let tile_pos = get_tile_pos(pos.x as i32, pos.y as i32);
-                            content.data.mapdata.tile[set_layer].id[tile_pos] = id as u32;
+                        let set_pos = if tile_id.is_some() {
+                            map_pos + Vec2::new(x as f32, y as f32)
                         } else {
-                            let pos =
-                                UVec3::new(map_pos.x as u32, map_pos.y as u32, set_layer as u32);
-
-                            let cur_id = content.map_view.map.get_tile(pos);
-                            content.data.record_tile(
-                                pos.x as u16,
-                                pos.y as u16,
-                                set_layer,
-                                cur_id.id,
-                                0,
+                            map_pos
+                        };
+                        let prev_collision = get_prev_collision(content, systems, set_pos);
+
+                        set_map_tile(
+                            content,
+                            systems,
+                            set_pos.x as i32,
+                            set_pos.y as i32,
+                            set_layer,
+                            tile_id.unwrap_or(0),
+                        );
+                        refresh_autotile_around(content, systems, set_pos, set_layer);
+                        update_tile_collision(content, systems, set_pos, prev_collision);
+                        if animated {
+                            update_tile_anim_time(
+                                content,
+                                set_pos.x as i32,
+                                set_pos.y as i32,
+                                anim_time,
                             );
-
-                            content.map_view.map.set_tile(pos, TileData::default());
-
-                            let tile_pos = get_tile_pos(map_pos.x as i32, map_pos.y as i32);
-                            content.data.mapdata.tile[set_layer].id[tile_pos] = 0;
                         }
                     }
                 }
@@ -642,20 +1414,104 @@ pub fn set_preset(content: &mut Content, systems: &mut SystemHolder) {
                     .set_map_pos(systems, map_pos, false);
             }
         }
-        PresetTypeList::AutoTile | PresetTypeList::AutotileAnimated => {
-            place_autotile(content, systems, animated, map_pos, cur_layer, frames);
+        PresetTypeList::AutoTile
+        | PresetTypeList::AutotileAnimated
+        | PresetTypeList::BlobTile
+        | PresetTypeList::BlobTileAnimated => {
+            place_autotile(content, systems, draw_type, map_pos, cur_layer, frames);
+            if animated {
+                update_tile_anim_time(content, map_pos.x as i32, map_pos.y as i32, anim_time);
+            }
+        }
+    }
+}
+
+// Collects every tile the fill should reach, based on the active fill options
+fn get_fill_area<F>(content: &Content, start_pos: Vec2, link: bool, is_match: F) -> Vec<Vec2>
+where
+    F: Fn(Vec2) -> bool,
+{
+    let tool = &content.interface.tool;
+    let use_selection =
+        tool.is_fill_option(FillOption::Selection) && content.map_view.selection.active;
+    let can_fill =
+        |pos: Vec2| (!use_selection || content.map_view.in_selection(pos)) && is_match(pos);
+
+    if !can_fill(start_pos) {
+        return Vec::new();
+    }
+
+    // Linked maps extend the area by a whole map on every side
+    let (min, max) = if link { (-32, 64) } else { (0, 32) };
+    let size = (max - min) as usize;
+    let get_index =
+        |pos: Vec2| (pos.x as i32 - min) as usize + ((pos.y as i32 - min) as usize * size);
+
+    if tool.is_fill_option(FillOption::Global) {
+        return (min..max)
+            .flat_map(|x| (min..max).map(move |y| Vec2::new(x as f32, y as f32)))
+            .filter(|pos| can_fill(*pos))
+            .collect();
+    }
+
+    let max_dir = if tool.is_fill_option(FillOption::EightWay) {
+        8
+    } else {
+        4
+    };
+
+    let mut visited = vec![false; size * size];
+    let mut fill_area: Vec<Vec2> = Vec::with_capacity(MAX_TILE);
+    let mut paint_to_map: Vec<Vec2> = Vec::with_capacity(MAX_TILE);
+
+    visited[get_index(start_pos)] = true;
+    paint_to_map.push(start_pos);
+
+    while let Some(pos) = paint_to_map.pop() {
+        fill_area.push(pos);
+
+        for dir in 0..max_dir {
+            let adjust_pos = match dir {
+                1 => Vec2::new(0.0, 1.0),   // Up
+                2 => Vec2::new(-1.0, 0.0),  // Left
+                3 => Vec2::new(1.0, 0.0),   // Right
+                4 => Vec2::new(-1.0, 1.0),  // Up Left
+                5 => Vec2::new(1.0, 1.0),   // Up Right
+                6 => Vec2::new(-1.0, -1.0), // Down Left
+                7 => Vec2::new(1.0, -1.0),  // Down Right
+                _ => Vec2::new(0.0, -1.0),  // Down
+            };
+            let checkpos = pos + adjust_pos;
+
+            if checkpos.x >= min as f32
+                && checkpos.x < max as f32
+                && checkpos.y >= min as f32
+                && checkpos.y < max as f32
+            {
+                let check_pos = get_index(checkpos);
+                if !visited[check_pos] && can_fill(checkpos) {
+                    visited[check_pos] = true;
+                    paint_to_map.push(checkpos);
+                }
+            }
         }
     }
+
+    fill_area
 }
 
 pub fn update_tile_fill(content: &mut Content, systems: &mut SystemHolder, set: bool) {
-    let cur_layer = match content.interface.tool.cur_layer {
-        1..=4 => content.interface.tool.cur_layer + 2,
-        5 | 6 => content.interface.tool.cur_layer - 4,
-        _ => content.interface.tool.cur_layer,
-    } as u32;
+    let cur_layer = convert_layer_button_to_index(content.interface.tool.cur_layer) as u32;
+
+    if is_layer_locked(systems, cur_layer as usize) {
+        return;
+    }
 
-    let tile_data = if set {
+    let use_pattern = content.interface.tool.is_fill_option(FillOption::Pattern);
+    let all_layers = content.interface.tool.is_fill_option(FillOption::AllLayers);
+
+    // Tile ids of the tileset selection, stored by column
+    let pattern = if set {
         let start_pos = content.interface.side_window.tilesets.selection.start_pos;
         let end_pos = content.interface.side_window.tilesets.selection.end_pos;
 
@@ -663,99 +1519,121 @@ pub fn update_tile_fill(content: &mut Content, systems: &mut SystemHolder, set:
             start_pos.x.min(end_pos.x),
             TILESET_COUNT_Y.saturating_sub(1) as f32 - start_pos.y.min(end_pos.y),
         );
-
-        let tile_id = if let Some(id) = systems
-            .resource
-            .tile_pos_loc
-            .get(&TilePos {
-                x: tilesheet_pos.x as u32 * TEXTURE_SIZE,
-                y: tilesheet_pos.y as u32 * TEXTURE_SIZE,
-                file: content.interface.side_window.tilesets.cur_tileset as u32,
-            })
-            .copied()
-        {
-            id
+        let (size_x, size_y) = if use_pattern {
+            (
+                (start_pos.x - end_pos.x).abs() as usize + 1,
+                (start_pos.y - end_pos.y).abs() as usize + 1,
+            )
         } else {
-            return;
+            (1, 1)
         };
 
-        TileData {
-            id: tile_id,
-            color: Color::rgba(255, 255, 255, 255),
-            anim_time: 250,
+        let mut pattern = Vec::with_capacity(size_x);
+        for x in 0..size_x {
+            let mut column = Vec::with_capacity(size_y);
+            for y in 0..size_y {
+                let tile_id = if let Some(id) = systems
+                    .resource
+                    .tile_pos_loc
+                    .get(&TilePos {
+                        x: (tilesheet_pos.x as u32 + x as u32) * TEXTURE_SIZE,
+                        y: (tilesheet_pos.y as u32 - y as u32) * TEXTURE_SIZE,
+                        file: content.interface.side_window.tilesets.cur_tileset as u32,
+                    })
+                    .copied()
+                {
+                    id
+                } else {
+                    return;
+                };
+                column.push(tile_id);
+            }
+            pattern.push(column);
         }
+        pattern
     } else {
-        TileData::default()
+        vec![vec![0]]
     };
 
     let map_pos = content.map_view.tile.cur_pos;
+    let get_fill_key = |content: &Content, pos: Vec2| {
+        let mut key = [0; 9];
+        if all_layers {
+            for (layer, id) in key.iter_mut().enumerate() {
+                *id = get_map_tile(content, pos.x as i32, pos.y as i32, layer)?;
+            }
+        } else {
+            key[0] = get_map_tile(content, pos.x as i32, pos.y as i32, cur_layer as usize)?;
+        }
+        Some(key)
+    };
 
-    let comparedata = content
-        .map_view
-        .map
-        .get_tile(UVec3::new(map_pos.x as u32, map_pos.y as u32, cur_layer))
-        .id;
-    if comparedata == tile_data.id {
+    let Some(comparedata) = get_fill_key(content, map_pos) else {
+        return;
+    };
+    if !all_layers && pattern.len() == 1 && pattern[0].len() == 1 && comparedata[0] == pattern[0][0]
+    {
         return;
     }
 
-    let mut paint_to_map: Vec<Vec2> = Vec::with_capacity(1024);
+    let link = content
+        .interface
+        .tool
+        .is_fill_option(FillOption::LinkedMaps)
+        && content.data.link_map.iter().any(Option::is_some);
+    let fill_area = get_fill_area(content, map_pos, link, |pos| {
+        get_fill_key(content, pos) == Some(comparedata)
+    });
 
-    paint_to_map.push(map_pos);
+    let mut got_change = false;
+    let mut changed_pos = HashSet::new();
+    for pos in fill_area {
+        // The pattern is anchored on the clicked tile
+        let pattern_x = (pos.x - map_pos.x).rem_euclid(pattern.len() as f32) as usize;
+        let pattern_y = (pos.y - map_pos.y).rem_euclid(pattern[0].len() as f32) as usize;
+        let id = if set {
+            pattern[pattern_x][pattern_y]
+        } else {
+            0
+        };
 
-    while let Some(pos) = paint_to_map.pop() {
-        let cur_id =
-            content
-                .map_view
-                .map
-                .get_tile(UVec3::new(pos.x as u32, pos.y as u32, cur_layer));
-        content.data.record_tile(
-            pos.x as u16,
-            pos.y as u16,
+        if get_map_tile(content, pos.x as i32, pos.y as i32, cur_layer as usize) == Some(id) {
+            continue;
+        }
+
+        if set_map_tile(
+            content,
+            systems,
+            pos.x as i32,
+            pos.y as i32,
             cur_layer as usize,
-            cur_id.id,
-            tile_data.id,
-        );
+            id,
+        ) {
+            got_change = true;
+            let _ = changed_pos.insert((pos.x as i32, pos.y as i32));
+        }
+    }
 
-        content
-            .map_view
-            .map
-            .set_tile(UVec3::new(pos.x as u32, pos.y as u32, cur_layer), tile_data);
-
-        let tile_pos = get_tile_pos(pos.x as i32, pos.y as i32);
-        content.data.mapdata.tile[cur_layer as usize].id[tile_pos] = tile_data.id as u32;
-
-        for dir in 0..4 {
-            let mut adjust_pos = Vec2::new(0.0, 0.0);
-            match dir {
-                1 => {
-                    adjust_pos.y = 1.0;
-                } // Up
-                2 => {
-                    adjust_pos.x = -1.0;
-                } // Left
-                3 => {
-                    adjust_pos.x = 1.0;
-                } // Right
-                _ => {
-                    adjust_pos.y = -1.0;
-                } // Down
-            }
-            let checkpos = pos + adjust_pos;
+    if !got_change {
+        return;
+    }
 
-            if checkpos.x >= 0.0 && checkpos.x < 32.0 && checkpos.y >= 0.0 && checkpos.y < 32.0 {
-                let check_data = content
-                    .map_view
-                    .map
-                    .get_tile(UVec3::new(checkpos.x as u32, checkpos.y as u32, cur_layer))
-                    .id;
-                if check_data == comparedata {
-                    paint_to_map.push(checkpos);
-                }
-            }
+    // Autotiles touching the edge of the fill pick their piece again
+    for &(x, y) in changed_pos.iter() {
+        let on_edge = AUTO_DIR
+            .iter()
+            .any(|(dir_x, dir_y)| !changed_pos.contains(&(x + *dir_x, y + *dir_y)));
+        if on_edge {
+            refresh_autotile_around(
+                content,
+                systems,
+                Vec2::new(x as f32, y as f32),
+                cur_layer as usize,
+            );
         }
     }
 
+    content.data.tile_placeholder.fill = true;
     content.data.changed = true;
     content.data.temp_saved = false;
     if let Some(map_pos) = content.data.pos {
@@ -807,11 +1685,16 @@ pub fn update_attribute_fill(content: &mut Content, systems: &mut SystemHolder,
         return;
     }
 
-    let mut paint_to_map: Vec<Vec2> = Vec::with_capacity(1024);
+    let fill_area = get_fill_area(content, map_pos, false, |pos| {
+        let check_pos = get_tile_pos(pos.x as i32, pos.y as i32);
+        content.data.mapdata.attribute[check_pos] == comparedata
+    });
 
-    paint_to_map.push(map_pos);
+    if fill_area.is_empty() {
+        return;
+    }
 
-    while let Some(pos) = paint_to_map.pop() {
+    for pos in fill_area {
         let new_pos = get_tile_pos(pos.x as i32, pos.y as i32);
 
         let cur_attr = content.data.mapdata.attribute[new_pos].clone();
@@ -830,35 +1713,9 @@ pub fn update_attribute_fill(content: &mut Content, systems: &mut SystemHolder,
                 .center_text(&mut systems.renderer, &view_attr.text);
             systems.gfx.set_color(&view_attr.bg, color);
         }
-
-        for dir in 0..4 {
-            let mut adjust_pos = Vec2::new(0.0, 0.0);
-            match dir {
-                1 => {
-                    adjust_pos.y = 1.0;
-                } // Up
-                2 => {
-                    adjust_pos.x = -1.0;
-                } // Left
-                3 => {
-                    adjust_pos.x = 1.0;
-                } // Right
-                _ => {
-                    adjust_pos.y = -1.0;
-                } // Down
-            }
-            let checkpos = pos + adjust_pos;
-
-            if checkpos.x >= 0.0 && checkpos.x < 32.0 && checkpos.y >= 0.0 && checkpos.y < 32.0 {
-                let check_pos = get_tile_pos(checkpos.x as i32, checkpos.y as i32);
-                let check_data = content.data.mapdata.attribute[check_pos].clone();
-                if check_data == comparedata {
-                    paint_to_map.push(checkpos);
-                }
-            }
-        }
     }
 
+    content.data.attr_placeholder.fill = true;
     content.data.changed = true;
     content.data.temp_saved = false;
     if let Some(map_pos) = content.data.pos {
@@ -872,11 +1729,7 @@ pub fn update_attribute_fill(content: &mut Content, systems: &mut SystemHolder,
 pub fn picker_layer_update(content: &mut Content, systems: &mut SystemHolder) {
     switch_tab(content, systems, TabButton::Tileset);
 
-    let cur_layer = match content.interface.tool.cur_layer {
-        1..=4 => content.interface.tool.cur_layer + 2,
-        5 | 6 => content.interface.tool.cur_layer - 4,
-        _ => content.interface.tool.cur_layer,
-    } as u32;
+    let cur_layer = convert_layer_button_to_index(content.interface.tool.cur_layer) as u32;
 
     let map_pos = content.map_view.tile.cur_pos;
     let tile_pos = UVec3::new(map_pos.x as u32, map_pos.y as u32, cur_layer);
@@ -989,6 +1842,81 @@ pub fn picker_attribute_update(content: &mut Content, systems: &mut SystemHolder
     }
 }
 
+pub fn goto_warp_target(
+    content: &mut Content,
+    systems: &mut SystemHolder,
+    graphics: &mut Graphics<FlatControls>,
+    alert: &mut Alert,
+    seconds: f32,
+) -> Result<()> {
+    let map_pos = content.map_view.tile.cur_pos;
+    let tile_pos = get_tile_pos(map_pos.x as i32, map_pos.y as i32);
+
+    let MapAttribute::Warp(data) = &content.data.mapdata.attribute[tile_pos] else {
+        return Ok(());
+    };
+
+    let target = MapPosition {
+        x: data.map_x,
+        y: data.map_y,
+        group: data.map_group as i32,
+    };
+    let target_tile = Vec2::new(data.tile_x as f32, data.tile_y as f32);
+
+    // Maps waiting on the temp file prompt get centered once the prompt loads them
+    if content.data.pos == Some(target)
+        || open_map(content, systems, alert, target, Some(target_tile), seconds)?
+    {
+        content
+            .map_view
+            .center_on_tile(systems, graphics, target_tile);
+    }
+    Ok(())
+}
+
+// Fills the warp inputs with the clicked tile, then returns to the map the warp is placed on
+pub fn pick_warp_target(
+    content: &mut Content,
+    systems: &mut SystemHolder,
+    alert: &mut Alert,
+    seconds: f32,
+) -> Result<()> {
+    let Some(target) = content.data.pos else {
+        alert.show_alert(
+            systems,
+            &AlertBuilder::new_info("Error", "Warp target must be a saved map"),
+        );
+        return Ok(());
+    };
+    let map_pos = content.map_view.tile.cur_pos;
+
+    let gui = &mut content.interface.side_window.attributes.attr_position;
+    for (index, value) in [
+        map_pos.x as i32,
+        map_pos.y as i32,
+        target.x,
+        target.y,
+        target.group,
+    ]
+    .iter()
+    .enumerate()
+    {
+        gui.input_box[index]
+            .textbox
+            .set_text(systems, format!("{value}"));
+    }
+
+    let source = gui.pick_source;
+    gui.set_picking(systems, false);
+
+    if let Some(source) = source
+        && source != target
+    {
+        open_map(content, systems, alert, source, None, seconds)?;
+    }
+    Ok(())
+}
+
 pub fn load_and_apply_map(
     systems: &mut SystemHolder,
     content: &mut Content,
@@ -1005,6 +1933,9 @@ pub fn load_and_apply_map(
                     &content.data.mapdata,
                     true,
                 )?;
+                content
+                    .data
+                    .save_history(systems.config.save_undo_history)?;
 
                 content.interface.notification.add_msg(
                     systems,
@@ -1018,6 +1949,9 @@ pub fn load_and_apply_map(
                 let _ = content.data.unsaved_map.insert(mappos);
             } else {
                 save_temp_file(0, 0, 0, &content.data.mapdata, false)?;
+                content
+                    .data
+                    .save_history(systems.config.save_undo_history)?;
 
                 content.interface.notification.add_msg(
                     systems,
@@ -1028,7 +1962,9 @@ pub fn load_and_apply_map(
         }
 
         apply_map_data(content, systems, &mapdata);
-        apply_link_map(content, mappos);
+        apply_link_map(content, systems, mappos)?;
+        clear_measure(content, systems);
+        content.data.switch_history(Some(mappos));
         content.data.mapdata = mapdata;
         content.data.pos = Some(mappos);
         content.data.changed = false;
@@ -1041,6 +1977,48 @@ pub fn load_and_apply_map(
     }
 }
 
+// Asks first when the map got a temp file, otherwise it gets loaded right away.
+// Returns true if the map got loaded right away
+pub fn open_map(
+    content: &mut Content,
+    systems: &mut SystemHolder,
+    alert: &mut Alert,
+    mappos: MapPosition,
+    center: Option<Vec2>,
+    seconds: f32,
+) -> Result<bool> {
+    // Edits made across the border are picked up from the temp file
+    save_link_map_temp(content)?;
+
+    if is_temp_map_exist(mappos.x, mappos.y, mappos.group as u64) {
+        alert.show_alert(
+            systems,
+            AlertBuilder::new_confirm(
+                "Temp File",
+                "Temporary file found! Would you like to load this file?",
+            )
+            .with_index(AlertIndex::LoadTempFile(mappos, center)),
+        );
+    } else if load_and_apply_map(systems, content, mappos, seconds)? {
+        content.interface.notification.add_msg(
+            systems,
+            format!(
+                "Map [X: {} Y: {} Group: {}] Loaded!",
+                mappos.x, mappos.y, mappos.group
+            ),
+            seconds,
+        );
+        return Ok(true);
+    } else {
+        alert.show_alert(
+            systems,
+            &AlertBuilder::new_info("Error", "Failed to load map"),
+        );
+    }
+
+    Ok(false)
+}
+
 pub fn save_map_change(content: &mut Content, mappos: MapPosition) -> Result<bool> {
     content.data.mapdata.position = mappos;
 
@@ -1051,6 +2029,8 @@ pub fn save_map_change(content: &mut Content, mappos: MapPosition) -> Result<boo
             if is_temp_map_exist(mappos.x, mappos.y, mappos.group as u64) {
                 delete_temp_map_file(mappos.x, mappos.y, mappos.group as u64)?;
             }
+            // Edits made across the map border are saved together with the map
+            save_link_map(content)?;
 
             content.data.pos = Some(mappos);
             content.data.changed = false;
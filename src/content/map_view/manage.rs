@@ -0,0 +1,116 @@
+use ascending_graphics::*;
+
+use crate::{
+    content::{Content, apply_link_map, apply_map_data, clear_link_map_view, save_link_map_temp},
+    data_types::Result,
+    database::{
+        MapData, MapPosition, delete_map_file, delete_temp_map_file, is_map_exist,
+        is_temp_map_exist, load_map_file, move_temp_map_file, update_warp_target,
+    },
+    renderer::SystemHolder,
+};
+
+// The map being replaced should not leave its unsaved edits behind
+fn discard_map_temp(content: &mut Content, map_pos: MapPosition) -> Result<()> {
+    if is_temp_map_exist(map_pos.x, map_pos.y, map_pos.group as u64) {
+        delete_temp_map_file(map_pos.x, map_pos.y, map_pos.group as u64)?;
+    }
+    let _ = content.data.unsaved_map.swap_remove(&map_pos);
+    let _ = content.data.map_history.remove(&map_pos);
+    Ok(())
+}
+
+pub fn duplicate_map(
+    content: &mut Content,
+    systems: &mut SystemHolder,
+    to: MapPosition,
+) -> Result<()> {
+    save_link_map_temp(content)?;
+    discard_map_temp(content, to)?;
+
+    let mut mapdata = content.data.mapdata.clone();
+    mapdata.position = to;
+    mapdata.save_file()?;
+
+    // The copy might be one of the linked maps
+    if let Some(map_pos) = content.data.pos {
+        apply_link_map(content, systems, map_pos)?;
+    }
+    Ok(())
+}
+
+// Map files are named after their position, so this is also how a map gets renamed
+pub fn move_map(
+    content: &mut Content,
+    systems: &mut SystemHolder,
+    to: MapPosition,
+    update_warps: bool,
+) -> Result<()> {
+    let Some(from) = content.data.pos else {
+        return Ok(());
+    };
+
+    save_link_map_temp(content)?;
+    discard_map_temp(content, to)?;
+
+    if is_map_exist(from.x, from.y, from.group as u64) {
+        let mut mapdata = load_map_file(from.x, from.y, from.group as u64, false)?;
+        mapdata.position = to;
+        mapdata.save_file()?;
+        delete_map_file(from.x, from.y, from.group as u64)?;
+    }
+    if is_temp_map_exist(from.x, from.y, from.group as u64) {
+        move_temp_map_file(from, to)?;
+    }
+    if content.data.unsaved_map.swap_remove(&from) {
+        let _ = content.data.unsaved_map.insert(to);
+    }
+
+    content.data.mapdata.position = to;
+    content.data.pos = Some(to);
+
+    if update_warps {
+        update_warp_target(from, to)?;
+        if content.data.mapdata.retarget_warp(from, to) {
+            content.data.changed = true;
+            content.data.temp_saved = false;
+        }
+    }
+
+    apply_link_map(content, systems, to)?;
+    content
+        .interface
+        .footer
+        .set_map_pos(systems, to, !content.data.changed);
+    Ok(())
+}
+
+pub fn delete_map(content: &mut Content, systems: &mut SystemHolder) -> Result<()> {
+    let Some(map_pos) = content.data.pos else {
+        return Ok(());
+    };
+
+    save_link_map_temp(content)?;
+    if is_map_exist(map_pos.x, map_pos.y, map_pos.group as u64) {
+        delete_map_file(map_pos.x, map_pos.y, map_pos.group as u64)?;
+    }
+    discard_map_temp(content, map_pos)?;
+
+    // The editor is left with an empty map that has no position
+    let mapdata = MapData::default(0, 0, 0);
+    apply_map_data(content, systems, &mapdata);
+    content.data.link_map.clear();
+    for map in content.map_view.linked_map.iter_mut() {
+        clear_link_map_view(map);
+    }
+
+    content.data.undo.clear();
+    content.data.redo.clear();
+    content.data.history_updated = true;
+    content.data.mapdata = mapdata;
+    content.data.pos = None;
+    content.data.changed = false;
+    content.data.temp_saved = true;
+    content.interface.footer.remove_map_pos(systems);
+    Ok(())
+}
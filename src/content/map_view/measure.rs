@@ -0,0 +1,179 @@
+use ascending_graphics::*;
+use bit_op::{BitOp, bit_u8::*};
+use std::collections::VecDeque;
+
+use crate::{
+    content::{Content, get_tile_pos},
+    data_types::*,
+    database::{MapAttribute, MapData},
+    gfx_collection::GfxType,
+    renderer::SystemHolder,
+};
+
+#[derive(Default)]
+pub struct MapMeasure {
+    pub start: Option<(i32, i32)>,
+    pub end: Option<(i32, i32)>,
+    pub path: Vec<GfxType>,
+}
+
+// Up, Left, Down and Right with the dir block bit of each direction
+const MOVE_DIR: [(i32, i32, u8); 4] = [(0, 1, B1), (-1, 0, B2), (0, -1, B0), (1, 0, B3)];
+
+fn get_opposite_dir(bit: u8) -> u8 {
+    match bit {
+        B1 => B0,
+        B0 => B1,
+        B2 => B3,
+        _ => B2,
+    }
+}
+
+// A move is stopped by the dir block of the tile being left and by
+// the dir block of the tile being entered when it faces back
+fn can_move(mapdata: &MapData, from: (i32, i32), to: (i32, i32), bit: u8) -> bool {
+    if !(0..32).contains(&to.0) || !(0..32).contains(&to.1) {
+        return false;
+    }
+
+    let to_index = get_tile_pos(to.0, to.1);
+    if matches!(mapdata.attribute[to_index], MapAttribute::Blocked) {
+        return false;
+    }
+
+    mapdata.dir_block[get_tile_pos(from.0, from.1)].get(bit) == 0
+        && mapdata.dir_block[to_index].get(get_opposite_dir(bit)) == 0
+}
+
+// Shortest walkable path within the map, the start tile is not included
+pub fn get_walking_path(
+    mapdata: &MapData,
+    start: (i32, i32),
+    end: (i32, i32),
+) -> Option<Vec<(i32, i32)>> {
+    let mut came_from: Vec<Option<(i32, i32)>> = vec![None; 1024];
+    let mut visited = vec![false; 1024];
+    let mut queue = VecDeque::new();
+
+    visited[get_tile_pos(start.0, start.1)] = true;
+    queue.push_back(start);
+
+    while let Some(pos) = queue.pop_front() {
+        if pos == end {
+            let mut path = Vec::new();
+            let mut cur = pos;
+            while cur != start {
+                path.push(cur);
+                cur = came_from[get_tile_pos(cur.0, cur.1)]?;
+            }
+            path.reverse();
+            return Some(path);
+        }
+
+        for (x, y, bit) in MOVE_DIR {
+            let next = (pos.0 + x, pos.1 + y);
+            if !can_move(mapdata, pos, next, bit) {
+                continue;
+            }
+
+            let index = get_tile_pos(next.0, next.1);
+            if !visited[index] {
+                visited[index] = true;
+                came_from[index] = Some(pos);
+                queue.push_back(next);
+            }
+        }
+    }
+
+    None
+}
+
+fn add_measure_rect(
+    systems: &mut SystemHolder,
+    map_pos: Vec2,
+    tile: (i32, i32),
+    color: Color,
+) -> GfxType {
+    let mut rect = Rect::new(
+        &mut systems.renderer,
+        Vec3::new(
+            map_pos.x + (tile.0 as u32 * TEXTURE_SIZE) as f32,
+            map_pos.y + (tile.1 as u32 * TEXTURE_SIZE) as f32,
+            ORDER_TILE_SELECT,
+        ),
+        Vec2::new(TEXTURE_SIZE as f32, TEXTURE_SIZE as f32),
+        color,
+        3,
+    );
+    rect.set_border_width(1.0)
+        .set_border_color(Color::rgba(40, 220, 120, 220));
+    systems.gfx.add_rect(
+        rect,
+        RENDER_TOP_MAP,
+        "Measure Path",
+        true,
+        CameraView::MainView,
+    )
+}
+
+pub fn clear_measure(content: &mut Content, systems: &mut SystemHolder) {
+    let measure = &mut content.map_view.measure;
+    for gfx in measure.path.drain(..) {
+        systems.gfx.remove_gfx(&mut systems.renderer, &gfx);
+    }
+    measure.start = None;
+    measure.end = None;
+    content.interface.footer.set_measure(systems, "");
+}
+
+// The first click picks the start tile and the second one the end tile
+pub fn update_measure(content: &mut Content, systems: &mut SystemHolder) {
+    let tile_pos = content.map_view.tile.cur_pos;
+    let tile = (tile_pos.x as i32, tile_pos.y as i32);
+
+    if content.map_view.measure.start.is_none() || content.map_view.measure.end.is_some() {
+        clear_measure(content, systems);
+
+        let map_pos = content.map_view.map.pos;
+        let gfx = add_measure_rect(systems, map_pos, tile, Color::rgba(40, 220, 120, 160));
+        content.map_view.measure.path.push(gfx);
+        content.map_view.measure.start = Some(tile);
+        content.interface.footer.set_measure(
+            systems,
+            &format!("Measure from [X: {} Y: {}]", tile.0, tile.1),
+        );
+        return;
+    }
+
+    let Some(start) = content.map_view.measure.start else {
+        return;
+    };
+    content.map_view.measure.end = Some(tile);
+
+    let distance = (start.0 - tile.0).abs() + (start.1 - tile.1).abs();
+    let path = get_walking_path(&content.data.mapdata, start, tile);
+
+    let map_pos = content.map_view.map.pos;
+    for &pos in path.iter().flatten() {
+        let color = if pos == tile {
+            Color::rgba(40, 220, 120, 160)
+        } else {
+            Color::rgba(40, 220, 120, 60)
+        };
+        let gfx = add_measure_rect(systems, map_pos, pos, color);
+        content.map_view.measure.path.push(gfx);
+    }
+    if path.is_none() {
+        let gfx = add_measure_rect(systems, map_pos, tile, Color::rgba(40, 220, 120, 160));
+        content.map_view.measure.path.push(gfx);
+    }
+
+    let walk = match path {
+        Some(path) => format!("{}", path.len()),
+        None => "No Path".to_string(),
+    };
+    content
+        .interface
+        .footer
+        .set_measure(systems, &format!("Distance: {distance} Walking: {walk}"));
+}
@@ -0,0 +1,188 @@
+use ascending_graphics::*;
+
+use crate::{
+    content::{
+        AUTO_DIR, Content, get_autotile_piece, get_map_tile, get_preset_tiles, is_layer_locked,
+        set_map_tile,
+    },
+    database::PresetData,
+    renderer::SystemHolder,
+};
+
+// Tiles a terrain is recognised by, its own preset and the transitions drawn into it
+struct TerrainTiles {
+    layer: usize,
+    tiles: Vec<usize>,
+}
+
+fn get_terrain_preset(content: &Content, preset: usize) -> Option<&PresetData> {
+    content
+        .preset
+        .data
+        .get(preset)
+        .filter(|preset| preset.draw_type.is_autotile())
+}
+
+fn get_terrain_tiles(content: &Content, systems: &SystemHolder) -> Vec<Option<TerrainTiles>> {
+    content
+        .terrain
+        .terrain
+        .iter()
+        .enumerate()
+        .map(|(index, terrain)| {
+            if terrain.layer >= MapLayers::Count as usize {
+                return None;
+            }
+
+            let mut tiles = get_preset_tiles(systems, get_terrain_preset(content, terrain.preset)?);
+            for transition in content
+                .terrain
+                .transition
+                .iter()
+                .filter(|transition| transition.to == index)
+            {
+                if let Some(preset) = get_terrain_preset(content, transition.preset) {
+                    tiles.extend(get_preset_tiles(systems, preset));
+                }
+            }
+
+            Some(TerrainTiles {
+                layer: terrain.layer,
+                tiles,
+            })
+        })
+        .collect()
+}
+
+// Returns None when the position can't be reached, when terrains overlap
+// the one defined last is taken
+fn get_terrain_at(
+    content: &Content,
+    terrain_tiles: &[Option<TerrainTiles>],
+    pos: Vec2,
+) -> Option<Option<usize>> {
+    get_map_tile(content, pos.x as i32, pos.y as i32, 0)?;
+
+    Some(
+        terrain_tiles
+            .iter()
+            .enumerate()
+            .rev()
+            .find_map(|(index, data)| {
+                let data = data.as_ref()?;
+                let tile = get_map_tile(content, pos.x as i32, pos.y as i32, data.layer)?;
+                data.tiles.contains(&tile).then_some(index)
+            }),
+    )
+}
+
+fn update_terrain_piece(
+    content: &mut Content,
+    systems: &SystemHolder,
+    terrain_tiles: &[Option<TerrainTiles>],
+    pos: Vec2,
+) {
+    let Some(Some(terrain)) = get_terrain_at(content, terrain_tiles, pos) else {
+        return;
+    };
+    let Some(layer) = terrain_tiles[terrain].as_ref().map(|data| data.layer) else {
+        return;
+    };
+    if is_layer_locked(systems, layer) {
+        return;
+    }
+
+    let around = AUTO_DIR
+        .map(|(x, y)| get_terrain_at(content, terrain_tiles, pos + Vec2::new(x as f32, y as f32)));
+    let key = around.map(|other| other.is_none_or(|other| other == Some(terrain)));
+
+    // Edges use the transition into the first other terrain found around the tile
+    let preset_index = around
+        .iter()
+        .flatten()
+        .flatten()
+        .filter(|&&other| other != terrain)
+        .find_map(|&other| content.terrain.get_transition(other, terrain))
+        .unwrap_or(content.terrain.terrain[terrain].preset);
+    let Some(preset) = get_terrain_preset(content, preset_index) else {
+        return;
+    };
+
+    if let Some(id) = get_autotile_piece(systems, preset.draw_type, &preset.frames[0], key)
+        && get_map_tile(content, pos.x as i32, pos.y as i32, layer) != Some(id)
+    {
+        set_map_tile(content, systems, pos.x as i32, pos.y as i32, layer, id);
+    }
+}
+
+pub fn paint_terrain(content: &mut Content, systems: &mut SystemHolder) {
+    let terrain = content.interface.tool.cur_terrain;
+    let Some(preset_index) = content.terrain.terrain.get(terrain).map(|data| data.preset) else {
+        return;
+    };
+
+    let terrain_tiles = get_terrain_tiles(content, systems);
+    let Some(layer) = terrain_tiles
+        .get(terrain)
+        .and_then(|data| data.as_ref())
+        .map(|data| data.layer)
+    else {
+        return;
+    };
+    if is_layer_locked(systems, layer) {
+        return;
+    }
+
+    let map_pos = content.map_view.tile.cur_pos;
+    let (x, y) = (map_pos.x as i32, map_pos.y as i32);
+    // Dragging over the same terrain got nothing left to do
+    match get_terrain_at(content, &terrain_tiles, map_pos) {
+        None => return,
+        Some(cur) if cur == Some(terrain) => return,
+        _ => {}
+    }
+
+    // The tile only keeps the new terrain, others placed on different layers are removed
+    for (index, other) in terrain_tiles.iter().enumerate() {
+        let Some(other) = other else {
+            continue;
+        };
+        if index == terrain || other.layer == layer || is_layer_locked(systems, other.layer) {
+            continue;
+        }
+
+        if let Some(tile) = get_map_tile(content, x, y, other.layer)
+            && other.tiles.contains(&tile)
+        {
+            set_map_tile(content, systems, x, y, other.layer, 0);
+        }
+    }
+
+    // The center piece marks the tile as the new terrain before the pieces get picked
+    let Some(id) = get_terrain_preset(content, preset_index).and_then(|preset| {
+        get_autotile_piece(systems, preset.draw_type, &preset.frames[0], [true; 8])
+    }) else {
+        return;
+    };
+    set_map_tile(content, systems, x, y, layer, id);
+
+    for pos_x in -1..=1 {
+        for pos_y in -1..=1 {
+            update_terrain_piece(
+                content,
+                systems,
+                &terrain_tiles,
+                map_pos + Vec2::new(pos_x as f32, pos_y as f32),
+            );
+        }
+    }
+
+    content.data.changed = true;
+    content.data.temp_saved = false;
+    if let Some(map_pos) = content.data.pos {
+        content
+            .interface
+            .footer
+            .set_map_pos(systems, map_pos, false);
+    }
+}
@@ -0,0 +1,200 @@
+use ascending_graphics::MapLayers;
+use bit_op::{BitOp, bit_u8::*};
+use speedy::{Readable, Writable};
+
+use crate::{
+    content::{Content, EditorChange, apply_map_data, get_tile_pos, is_layer_locked},
+    database::{DEFAULT_ANIM_TIME, MapAttribute},
+    renderer::SystemHolder,
+};
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Readable, Writable)]
+pub enum MapTransform {
+    Shift { x: i32, y: i32, wrap: bool },
+    FlipHorizontal,
+    FlipVertical,
+    RotateClockwise,
+    RotateCounterClockwise,
+}
+
+impl MapTransform {
+    // Returns None if the tile got shifted out of the map
+    pub fn get_pos(&self, x: i32, y: i32) -> Option<(i32, i32)> {
+        match *self {
+            MapTransform::Shift {
+                x: shift_x,
+                y: shift_y,
+                wrap,
+            } => {
+                let (new_x, new_y) = (x + shift_x, y + shift_y);
+                if wrap {
+                    Some((new_x.rem_euclid(32), new_y.rem_euclid(32)))
+                } else if (0..32).contains(&new_x) && (0..32).contains(&new_y) {
+                    Some((new_x, new_y))
+                } else {
+                    None
+                }
+            }
+            MapTransform::FlipHorizontal => Some((31 - x, y)),
+            MapTransform::FlipVertical => Some((x, 31 - y)),
+            MapTransform::RotateClockwise => Some((y, 31 - x)),
+            MapTransform::RotateCounterClockwise => Some((31 - y, x)),
+        }
+    }
+
+    // Blocked directions turn together with the tile
+    pub fn get_dirblock(&self, dirblock: u8) -> u8 {
+        let up = dirblock.get(B1) != 0;
+        let left = dirblock.get(B2) != 0;
+        let down = dirblock.get(B0) != 0;
+        let right = dirblock.get(B3) != 0;
+
+        let (up, left, down, right) = match self {
+            MapTransform::Shift { .. } => return dirblock,
+            MapTransform::FlipHorizontal => (up, right, down, left),
+            MapTransform::FlipVertical => (down, left, up, right),
+            MapTransform::RotateClockwise => (left, down, right, up),
+            MapTransform::RotateCounterClockwise => (right, up, left, down),
+        };
+
+        let mut new_dirblock = dirblock & !0b0000_1111;
+        if up {
+            new_dirblock.set(B1);
+        }
+        if left {
+            new_dirblock.set(B2);
+        }
+        if down {
+            new_dirblock.set(B0);
+        }
+        if right {
+            new_dirblock.set(B3);
+        }
+        new_dirblock
+    }
+
+    pub fn get_label(&self) -> String {
+        match self {
+            MapTransform::Shift { x, y, wrap } => {
+                format!(
+                    "Shift Map {x},{y} ({})",
+                    if *wrap { "Wrap" } else { "Clear" }
+                )
+            }
+            MapTransform::FlipHorizontal => "Flip Map Horizontal".to_string(),
+            MapTransform::FlipVertical => "Flip Map Vertical".to_string(),
+            MapTransform::RotateClockwise => "Rotate Map Clockwise".to_string(),
+            MapTransform::RotateCounterClockwise => "Rotate Map Counter Clockwise".to_string(),
+        }
+    }
+}
+
+// Reads the shift input, the values can be separated by a comma or a space
+pub fn parse_shift_input(text: &str) -> Option<(i32, i32)> {
+    let mut value = text
+        .split([',', ' '])
+        .filter(|data| !data.is_empty())
+        .map(|data| data.trim().parse::<i32>());
+
+    let x = value.next()?.ok()?;
+    let y = value.next()?.ok()?;
+    if value.next().is_some() {
+        return None;
+    }
+    Some((x, y))
+}
+
+pub fn transform_map(content: &mut Content, systems: &mut SystemHolder, transform: MapTransform) {
+    let old_data = content.data.mapdata.clone();
+    let mut mapdata = old_data.clone();
+
+    // Locked layers stay where they are, the frame duration stays with the anim layers
+    let unlocked: Vec<bool> = (0..mapdata.tile.len())
+        .map(|layer| !is_layer_locked(systems, layer))
+        .collect();
+    let move_anim_time =
+        (MapLayers::Anim1 as usize..=MapLayers::Anim4 as usize).all(|layer| unlocked[layer]);
+
+    for (tile, _) in mapdata
+        .tile
+        .iter_mut()
+        .zip(unlocked.iter())
+        .filter(|(_, unlocked)| **unlocked)
+    {
+        tile.id.fill(0);
+    }
+    mapdata.dir_block.fill(0);
+    mapdata.attribute.fill(MapAttribute::Walkable);
+    if move_anim_time {
+        mapdata.anim_time.fill(DEFAULT_ANIM_TIME);
+    }
+
+    for x in 0..32 {
+        for y in 0..32 {
+            let Some((new_x, new_y)) = transform.get_pos(x, y) else {
+                continue;
+            };
+            let from = get_tile_pos(x, y);
+            let to = get_tile_pos(new_x, new_y);
+
+            for ((new_tile, old_tile), _) in mapdata
+                .tile
+                .iter_mut()
+                .zip(old_data.tile.iter())
+                .zip(unlocked.iter())
+                .filter(|(_, unlocked)| **unlocked)
+            {
+                new_tile.id[to] = old_tile.id[from];
+            }
+            mapdata.dir_block[to] = transform.get_dirblock(old_data.dir_block[from]);
+            mapdata.attribute[to].clone_from(&old_data.attribute[from]);
+            if move_anim_time {
+                mapdata.anim_time[to] = old_data.anim_time[from];
+            }
+        }
+    }
+
+    // Warps that lead into the same map keep pointing at the same spot
+    if let Some(map_pos) = content.data.pos {
+        for attribute in mapdata.attribute.iter_mut() {
+            if let MapAttribute::Warp(warp) = attribute
+                && warp.map_x == map_pos.x
+                && warp.map_y == map_pos.y
+                && warp.map_group == map_pos.group as u64
+                && let Some((x, y)) = transform.get_pos(warp.tile_x as i32, warp.tile_y as i32)
+            {
+                warp.tile_x = x as u32;
+                warp.tile_y = y as u32;
+            }
+        }
+    }
+
+    for spawns in mapdata.zonespawns.iter_mut() {
+        *spawns = spawns
+            .iter()
+            .filter_map(|&(x, y)| {
+                transform
+                    .get_pos(x as i32, y as i32)
+                    .map(|(x, y)| (x as u16, y as u16))
+            })
+            .collect();
+    }
+
+    content.data.record_change(EditorChange::Transform(
+        transform,
+        old_data.write_to_vec().unwrap_or_default(),
+        mapdata.write_to_vec().unwrap_or_default(),
+    ));
+
+    apply_map_data(content, systems, &mapdata);
+    content.data.mapdata = mapdata;
+
+    content.data.changed = true;
+    content.data.temp_saved = false;
+    if let Some(map_pos) = content.data.pos {
+        content
+            .interface
+            .footer
+            .set_map_pos(systems, map_pos, false);
+    }
+}
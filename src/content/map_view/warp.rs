@@ -0,0 +1,215 @@
+use crate::{
+    content::{
+        AttrChangeData, AttrChanges, Content, EditorChange, LinkEdit, get_tile_pos,
+        set_map_attribute,
+        widget::{Alert, AlertBuilder},
+    },
+    data_types::*,
+    database::{
+        EditorMapAttribute, MapAttribute, MapPosition, WarpData, is_map_exist, is_temp_map_exist,
+        load_map_file, load_temp_map_file, save_temp_file,
+    },
+    renderer::SystemHolder,
+};
+
+pub fn is_paired_warp_mode(content: &Content) -> bool {
+    let gui = &content.interface.side_window.attributes;
+    gui.cur_attribute == EditorMapAttribute::Warp && gui.attr_position.two_way.value
+}
+
+// Writes only the warp tiles into the saved map file, other unsaved edits of the map stay unsaved.
+// Returns false for maps that got no map file yet
+fn save_warp_tiles(map_pos: MapPosition, tiles: &[(usize, MapAttribute)]) -> Result<bool> {
+    if !is_map_exist(map_pos.x, map_pos.y, map_pos.group as u64) {
+        return Ok(false);
+    }
+
+    let mut mapdata = load_map_file(map_pos.x, map_pos.y, map_pos.group as u64, false)?;
+    for (tile_pos, attribute) in tiles {
+        mapdata.attribute[*tile_pos] = attribute.clone();
+    }
+    mapdata.position = map_pos;
+    mapdata.save_file()?;
+    Ok(true)
+}
+
+fn get_exit_tile(x: i32, y: i32, offset: (i32, i32)) -> Option<(u32, u32)> {
+    let (x, y) = (x + offset.0, y + offset.1);
+
+    if (0..32).contains(&x) && (0..32).contains(&y) {
+        Some((x as u32, y as u32))
+    } else {
+        None
+    }
+}
+
+// Places the warp on the hovered door tile and the reciprocal warp on the target door,
+// each one leads to the tile in front of the other door
+pub fn place_paired_warp(
+    content: &mut Content,
+    systems: &mut SystemHolder,
+    alert: &mut Alert,
+    seconds: f32,
+) -> Result<()> {
+    let Some(source_map) = content.data.pos else {
+        alert.show_alert(
+            systems,
+            &AlertBuilder::new_info("Error", "Save the map before placing a two-way warp"),
+        );
+        return Ok(());
+    };
+
+    let gui = &content.interface.side_window.attributes.attr_position;
+    let target = gui.get_value();
+    let (source_offset, target_offset) = (gui.get_exit_offset(0), gui.get_exit_offset(1));
+    let source = content.map_view.tile.cur_pos;
+    let (source_x, source_y) = (source.x as i32, source.y as i32);
+
+    let error = if !(0..32).contains(&target.x) || !(0..32).contains(&target.y) {
+        Some("Warp target is outside of the map")
+    } else if target.map == source_map && (target.x, target.y) == (source_x, source_y) {
+        Some("Warp target is the same tile")
+    } else if target.map != source_map
+        && !is_map_exist(target.map.x, target.map.y, target.map.group as u64)
+        && !is_temp_map_exist(target.map.x, target.map.y, target.map.group as u64)
+    {
+        Some("Warp target map does not exist")
+    } else {
+        None
+    };
+    if let Some(msg) = error {
+        alert.show_alert(systems, &AlertBuilder::new_info("Error", msg));
+        return Ok(());
+    }
+
+    let (Some(target_exit), Some(source_exit)) = (
+        get_exit_tile(target.x, target.y, target_offset),
+        get_exit_tile(source_x, source_y, source_offset),
+    ) else {
+        alert.show_alert(
+            systems,
+            &AlertBuilder::new_info("Error", "Tile in front of the door is outside of the map"),
+        );
+        return Ok(());
+    };
+
+    let warp_to = MapAttribute::Warp(WarpData {
+        map_x: target.map.x,
+        map_y: target.map.y,
+        map_group: target.map.group as u64,
+        tile_x: target_exit.0,
+        tile_y: target_exit.1,
+    });
+    let warp_back = MapAttribute::Warp(WarpData {
+        map_x: source_map.x,
+        map_y: source_map.y,
+        map_group: source_map.group as u64,
+        tile_x: source_exit.0,
+        tile_y: source_exit.1,
+    });
+
+    let was_saved = !content.data.changed;
+    let mut source_tiles = vec![(get_tile_pos(source_x, source_y), warp_to.clone())];
+
+    set_map_attribute(content, systems, source_x as u16, source_y as u16, warp_to);
+    if target.map == source_map {
+        // Both warps are kept in a single change
+        source_tiles.push((get_tile_pos(target.x, target.y), warp_back.clone()));
+        set_map_attribute(
+            content,
+            systems,
+            target.x as u16,
+            target.y as u16,
+            warp_back,
+        );
+    } else {
+        set_target_warp(content, systems, target.map, target.x, target.y, warp_back)?;
+    }
+    content.data.record_placeholder();
+
+    if let Ok(saved) = save_warp_tiles(source_map, &source_tiles) {
+        // Without other edits the map matches its file again
+        if was_saved && saved {
+            content.data.changed = false;
+            content.data.temp_saved = true;
+            content
+                .interface
+                .footer
+                .set_map_pos(systems, source_map, true);
+        }
+        content.interface.notification.add_msg(
+            systems,
+            format!(
+                "Two-Way Warp placed to [X: {} Y: {} Group: {}]",
+                target.map.x, target.map.y, target.map.group
+            ),
+            seconds,
+        );
+    } else {
+        alert.show_alert(
+            systems,
+            &AlertBuilder::new_info("Error", "Failed to save map"),
+        );
+    }
+    Ok(())
+}
+
+// The reciprocal warp is written into the target map and recorded in its history
+fn set_target_warp(
+    content: &mut Content,
+    systems: &mut SystemHolder,
+    map_pos: MapPosition,
+    x: i32,
+    y: i32,
+    attribute: MapAttribute,
+) -> Result<()> {
+    let tile_pos = get_tile_pos(x, y);
+
+    let from = if let Some(link_data) = content
+        .data
+        .link_map
+        .iter_mut()
+        .flatten()
+        .find(|link_data| link_data.pos == map_pos)
+    {
+        // The linked map keeps showing the warp, its file gets only the warp tile
+        let from = link_data.mapdata.attribute[tile_pos].clone();
+        link_data.mapdata.attribute[tile_pos] = attribute.clone();
+        link_data.changed = true;
+        let _ = link_data.edits.insert(LinkEdit::Attribute(tile_pos));
+        let _ = save_warp_tiles(map_pos, &[(tile_pos, attribute.clone())])?;
+        from
+    } else if is_temp_map_exist(map_pos.x, map_pos.y, map_pos.group as u64) {
+        // Unsaved edits of the target map stay unsaved, the warp joins them in the temp file
+        // and is saved into the map file so discarding those edits keeps the warp
+        let mut mapdata = load_temp_map_file(map_pos.x, map_pos.y, map_pos.group as u64)?;
+        let from = mapdata.attribute[tile_pos].clone();
+        mapdata.attribute[tile_pos] = attribute.clone();
+        save_temp_file(map_pos.x, map_pos.y, map_pos.group as u64, &mapdata, true)?;
+        let _ = content.data.unsaved_map.insert(map_pos);
+
+        let _ = save_warp_tiles(map_pos, &[(tile_pos, attribute.clone())])?;
+        from
+    } else {
+        let mut mapdata = load_map_file(map_pos.x, map_pos.y, map_pos.group as u64, false)?;
+        let from = mapdata.attribute[tile_pos].clone();
+        mapdata.attribute[tile_pos] = attribute.clone();
+        mapdata.position = map_pos;
+        mapdata.save_file()?;
+        from
+    };
+
+    content.data.record_map_change(
+        map_pos,
+        EditorChange::Attr(AttrChanges {
+            change: vec![AttrChangeData {
+                x: x as u16,
+                y: y as u16,
+                from,
+                to: attribute,
+            }],
+            fill: false,
+        }),
+        systems.config.save_undo_history,
+    )
+}
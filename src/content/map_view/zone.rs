@@ -0,0 +1,162 @@
+use crate::{
+    content::{
+        Content, EditorChange, ZoneListChange, update_zone_list, update_zone_textbox,
+        update_zone_visible,
+    },
+    database::{ZoneData, ZoneNpc},
+    renderer::SystemHolder,
+};
+
+fn set_zone_changed(content: &mut Content, systems: &mut SystemHolder) {
+    content.data.changed = true;
+    content.data.temp_saved = false;
+    if let Some(map_pos) = content.data.pos {
+        content
+            .interface
+            .footer
+            .set_map_pos(systems, map_pos, false);
+    }
+}
+
+pub fn add_zone(content: &mut Content, systems: &mut SystemHolder) {
+    let mapdata = &mut content.data.mapdata;
+    let zone = mapdata.zones.len();
+    let data = ZoneData::new(format!("Zone {}", zone + 1));
+
+    mapdata.zones.push(data.clone());
+    mapdata.zonespawns.push(Vec::new());
+    content
+        .data
+        .record_change(EditorChange::ZoneList(ZoneListChange {
+            zone,
+            data,
+            spawns: Vec::new(),
+            add: true,
+        }));
+
+    content.interface.side_window.zone.cur_zone = zone;
+    content.interface.side_window.zone.cur_npc = None;
+    update_zone_list(content, systems);
+    update_zone_textbox(content, systems);
+    update_zone_visible(content, systems);
+    set_zone_changed(content, systems);
+}
+
+// The spawns go along with the zone, so undo brings both back
+pub fn remove_zone(content: &mut Content, systems: &mut SystemHolder) {
+    let zone = content.interface.side_window.zone.cur_zone;
+    let mapdata = &mut content.data.mapdata;
+    if zone >= mapdata.zones.len() {
+        return;
+    }
+
+    let data = mapdata.zones.remove(zone);
+    let spawns = mapdata.zonespawns.remove(zone);
+    content
+        .data
+        .record_change(EditorChange::ZoneList(ZoneListChange {
+            zone,
+            data,
+            spawns,
+            add: false,
+        }));
+
+    content.interface.side_window.zone.cur_npc = None;
+    update_zone_list(content, systems);
+    update_zone_textbox(content, systems);
+    update_zone_visible(content, systems);
+    set_zone_changed(content, systems);
+}
+
+pub fn add_zone_npc(content: &mut Content, systems: &mut SystemHolder) {
+    let cur_zone = content.interface.side_window.zone.cur_zone;
+    let Some(zone) = content.data.mapdata.zones.get_mut(cur_zone) else {
+        return;
+    };
+
+    let last_setting = zone.clone();
+    zone.npcs.push(ZoneNpc::new(0));
+    let new_setting = zone.clone();
+    content.interface.side_window.zone.cur_npc = Some(new_setting.npcs.len() - 1);
+
+    content
+        .data
+        .record_zone_setting(cur_zone, None, last_setting, new_setting);
+    update_zone_list(content, systems);
+    update_zone_textbox(content, systems);
+    set_zone_changed(content, systems);
+}
+
+pub fn remove_zone_npc(content: &mut Content, systems: &mut SystemHolder) {
+    let gui = &content.interface.side_window.zone;
+    let cur_zone = gui.cur_zone;
+    let Some(zone) = content.data.mapdata.zones.get_mut(cur_zone) else {
+        return;
+    };
+    let Some(index) = gui.cur_npc.filter(|index| *index < zone.npcs.len()) else {
+        return;
+    };
+
+    let last_setting = zone.clone();
+    zone.npcs.remove(index);
+    let new_setting = zone.clone();
+    content.interface.side_window.zone.cur_npc = None;
+
+    content
+        .data
+        .record_zone_setting(cur_zone, None, last_setting, new_setting);
+    update_zone_list(content, systems);
+    update_zone_textbox(content, systems);
+    set_zone_changed(content, systems);
+}
+
+// Applies what was typed into the zone textboxes, the ones after Max NPC edit the selected npc
+pub fn update_zone_setting(content: &mut Content, systems: &mut SystemHolder, index: usize) {
+    let gui = &content.interface.side_window.zone;
+    let (cur_zone, cur_npc) = (gui.cur_zone, gui.cur_npc);
+    let text = gui.textbox[index].text.clone();
+
+    let Some(zone) = content.data.mapdata.zones.get_mut(cur_zone) else {
+        return;
+    };
+    let last_setting = zone.clone();
+
+    match index {
+        0 => zone.name = text,
+        1 => {
+            if let Ok(value) = text.parse() {
+                zone.max_npc = value;
+            }
+        }
+        _ => {
+            let Some(npc) = cur_npc.and_then(|npc| zone.npcs.get_mut(npc)) else {
+                return;
+            };
+
+            match index {
+                2 => npc.npc_id = text.parse().unwrap_or(npc.npc_id),
+                3 => npc.weight = text.parse().unwrap_or(npc.weight),
+                4 => npc.min_level = text.parse().unwrap_or(npc.min_level),
+                5 => npc.max_level = text.parse().unwrap_or(npc.max_level),
+                _ => npc.respawn = text.parse().unwrap_or(npc.respawn),
+            }
+        }
+    }
+
+    if *zone == last_setting {
+        return;
+    }
+
+    let new_setting = zone.clone();
+    content
+        .data
+        .record_zone_setting(cur_zone, Some((index, cur_npc)), last_setting, new_setting);
+
+    // The zone list shows the npc ids too
+    let gui = &mut content.interface.side_window.zone;
+    gui.update_zone_list(systems, &content.data.mapdata.zones);
+    if index >= 2 {
+        gui.update_npc_list(systems, content.data.mapdata.zones.get(cur_zone));
+    }
+    set_zone_changed(content, systems);
+}
@@ -1,13 +1,23 @@
+use ascending_graphics::Color;
+
 // General
 pub const TEXTURE_SIZE: u32 = 20;
 pub const TILESET_COUNT_X: u32 = 10;
 pub const TILESET_COUNT_Y: u32 = 20;
 pub const MAX_TILE: usize = 1024;
 pub const MAX_CHANGES: usize = 64;
-pub const MAX_PRESETS: usize = 100;
+// One frame for each of the anim layers
+pub const MAX_ANIM_FRAMES: u32 = 4;
 
 // Editor
 pub const MAX_VISIBLE_ATTRIBUTE: usize = 12;
+pub const ZONE_COLOR: [Color; 5] = [
+    Color::rgba(0, 60, 200, 150),
+    Color::rgba(200, 40, 40, 150),
+    Color::rgba(40, 160, 40, 150),
+    Color::rgba(210, 180, 0, 150),
+    Color::rgba(150, 40, 180, 150),
+];
 
 // GFX Z Order
 pub const ORDER_LINKED_TILE_BG: f32 = 10.0;
@@ -19,6 +19,8 @@ pub enum SelectedTextbox {
     SampleTextbox,
     AttrContent,
     ZoneTextbox,
+    TilesetTextbox,
+    PresetTextbox,
     MapPosTextbox,
 }
 
@@ -29,7 +31,9 @@ impl SelectedTextbox {
             1 => SelectedTextbox::SampleTextbox,
             2 => SelectedTextbox::AttrContent,
             3 => SelectedTextbox::ZoneTextbox,
-            4 => SelectedTextbox::MapPosTextbox,
+            4 => SelectedTextbox::TilesetTextbox,
+            5 => SelectedTextbox::PresetTextbox,
+            6 => SelectedTextbox::MapPosTextbox,
             _ => SelectedTextbox::None,
         }
     }
@@ -42,6 +46,8 @@ pub enum ToolType {
     Eraser,
     Fill,
     Picker,
+    Measure,
+    Terrain,
     Count,
 }
 
@@ -52,11 +58,37 @@ impl ToolType {
             2 => ToolType::Eraser,
             3 => ToolType::Fill,
             4 => ToolType::Picker,
+            5 => ToolType::Measure,
+            6 => ToolType::Terrain,
             _ => ToolType::Move,
         }
     }
 }
 
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum FillOption {
+    Global,
+    EightWay,
+    AllLayers,
+    Selection,
+    Pattern,
+    LinkedMaps,
+    Count,
+}
+
+impl FillOption {
+    pub fn from_index(index: usize) -> Self {
+        match index {
+            1 => FillOption::EightWay,
+            2 => FillOption::AllLayers,
+            3 => FillOption::Selection,
+            4 => FillOption::Pattern,
+            5 => FillOption::LinkedMaps,
+            _ => FillOption::Global,
+        }
+    }
+}
+
 pub fn convert_index_to_maplayers(index: usize) -> MapLayers {
     match index {
         1 => MapLayers::Mask,
@@ -71,6 +103,30 @@ pub fn convert_index_to_maplayers(index: usize) -> MapLayers {
     }
 }
 
+// Layer buttons are ordered by how they are drawn on screen,
+// this converts the button index into the map layer index
+pub fn convert_layer_button_to_index(index: usize) -> usize {
+    match index {
+        1..=4 => index + 2,
+        5 | 6 => index - 4,
+        _ => index,
+    }
+}
+
+pub fn map_layer_name(layer: usize) -> &'static str {
+    match layer {
+        1 => "Mask",
+        2 => "Mask 2",
+        3 => "Anim 1",
+        4 => "Anim 2",
+        5 => "Anim 3",
+        6 => "Anim 4",
+        7 => "Fringe",
+        8 => "Fringe 2",
+        _ => "Ground",
+    }
+}
+
 #[derive(PartialEq, Eq, Clone, Copy, Debug)]
 pub enum TabButton {
     Tileset,
@@ -80,6 +136,9 @@ pub enum TabButton {
     DirBlock,
     Weather,
     Music,
+    History,
+    WarpSource,
+    Inspector,
     //Properties,
     Count,
 }
@@ -93,7 +152,10 @@ impl TabButton {
             4 => TabButton::DirBlock,
             5 => TabButton::Weather,
             6 => TabButton::Music,
-            //7 => TabButton::Properties,
+            7 => TabButton::History,
+            8 => TabButton::WarpSource,
+            9 => TabButton::Inspector,
+            //10 => TabButton::Properties,
             _ => TabButton::Tileset,
         }
     }
@@ -1,5 +1,9 @@
 pub mod map;
 pub mod presets;
+pub mod terrain;
+pub mod tilesheet;
 
 pub use map::*;
 pub use presets::*;
+pub use terrain::*;
+pub use tilesheet::*;
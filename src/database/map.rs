@@ -10,20 +10,28 @@ use std::{
 
 pub mod data;
 pub mod editor;
+pub mod legacy;
 
 pub use data::*;
 pub use editor::*;
+use legacy::{MapDataV1, MapDataV2};
 
 use crate::data_types::{EditorError, Result};
 
+const MAP_FILE_TAG: &[u8; 4] = b"AMAP";
+pub const MAP_FILE_VERSION: u16 = 3;
+
 #[derive(Clone, Debug, Serialize, Deserialize, Readable, Writable)]
 pub struct MapData {
     pub position: MapPosition,
     pub tile: Vec<Tile>,
     pub dir_block: Vec<u8>,
     pub attribute: Vec<MapAttribute>,
-    pub zonespawns: [Vec<(u16, u16)>; 5],
-    pub zones: [(u64, [Option<u64>; 5]); 5],
+    // Frame duration in milliseconds of the animated tiles on each map tile
+    pub anim_time: Vec<u32>,
+    // Spawn tiles of each zone, kept at the same index as its zone data
+    pub zonespawns: Vec<Vec<(u16, u16)>>,
+    pub zones: Vec<ZoneData>,
     pub music: Option<String>,
     pub weather: Weather,
 }
@@ -39,20 +47,50 @@ impl MapData {
             tile: vec![Tile { id: vec![0; 1024] }; 9],
             dir_block: vec![0; 1024],
             attribute: vec![MapAttribute::Walkable; 1024],
-            zonespawns: Default::default(),
-            zones: Default::default(),
+            anim_time: vec![DEFAULT_ANIM_TIME; 1024],
+            zonespawns: Vec::new(),
+            zones: Vec::new(),
             music: None,
             weather: Weather::default(),
         }
     }
 
+    // Map files start with the format tag and version, untagged files are read as the first format
+    pub fn to_file_bytes(&self) -> Vec<u8> {
+        let mut bytes = MAP_FILE_TAG.to_vec();
+        bytes.extend_from_slice(&MAP_FILE_VERSION.to_le_bytes());
+        bytes.extend(self.write_to_vec().unwrap());
+        bytes
+    }
+
+    pub fn from_file_bytes(bytes: &[u8]) -> Result<MapData> {
+        let result = match bytes.strip_prefix(MAP_FILE_TAG.as_slice()) {
+            Some(data) if data.len() >= 2 => match u16::from_le_bytes([data[0], data[1]]) {
+                2 => MapDataV2::read_from_buffer(&data[2..]).map(MapData::from),
+                MAP_FILE_VERSION => MapData::read_from_buffer(&data[2..]),
+                version => {
+                    return Err(EditorError::Other {
+                        source: OtherError::new(&format!("Unknown map file version {version}")),
+                        backtrace: Backtrace::new(),
+                    });
+                }
+            },
+            _ => MapDataV1::read_from_buffer(bytes).map(MapData::from),
+        };
+
+        result.map_err(|e| EditorError::Other {
+            source: OtherError::new(&format!("Failed to read map data, Err {e:?}")),
+            backtrace: Backtrace::new(),
+        })
+    }
+
     pub fn save_file(&self) -> Result<()> {
         let name = format!(
             "./data/maps/{}_{}_{}.bin",
             self.position.x, self.position.y, self.position.group
         );
 
-        let bytes = self.write_to_vec().unwrap();
+        let bytes = self.to_file_bytes();
 
         match OpenOptions::new()
             .truncate(true)
@@ -77,6 +115,41 @@ impl MapData {
         }
     }
 
+    // Lists the tiles holding a warp that leads into the target map
+    pub fn get_warp_to(&self, target: MapPosition) -> Vec<(u32, u32, WarpData)> {
+        self.attribute
+            .iter()
+            .enumerate()
+            .filter_map(|(index, attribute)| match attribute {
+                MapAttribute::Warp(warp)
+                    if warp.map_x == target.x
+                        && warp.map_y == target.y
+                        && warp.map_group == target.group as u64 =>
+                {
+                    Some(((index % 32) as u32, (index / 32) as u32, warp.clone()))
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    pub fn retarget_warp(&mut self, from: MapPosition, to: MapPosition) -> bool {
+        let mut changed = false;
+        for attribute in self.attribute.iter_mut() {
+            if let MapAttribute::Warp(warp) = attribute
+                && warp.map_x == from.x
+                && warp.map_y == from.y
+                && warp.map_group == from.group as u64
+            {
+                warp.map_x = to.x;
+                warp.map_y = to.y;
+                warp.map_group = to.group as u64;
+                changed = true;
+            }
+        }
+        changed
+    }
+
     pub fn save_temp_file(&self, exist: bool) -> Result<()> {
         let name = if exist {
             format!(
@@ -87,7 +160,7 @@ impl MapData {
             "./temp/recovery.bin".to_string()
         };
 
-        let bytes = self.write_to_vec().unwrap();
+        let bytes = self.to_file_bytes();
 
         match OpenOptions::new()
             .truncate(true)
@@ -120,7 +193,7 @@ pub fn save_temp_file(x: i32, y: i32, group: u64, data: &MapData, exist: bool) -
         "./temp/recovery.bin".to_string()
     };
 
-    let bytes = data.write_to_vec().unwrap();
+    let bytes = data.to_file_bytes();
 
     match OpenOptions::new().write(true).create_new(true).open(&name) {
         Ok(mut file) => {
@@ -144,7 +217,7 @@ pub fn save_temp_file(x: i32, y: i32, group: u64, data: &MapData, exist: bool) -
 pub fn create_map_file(x: i32, y: i32, group: u64, data: &MapData) -> Result<()> {
     let name = format!("./data/maps/{x}_{y}_{group}.bin");
 
-    let bytes = data.write_to_vec().unwrap();
+    let bytes = data.to_file_bytes();
 
     match OpenOptions::new().write(true).create_new(true).open(&name) {
         Ok(mut file) => {
@@ -183,7 +256,7 @@ pub fn load_map_file(x: i32, y: i32, group: u64, create_file: bool) -> Result<Ma
         Ok(mut file) => {
             let mut bytes = Vec::new();
             file.read_to_end(&mut bytes)?;
-            Ok(MapData::read_from_buffer(&bytes).unwrap())
+            MapData::from_file_bytes(&bytes)
         }
         Err(_) => Ok(MapData::default(x, y, group)),
     }
@@ -200,7 +273,7 @@ pub fn load_temp_map_file(x: i32, y: i32, group: u64) -> Result<MapData> {
         Ok(mut file) => {
             let mut bytes = Vec::new();
             file.read_to_end(&mut bytes)?;
-            Ok(MapData::read_from_buffer(&bytes).unwrap())
+            MapData::from_file_bytes(&bytes)
         }
         Err(_) => Ok(MapData::default(x, y, group)),
     }
@@ -209,6 +282,63 @@ pub fn load_temp_map_file(x: i32, y: i32, group: u64) -> Result<MapData> {
 pub fn delete_temp_map_file(x: i32, y: i32, group: u64) -> Result<()> {
     let name: String = format!("./temp/{x}_{y}_{group}.bin");
     fs::remove_file(name)?;
+    delete_history_file(x, y, group, true)?;
+    Ok(())
+}
+
+fn get_history_file_name(x: i32, y: i32, group: u64, exist: bool) -> String {
+    if exist {
+        format!("./temp/{x}_{y}_{group}_history.bin")
+    } else {
+        "./temp/recovery_history.bin".to_string()
+    }
+}
+
+pub fn save_history_file(x: i32, y: i32, group: u64, bytes: &[u8], exist: bool) -> Result<()> {
+    let name = get_history_file_name(x, y, group, exist);
+
+    match OpenOptions::new()
+        .truncate(true)
+        .write(true)
+        .create(true)
+        .open(&name)
+    {
+        Ok(mut file) => {
+            if let Err(e) = file.write(bytes) {
+                Err(EditorError::Other {
+                    source: OtherError::new(&format!("File Error Err {e:?}",)),
+                    backtrace: Backtrace::new(),
+                })
+            } else {
+                Ok(())
+            }
+        }
+        Err(e) => Err(EditorError::Other {
+            source: OtherError::new(&format!("Failed to open {name}, Err {e:?}",)),
+            backtrace: Backtrace::new(),
+        }),
+    }
+}
+
+pub fn load_history_file(x: i32, y: i32, group: u64, exist: bool) -> Result<Option<Vec<u8>>> {
+    let name = get_history_file_name(x, y, group, exist);
+
+    if !Path::new(&name).exists() {
+        return Ok(None);
+    }
+
+    let mut file = OpenOptions::new().read(true).open(name)?;
+    let mut bytes = Vec::new();
+    file.read_to_end(&mut bytes)?;
+    Ok(Some(bytes))
+}
+
+pub fn delete_history_file(x: i32, y: i32, group: u64, exist: bool) -> Result<()> {
+    let name = get_history_file_name(x, y, group, exist);
+
+    if Path::new(&name).exists() {
+        fs::remove_file(name)?;
+    }
     Ok(())
 }
 
@@ -224,7 +354,7 @@ pub fn load_recovery_map_file() -> Result<MapData> {
         Ok(mut file) => {
             let mut bytes = Vec::new();
             file.read_to_end(&mut bytes)?;
-            Ok(MapData::read_from_buffer(&bytes).unwrap())
+            MapData::from_file_bytes(&bytes)
         }
         Err(_) => Ok(MapData::default(0, 0, 0)),
     }
@@ -233,6 +363,7 @@ pub fn load_recovery_map_file() -> Result<MapData> {
 pub fn delete_recovery_map_file() -> Result<()> {
     let name: String = "./temp/recovery.bin".to_string();
     fs::remove_file(name)?;
+    delete_history_file(0, 0, 0, false)?;
     Ok(())
 }
 
@@ -250,6 +381,162 @@ pub fn is_map_exist(x: i32, y: i32, group: u64) -> bool {
     Path::new(&name).exists()
 }
 
+pub fn delete_map_file(x: i32, y: i32, group: u64) -> Result<()> {
+    let name: String = format!("./data/maps/{x}_{y}_{group}.bin");
+    fs::remove_file(name)?;
+    Ok(())
+}
+
+// Moves the temp file and its history, the position inside the temp map is updated too
+pub fn move_temp_map_file(from: MapPosition, to: MapPosition) -> Result<()> {
+    let mut data = load_temp_map_file(from.x, from.y, from.group as u64)?;
+    data.position = to;
+    data.save_temp_file(true)?;
+    fs::remove_file(format!("./temp/{}_{}_{}.bin", from.x, from.y, from.group))?;
+
+    let history_name = get_history_file_name(from.x, from.y, from.group as u64, true);
+    if Path::new(&history_name).exists() {
+        fs::rename(
+            history_name,
+            get_history_file_name(to.x, to.y, to.group as u64, true),
+        )?;
+    }
+    Ok(())
+}
+
+fn parse_map_file_name(name: &str) -> Option<MapPosition> {
+    let mut value = name.strip_suffix(".bin")?.split('_');
+
+    let x = value.next()?.parse::<i32>().ok()?;
+    let y = value.next()?.parse::<i32>().ok()?;
+    let group = value.next()?.parse::<i32>().ok()?;
+    if value.next().is_some() {
+        return None;
+    }
+    Some(MapPosition { x, y, group })
+}
+
+fn get_map_list_in(path: &str) -> Result<Vec<MapPosition>> {
+    if !Path::new(path).exists() {
+        return Ok(Vec::new());
+    }
+
+    let mut list = Vec::new();
+    for entry in fs::read_dir(path)? {
+        let entry = entry?;
+        if let Some(map_pos) = entry.file_name().to_str().and_then(parse_map_file_name) {
+            list.push(map_pos);
+        }
+    }
+    Ok(list)
+}
+
+pub fn get_map_file_list() -> Result<Vec<MapPosition>> {
+    get_map_list_in("./data/maps")
+}
+
+// History and recovery files do not follow the x_y_group name, so they are skipped
+pub fn get_temp_map_file_list() -> Result<Vec<MapPosition>> {
+    get_map_list_in("./temp")
+}
+
+// Points the warps of every saved and temp map that lead to the old position to the new one
+pub fn update_warp_target(from: MapPosition, to: MapPosition) -> Result<()> {
+    for map_pos in get_map_file_list()? {
+        let mut data = load_map_file(map_pos.x, map_pos.y, map_pos.group as u64, false)?;
+        if data.retarget_warp(from, to) {
+            data.position = map_pos;
+            data.save_file()?;
+        }
+    }
+
+    for map_pos in get_temp_map_file_list()? {
+        let mut data = load_temp_map_file(map_pos.x, map_pos.y, map_pos.group as u64)?;
+        if data.retarget_warp(from, to) {
+            data.position = map_pos;
+            data.save_temp_file(true)?;
+        }
+    }
+    Ok(())
+}
+
+// Template names become file names, so only plain characters are allowed
+pub fn is_valid_template_name(name: &str) -> bool {
+    !name.trim().is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, ' ' | '-' | '_'))
+}
+
+pub fn save_map_template(name: &str, data: &MapData) -> Result<()> {
+    let name = format!("./data/templates/{}.bin", name.trim());
+
+    let bytes = data.to_file_bytes();
+
+    match OpenOptions::new()
+        .truncate(true)
+        .write(true)
+        .create(true)
+        .open(&name)
+    {
+        Ok(mut file) => {
+            if let Err(e) = file.write(bytes.as_slice()) {
+                Err(EditorError::Other {
+                    source: OtherError::new(&format!("File Error Err {e:?}",)),
+                    backtrace: Backtrace::new(),
+                })
+            } else {
+                Ok(())
+            }
+        }
+        Err(e) => Err(EditorError::Other {
+            source: OtherError::new(&format!("Failed to open {name}, Err {e:?}",)),
+            backtrace: Backtrace::new(),
+        }),
+    }
+}
+
+pub fn load_map_template(name: &str) -> Result<MapData> {
+    let name = format!("./data/templates/{name}.bin");
+
+    let mut file = OpenOptions::new().read(true).open(&name)?;
+    let mut bytes = Vec::new();
+    file.read_to_end(&mut bytes)?;
+    MapData::from_file_bytes(&bytes)
+}
+
+pub fn get_map_template_list() -> Result<Vec<String>> {
+    let path = "./data/templates";
+    if !Path::new(path).exists() {
+        return Ok(Vec::new());
+    }
+
+    let mut list = Vec::new();
+    for entry in fs::read_dir(path)? {
+        let entry = entry?;
+        if let Some(name) = entry
+            .file_name()
+            .to_str()
+            .and_then(|name| name.strip_suffix(".bin"))
+        {
+            list.push(name.to_string());
+        }
+    }
+    list.sort();
+    Ok(list)
+}
+
+// Creates the map file of a new position out of a template instead of an empty map
+pub fn create_map_from_template(x: i32, y: i32, group: u64, name: &str) -> Result<()> {
+    let mut data = load_map_template(name)?;
+    data.position = MapPosition {
+        x,
+        y,
+        group: group as i32,
+    };
+    create_map_file(x, y, group, &data)
+}
+
 pub fn save_and_clear_map(x: i32, y: i32, group: u64) -> Result<()> {
     if let Ok(data) = load_temp_map_file(x, y, group) {
         delete_temp_map_file(x, y, group)?;
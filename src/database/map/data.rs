@@ -16,6 +16,9 @@ pub struct Position {
     pub map: MapPosition,
 }
 
+// Frame duration in milliseconds of the animated tiles when none was set
+pub const DEFAULT_ANIM_TIME: u32 = 250;
+
 #[derive(Clone, Debug, Serialize, Deserialize, Readable, Writable)]
 pub struct Tile {
     pub id: Vec<u32>,
@@ -114,3 +117,43 @@ pub struct ItemSpawnData {
     pub amount: u16,
     pub timer: u64,
 }
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, Readable, Writable)]
+pub struct ZoneNpc {
+    pub npc_id: u64,
+    // Chance of this npc being picked against the other entries of the zone
+    pub weight: u32,
+    pub min_level: u32,
+    pub max_level: u32,
+    // Seconds until a killed npc spawns again
+    pub respawn: u64,
+}
+
+impl ZoneNpc {
+    pub fn new(npc_id: u64) -> Self {
+        Self {
+            npc_id,
+            weight: 1,
+            min_level: 1,
+            max_level: 1,
+            respawn: 60,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, Default, Readable, Writable)]
+pub struct ZoneData {
+    pub name: String,
+    pub max_npc: u64,
+    pub npcs: Vec<ZoneNpc>,
+}
+
+impl ZoneData {
+    pub fn new(name: String) -> Self {
+        Self {
+            name,
+            max_npc: 0,
+            npcs: Vec::new(),
+        }
+    }
+}
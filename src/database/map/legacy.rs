@@ -0,0 +1,76 @@
+use serde::{Deserialize, Serialize};
+use speedy::{Readable, Writable};
+
+use crate::database::{
+    DEFAULT_ANIM_TIME, MapAttribute, MapData, MapPosition, Tile, Weather, ZoneData, ZoneNpc,
+};
+
+// Untagged map files from before the format version, they only got five zones
+// with up to five npc ids each
+#[derive(Clone, Debug, Serialize, Deserialize, Readable, Writable)]
+pub struct MapDataV1 {
+    pub position: MapPosition,
+    pub tile: Vec<Tile>,
+    pub dir_block: Vec<u8>,
+    pub attribute: Vec<MapAttribute>,
+    pub zonespawns: [Vec<(u16, u16)>; 5],
+    pub zones: [(u64, [Option<u64>; 5]); 5],
+    pub music: Option<String>,
+    pub weather: Weather,
+}
+
+impl From<MapDataV1> for MapData {
+    fn from(data: MapDataV1) -> Self {
+        let zones = data
+            .zones
+            .iter()
+            .enumerate()
+            .map(|(i, (max_npc, npcs))| ZoneData {
+                name: format!("Zone {}", i + 1),
+                max_npc: *max_npc,
+                npcs: npcs.iter().flatten().map(|id| ZoneNpc::new(*id)).collect(),
+            })
+            .collect();
+
+        MapData {
+            position: data.position,
+            tile: data.tile,
+            dir_block: data.dir_block,
+            attribute: data.attribute,
+            anim_time: vec![DEFAULT_ANIM_TIME; 1024],
+            zonespawns: data.zonespawns.into(),
+            zones,
+            music: data.music,
+            weather: data.weather,
+        }
+    }
+}
+
+// Map files of version 2, the animated tiles all played at the same speed
+#[derive(Clone, Debug, Serialize, Deserialize, Readable, Writable)]
+pub struct MapDataV2 {
+    pub position: MapPosition,
+    pub tile: Vec<Tile>,
+    pub dir_block: Vec<u8>,
+    pub attribute: Vec<MapAttribute>,
+    pub zonespawns: Vec<Vec<(u16, u16)>>,
+    pub zones: Vec<ZoneData>,
+    pub music: Option<String>,
+    pub weather: Weather,
+}
+
+impl From<MapDataV2> for MapData {
+    fn from(data: MapDataV2) -> Self {
+        MapData {
+            position: data.position,
+            tile: data.tile,
+            dir_block: data.dir_block,
+            attribute: data.attribute,
+            anim_time: vec![DEFAULT_ANIM_TIME; 1024],
+            zonespawns: data.zonespawns,
+            zones: data.zones,
+            music: data.music,
+            weather: data.weather,
+        }
+    }
+}
@@ -1,14 +1,23 @@
 use ascending_graphics::OtherError;
 use serde::{Deserialize, Serialize};
 use snafu::Backtrace;
-use speedy::{Endianness, Readable, Writable};
+use speedy::{Readable, Writable};
 use std::{
-    fs::{self, File, OpenOptions},
-    io::{Read, Write},
+    collections::HashMap,
+    fs::OpenOptions,
+    io::{BufReader, Read, Write},
     path::Path,
 };
 
-use crate::data_types::{EditorError, MAX_PRESETS, Result};
+use crate::{
+    data_types::{EditorError, Result},
+    database::DEFAULT_ANIM_TIME,
+};
+
+const PRESET_FILE: &str = "./mapeditor/data/presets.json";
+const LEGACY_PRESET_DIR: &str = "./mapeditor/data/presets/";
+const LEGACY_PRESET_COUNT: usize = 100;
+const BUNDLE_DIR: &str = "./mapeditor/bundles/";
 
 #[derive(
     Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, Readable, Writable, Default,
@@ -19,6 +28,8 @@ pub enum PresetTypeList {
     Animated,
     AutoTile,
     AutotileAnimated,
+    BlobTile,
+    BlobTileAnimated,
 }
 
 impl PresetTypeList {
@@ -27,9 +38,33 @@ impl PresetTypeList {
             1 => PresetTypeList::Animated,
             2 => PresetTypeList::AutoTile,
             3 => PresetTypeList::AutotileAnimated,
+            4 => PresetTypeList::BlobTile,
+            5 => PresetTypeList::BlobTileAnimated,
             _ => PresetTypeList::Normal,
         }
     }
+
+    pub fn is_animated(self) -> bool {
+        matches!(
+            self,
+            PresetTypeList::Animated
+                | PresetTypeList::AutotileAnimated
+                | PresetTypeList::BlobTileAnimated
+        )
+    }
+
+    pub fn is_autotile(self) -> bool {
+        self.autotile_size().is_some()
+    }
+
+    // Size in tiles of the tile sheet area that holds the autotile pieces
+    pub fn autotile_size(self) -> Option<(u32, u32)> {
+        match self {
+            PresetTypeList::AutoTile | PresetTypeList::AutotileAnimated => Some((5, 3)),
+            PresetTypeList::BlobTile | PresetTypeList::BlobTileAnimated => Some((8, 6)),
+            _ => None,
+        }
+    }
 }
 
 #[derive(
@@ -49,76 +84,185 @@ pub struct PresetFrames {
     pub tileset: u16,
 }
 
-#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, Readable, Writable, Default)]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
 pub struct PresetData {
     pub name: String,
+    // Folder the preset is listed under, empty when it has none
+    #[serde(default)]
+    pub category: String,
     pub draw_type: PresetTypeList,
     pub frames: [PresetFrames; 4],
+    // Frame duration in milliseconds, used by the animated types
+    #[serde(default = "default_anim_time")]
+    pub anim_time: u32,
+}
+
+impl Default for PresetData {
+    fn default() -> Self {
+        Self {
+            name: String::new(),
+            category: String::new(),
+            draw_type: PresetTypeList::default(),
+            frames: [PresetFrames::default(); 4],
+            anim_time: DEFAULT_ANIM_TIME,
+        }
+    }
+}
+
+fn default_anim_time() -> u32 {
+    DEFAULT_ANIM_TIME
+}
+
+// Layout of the p{i}.bin files used before the preset library
+#[derive(Readable, Default)]
+struct LegacyPresetData {
+    name: String,
+    draw_type: PresetTypeList,
+    frames: [PresetFrames; 4],
+}
+
+impl From<LegacyPresetData> for PresetData {
+    fn from(data: LegacyPresetData) -> Self {
+        PresetData {
+            name: data.name,
+            category: String::new(),
+            draw_type: data.draw_type,
+            frames: data.frames,
+            anim_time: DEFAULT_ANIM_TIME,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, Default)]
+struct PresetLibrary {
+    #[serde(default)]
+    presets: Vec<PresetData>,
+}
+
+// Tiles of the autotile presets mapped to the preset and frame they belong to
+#[derive(Default)]
+pub struct AutotileLookup {
+    pub tiles: HashMap<usize, (usize, usize)>,
+    // Every tile of each preset, empty for the presets that are no autotile
+    pub preset_tiles: Vec<Vec<usize>>,
 }
 
 pub struct Presets {
     pub data: Vec<PresetData>,
     pub selected_preset_tiles: Vec<usize>,
+    // Built on first use and dropped whenever the presets get saved
+    pub autotile_lookup: Option<AutotileLookup>,
 }
 
 impl Presets {
     pub fn load_data() -> Result<Self> {
-        let mut data = Vec::with_capacity(MAX_PRESETS);
-
-        for i in 0..MAX_PRESETS {
-            let name: String = format!("./mapeditor/data/presets/p{i}.bin");
-
-            data.push(if !Path::new(&name).exists() {
-                let pd_data = PresetData::default();
-
-                let bytes = pd_data.write_to_vec().unwrap();
-
-                match OpenOptions::new().write(true).create_new(true).open(&name) {
-                    Ok(mut file) => {
-                        if let Err(e) = file.write(bytes.as_slice()) {
-                            return Err(EditorError::Other {
-                                source: OtherError::new(&format!("File Error Err {e:?}",)),
-                                backtrace: Backtrace::new(),
-                            });
-                        }
-                    }
-                    Err(e) => {
-                        return Err(EditorError::Other {
-                            source: OtherError::new(&format!("Failed to open {name}, Err {e:?}",)),
-                            backtrace: Backtrace::new(),
-                        });
-                    }
-                }
-
-                pd_data
-            } else {
-                match OpenOptions::new().read(true).open(name) {
-                    Ok(mut file) => {
-                        let mut bytes = Vec::new();
-                        file.read_to_end(&mut bytes)?;
-                        PresetData::read_from_buffer(&bytes).unwrap()
-                    }
-                    Err(_) => PresetData::default(),
-                }
-            });
-        }
+        let data = if Path::new(PRESET_FILE).exists() {
+            let file = OpenOptions::new().read(true).open(PRESET_FILE)?;
+            let library: PresetLibrary =
+                serde_json::from_reader(BufReader::new(file)).map_err(|e| EditorError::Other {
+                    source: OtherError::new(&format!("Failed to read {PRESET_FILE}, Err {e:?}",)),
+                    backtrace: Backtrace::new(),
+                })?;
+            library.presets
+        } else {
+            let data = load_legacy_presets()?;
+            save_library(&data)?;
+            data
+        };
 
         Ok(Presets {
             data,
             selected_preset_tiles: Vec::with_capacity(52),
+            autotile_lookup: None,
         })
     }
 
-    pub fn save_preset(&self, index: usize) -> Result<()> {
-        let name: String = format!("./mapeditor/data/presets/p{index}.bin");
+    pub fn save_data(&mut self) -> Result<()> {
+        self.autotile_lookup = None;
+        save_library(&self.data)
+    }
+
+    // Categories in use, sorted by name
+    pub fn get_categories(&self) -> Vec<String> {
+        let mut categories: Vec<String> = self
+            .data
+            .iter()
+            .filter(|data| !data.category.is_empty())
+            .map(|data| data.category.clone())
+            .collect();
+        categories.sort_by_key(|category| category.to_lowercase());
+        categories.dedup();
+        categories
+    }
+}
+
+// Frame of a bundled preset, the tile sheet is kept by name as other projects
+// can load their tile sheets in another order
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub struct BundleFrame {
+    pub start: PresetPos,
+    pub end: PresetPos,
+    // Empty on the frames the preset does not use
+    #[serde(default)]
+    pub tileset: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub struct BundlePreset {
+    pub name: String,
+    #[serde(default)]
+    pub category: String,
+    pub draw_type: PresetTypeList,
+    pub frames: [BundleFrame; 4],
+    #[serde(default = "default_anim_time")]
+    pub anim_time: u32,
+}
+
+// Presets shared between projects
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub struct PresetBundle {
+    #[serde(default)]
+    pub presets: Vec<BundlePreset>,
+}
 
-        let bytes = self.data[index].write_to_vec().unwrap();
+fn bundle_path(name: &str) -> String {
+    format!("{BUNDLE_DIR}{}.json", name.trim())
+}
+
+impl PresetBundle {
+    pub fn load_data(name: &str) -> Result<Self> {
+        let path = bundle_path(name);
+        if !Path::new(&path).exists() {
+            return Err(EditorError::Other {
+                source: OtherError::new(&format!("{path} does not exist")),
+                backtrace: Backtrace::new(),
+            });
+        }
+
+        let file = OpenOptions::new().read(true).open(&path)?;
+        serde_json::from_reader(BufReader::new(file)).map_err(|e| EditorError::Other {
+            source: OtherError::new(&format!("Failed to read {path}, Err {e:?}",)),
+            backtrace: Backtrace::new(),
+        })
+    }
+
+    pub fn save_data(&self, name: &str) -> Result<()> {
+        let path = bundle_path(name);
+        let bytes = match serde_json::to_vec_pretty(self) {
+            Ok(bytes) => bytes,
+            Err(e) => {
+                return Err(EditorError::Other {
+                    source: OtherError::new(&format!("Serdes File Error Err {e:?}",)),
+                    backtrace: Backtrace::new(),
+                });
+            }
+        };
 
         match OpenOptions::new()
             .truncate(true)
             .write(true)
             .create(true)
-            .open(&name)
+            .open(&path)
         {
             Ok(mut file) => {
                 if let Err(e) = file.write(bytes.as_slice()) {
@@ -131,9 +275,81 @@ impl Presets {
                 }
             }
             Err(e) => Err(EditorError::Other {
-                source: OtherError::new(&format!("Failed to open {name}, Err {e:?}",)),
+                source: OtherError::new(&format!("Failed to open {path}, Err {e:?}",)),
                 backtrace: Backtrace::new(),
             }),
         }
     }
 }
+
+// Imports the old preset files, the unused slots at the end are dropped
+// while the rest keep their index so the terrains still point at the same presets
+fn load_legacy_presets() -> Result<Vec<PresetData>> {
+    let mut data = Vec::with_capacity(LEGACY_PRESET_COUNT);
+
+    for i in 0..LEGACY_PRESET_COUNT {
+        let name: String = format!("{LEGACY_PRESET_DIR}p{i}.bin");
+
+        data.push(if !Path::new(&name).exists() {
+            PresetData::default()
+        } else {
+            match OpenOptions::new().read(true).open(&name) {
+                Ok(mut file) => {
+                    let mut bytes = Vec::new();
+                    file.read_to_end(&mut bytes)?;
+                    LegacyPresetData::read_from_buffer(&bytes)
+                        .unwrap_or_default()
+                        .into()
+                }
+                Err(_) => PresetData::default(),
+            }
+        });
+    }
+
+    while data
+        .last()
+        .is_some_and(|preset| *preset == PresetData::default())
+    {
+        data.pop();
+    }
+
+    Ok(data)
+}
+
+fn save_library(data: &[PresetData]) -> Result<()> {
+    let library = PresetLibrary {
+        presets: data.to_vec(),
+    };
+
+    let bytes = match serde_json::to_vec_pretty(&library) {
+        Ok(bytes) => bytes,
+        Err(e) => {
+            return Err(EditorError::Other {
+                source: OtherError::new(&format!("Serdes File Error Err {e:?}",)),
+                backtrace: Backtrace::new(),
+            });
+        }
+    };
+
+    match OpenOptions::new()
+        .truncate(true)
+        .write(true)
+        .create(true)
+        .open(PRESET_FILE)
+    {
+        Ok(mut file) => {
+            if let Err(e) = file.write(bytes.as_slice()) {
+                Err(EditorError::Other {
+                    source: OtherError::new(&format!("File Error Err {e:?}",)),
+                    backtrace: Backtrace::new(),
+                })
+            } else {
+                Ok(())
+            }
+        }
+        Err(e) => Err(EditorError::Other {
+            source: OtherError::new(&format!("Failed to open {PRESET_FILE}, Err {e:?}",)),
+            backtrace: Backtrace::new(),
+        }),
+    }
+}
@@ -0,0 +1,101 @@
+use ascending_graphics::OtherError;
+use serde::{Deserialize, Serialize};
+use snafu::Backtrace;
+use std::{
+    fs::OpenOptions,
+    io::{BufReader, Write},
+    path::Path,
+};
+
+use crate::data_types::{EditorError, Result};
+
+const TERRAIN_FILE: &str = "./mapeditor/data/terrains.json";
+
+// A terrain is painted with an autotile preset on its own map layer
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub struct TerrainData {
+    pub name: String,
+    pub preset: usize,
+    pub layer: usize,
+}
+
+// Autotile preset that draws the edges of `to` where it meets `from`
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub struct TerrainTransition {
+    pub from: usize,
+    pub to: usize,
+    pub preset: usize,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub struct Terrains {
+    #[serde(default)]
+    pub terrain: Vec<TerrainData>,
+    #[serde(default)]
+    pub transition: Vec<TerrainTransition>,
+    // Reading the file again failed, saving then leaves the broken file for the user to fix
+    #[serde(skip)]
+    pub load_failed: bool,
+}
+
+impl Terrains {
+    // The file is edited by hand, an empty one is written on the first run
+    pub fn load_data() -> Result<Self> {
+        if !Path::new(TERRAIN_FILE).exists() {
+            let data = Terrains::default();
+            data.save_data()?;
+            return Ok(data);
+        }
+
+        let file = OpenOptions::new().read(true).open(TERRAIN_FILE)?;
+        serde_json::from_reader(BufReader::new(file)).map_err(|e| EditorError::Other {
+            source: OtherError::new(&format!("Failed to read {TERRAIN_FILE}, Err {e:?}",)),
+            backtrace: Backtrace::new(),
+        })
+    }
+
+    pub fn save_data(&self) -> Result<()> {
+        if self.load_failed {
+            return Ok(());
+        }
+
+        let bytes = match serde_json::to_vec_pretty(self) {
+            Ok(bytes) => bytes,
+            Err(e) => {
+                return Err(EditorError::Other {
+                    source: OtherError::new(&format!("Serdes File Error Err {e:?}",)),
+                    backtrace: Backtrace::new(),
+                });
+            }
+        };
+
+        match OpenOptions::new()
+            .truncate(true)
+            .write(true)
+            .create(true)
+            .open(TERRAIN_FILE)
+        {
+            Ok(mut file) => {
+                if let Err(e) = file.write(bytes.as_slice()) {
+                    Err(EditorError::Other {
+                        source: OtherError::new(&format!("File Error Err {e:?}",)),
+                        backtrace: Backtrace::new(),
+                    })
+                } else {
+                    Ok(())
+                }
+            }
+            Err(e) => Err(EditorError::Other {
+                source: OtherError::new(&format!("Failed to open {TERRAIN_FILE}, Err {e:?}",)),
+                backtrace: Backtrace::new(),
+            }),
+        }
+    }
+
+    pub fn get_transition(&self, from: usize, to: usize) -> Option<usize> {
+        self.transition
+            .iter()
+            .find(|transition| transition.from == from && transition.to == to)
+            .map(|transition| transition.preset)
+    }
+}
@@ -0,0 +1,158 @@
+use ascending_graphics::OtherError;
+use serde::{Deserialize, Serialize};
+use snafu::Backtrace;
+use std::{
+    fs::OpenOptions,
+    io::{BufReader, Write},
+    path::Path,
+};
+
+use crate::data_types::{EditorError, Result};
+
+// Collision a tile brings along when it gets painted on a map
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub struct TileCollision {
+    #[serde(default)]
+    pub blocked: bool,
+    #[serde(default)]
+    pub npc_blocked: bool,
+    // Same bits as the map dir blocks
+    #[serde(default)]
+    pub dir_block: u8,
+}
+
+impl TileCollision {
+    pub fn is_empty(&self) -> bool {
+        !self.blocked && !self.npc_blocked && self.dir_block == 0
+    }
+
+    // Tiles stacked on the layers of a map tile add up their collision
+    pub fn merge(&mut self, other: &TileCollision) {
+        self.blocked |= other.blocked;
+        self.npc_blocked |= other.npc_blocked;
+        self.dir_block |= other.dir_block;
+    }
+}
+
+// x and y are the column and row of the tile, counted from the top left of the image
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub struct TileMeta {
+    pub x: u32,
+    pub y: u32,
+    #[serde(flatten)]
+    pub collision: TileCollision,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    // The frames are laid out to the right of the tile, each one goes on its own anim layer
+    #[serde(default)]
+    pub anim_frames: u32,
+}
+
+impl TileMeta {
+    pub fn new(x: u32, y: u32) -> Self {
+        TileMeta {
+            x,
+            y,
+            ..Default::default()
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.collision.is_empty() && self.tags.is_empty() && self.anim_frames <= 1
+    }
+
+    pub fn has_tag(&self, tag: &str) -> bool {
+        self.tags
+            .iter()
+            .any(|check| check.eq_ignore_ascii_case(tag))
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub struct TilesheetMeta {
+    // Shown in the tile sheet lists instead of the file name when set
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub tiles: Vec<TileMeta>,
+}
+
+// The metadata sits next to the tile sheet image, t0.png uses t0.json
+pub fn tilesheet_meta_path(image_path: &str) -> String {
+    Path::new(image_path)
+        .with_extension("json")
+        .to_string_lossy()
+        .to_string()
+}
+
+impl TilesheetMeta {
+    // Tile sheets without a metadata file have no collision
+    pub fn load_data(image_path: &str) -> Result<Self> {
+        let path = tilesheet_meta_path(image_path);
+        if !Path::new(&path).exists() {
+            return Ok(TilesheetMeta::default());
+        }
+
+        let file = OpenOptions::new().read(true).open(&path)?;
+        serde_json::from_reader(BufReader::new(file)).map_err(|e| EditorError::Other {
+            source: OtherError::new(&format!("Failed to read {path}, Err {e:?}",)),
+            backtrace: Backtrace::new(),
+        })
+    }
+
+    pub fn save_data(&mut self, image_path: &str) -> Result<()> {
+        self.tiles.retain(|tile| !tile.is_empty());
+
+        let path = tilesheet_meta_path(image_path);
+        let bytes = match serde_json::to_vec_pretty(self) {
+            Ok(bytes) => bytes,
+            Err(e) => {
+                return Err(EditorError::Other {
+                    source: OtherError::new(&format!("Serdes File Error Err {e:?}",)),
+                    backtrace: Backtrace::new(),
+                });
+            }
+        };
+
+        match OpenOptions::new()
+            .truncate(true)
+            .write(true)
+            .create(true)
+            .open(&path)
+        {
+            Ok(mut file) => {
+                if let Err(e) = file.write(bytes.as_slice()) {
+                    Err(EditorError::Other {
+                        source: OtherError::new(&format!("File Error Err {e:?}",)),
+                        backtrace: Backtrace::new(),
+                    })
+                } else {
+                    Ok(())
+                }
+            }
+            Err(e) => Err(EditorError::Other {
+                source: OtherError::new(&format!("Failed to open {path}, Err {e:?}",)),
+                backtrace: Backtrace::new(),
+            }),
+        }
+    }
+
+    pub fn get_tile(&self, x: u32, y: u32) -> Option<&TileMeta> {
+        self.tiles.iter().find(|tile| tile.x == x && tile.y == y)
+    }
+
+    pub fn get_tile_mut(&mut self, x: u32, y: u32) -> &mut TileMeta {
+        let index = match self
+            .tiles
+            .iter()
+            .position(|tile| tile.x == x && tile.y == y)
+        {
+            Some(index) => index,
+            None => {
+                self.tiles.push(TileMeta::new(x, y));
+                self.tiles.len() - 1
+            }
+        };
+        &mut self.tiles[index]
+    }
+}
@@ -405,7 +405,7 @@ impl winit::application::ApplicationHandler for Runner {
                 match input {
                     input::InputEvent::KeyInput { key, pressed, .. } => {
                         handle_key_input(
-                            &key, pressed, content, systems, alert, event_loop, seconds,
+                            &key, pressed, content, systems, graphics, alert, event_loop, seconds,
                         )
                         .unwrap();
                     }
@@ -677,9 +677,11 @@ async fn main() -> Result<()> {
 
     // Create the directory for our map data
     fs::create_dir_all("./data/maps/")?;
+    fs::create_dir_all("./data/templates/")?;
     fs::create_dir_all("./temp/")?;
     fs::create_dir_all("./mapeditor/images/")?;
-    fs::create_dir_all("./mapeditor/data/presets/")?;
+    fs::create_dir_all("./mapeditor/data/")?;
+    fs::create_dir_all("./mapeditor/bundles/")?;
 
     // This allows us to take control of panic!() so we can send it to a file via the logger.
     panic::set_hook(Box::new(|panic_info| {
@@ -7,6 +7,7 @@ use ascending_graphics::*;
 
 use crate::data_types::Result;
 use crate::data_types::TEXTURE_SIZE;
+use crate::database::{TileMeta, TilesheetMeta};
 
 pub enum GuiTexture {
     VerticalArrow,
@@ -24,8 +25,21 @@ pub enum GuiTexture {
 
 pub struct TilesheetData {
     pub name: String,
+    pub path: String,
     pub img: usize,
     pub tile: TileSheet,
+    pub meta: TilesheetMeta,
+}
+
+impl TilesheetData {
+    // The name given in the metadata, or the file name for sheets that got none
+    pub fn get_name(&self) -> String {
+        if self.meta.name.is_empty() {
+            self.name.clone()
+        } else {
+            self.meta.name.clone()
+        }
+    }
 }
 
 #[derive(Hash, Clone, Copy, PartialEq, Eq, Debug)]
@@ -85,12 +99,15 @@ impl TextureAllocation {
             if Path::new(&path).exists() {
                 let res = TilesheetData {
                     name: format!("t{count}.png"),
+                    path: path.clone(),
                     img: Texture::from_file(&path)?
                         .upload(&path, img_atlases, renderer)
                         .ok_or_else(|| OtherError::new("failed to upload image"))?,
                     tile: Texture::from_file(&path)?
                         .new_tilesheet(&path, map_atlases, renderer, TEXTURE_SIZE)
                         .ok_or_else(|| OtherError::new("failed to upload tiles"))?,
+                    // A broken metadata file only loses the properties of that sheet
+                    meta: TilesheetMeta::load_data(&path).unwrap_or_default(),
                 };
 
                 // Store the tile location
@@ -123,4 +140,11 @@ impl TextureAllocation {
             tile_pos_loc,
         })
     }
+
+    pub fn get_tile_meta(&self, tilepos: &TilePos) -> Option<&TileMeta> {
+        self.tilesheet
+            .get(tilepos.file as usize)?
+            .meta
+            .get_tile(tilepos.x / TEXTURE_SIZE, tilepos.y / TEXTURE_SIZE)
+    }
 }